@@ -0,0 +1,34 @@
+pub mod ines;
+pub mod mapper;
+mod nametables;
+
+#[cfg(all(test, not(tarpaulin_include)))]
+mod tests;
+
+pub use mapper::Mapper;
+pub use nametables::Nametables;
+
+/// Size of the console's nametable RAM
+pub const CIRAM_SIZE: usize = 2 * 1024;
+
+/// The console's 2 KiB of nametable RAM, the cartridge decides how it's mapped
+pub type Ciram = [u8; CIRAM_SIZE];
+
+/// How the 4 nametables are mapped onto the 2 KiB of VRAM
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mirroring {
+    /// `$2000` and `$2400` share the first page, `$2800` and `$2C00` the second one
+    Horizontal,
+
+    /// `$2000` and `$2800` share the first page, `$2400` and `$2C00` the second one
+    Vertical,
+
+    /// The cartridge provides the VRAM for all 4 nametables
+    FourScreen,
+
+    /// All 4 nametables use the first page, only mappers can select it
+    SingleScreenLower,
+
+    /// All 4 nametables use the second page, only mappers can select it
+    SingleScreenUpper,
+}
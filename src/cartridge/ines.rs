@@ -0,0 +1,289 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use super::Mirroring;
+
+pub const HEADER_SIZE: usize = 16;
+pub const TRAINER_SIZE: usize = 512;
+
+const MAGIC: [u8; 4] = *b"NES\x1A";
+
+const PRG_ROM_UNIT: usize = 16 * 1024;
+const CHR_ROM_UNIT: usize = 8 * 1024;
+const PRG_RAM_UNIT: usize = 8 * 1024;
+const CHR_RAM_SIZE: usize = 8 * 1024;
+
+/// Which version of the header a file uses
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    INes,
+    Nes20,
+}
+
+/// The video standard the game was made for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TvSystem {
+    Ntsc,
+    Pal,
+
+    /// Works on both NTSC and PAL consoles
+    MultiRegion,
+
+    /// The Dendy famiclone
+    Dendy,
+}
+
+/// The kind of console the game runs on
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConsoleType {
+    Nes,
+    VsSystem,
+    Playchoice10,
+
+    /// A console from the NES 2.0 extended console type list, holds its number
+    Extended(u8),
+}
+
+/// Information from the header of an iNES or NES 2.0 file
+///
+/// The formats are described at https://www.nesdev.org/wiki/INES
+/// and https://www.nesdev.org/wiki/NES_2.0
+///
+/// Sizes are in bytes. iNES files don't distinguish volatile and battery backed PRG RAM,
+/// it's all reported as PRG NVRAM when the battery flag is set
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Header {
+    pub format: Format,
+    pub mapper: u16,
+
+    /// Always 0 for iNES files
+    pub submapper: u8,
+
+    pub prg_rom_size: usize,
+    pub chr_rom_size: usize,
+    pub prg_ram_size: usize,
+    pub prg_nvram_size: usize,
+    pub chr_ram_size: usize,
+    pub chr_nvram_size: usize,
+
+    /// Whether the cartridge has battery backed memory
+    pub battery: bool,
+
+    pub mirroring: Mirroring,
+
+    /// Whether a 512 byte trainer is between the header and PRG ROM
+    pub trainer: bool,
+
+    pub tv_system: TvSystem,
+    pub console_type: ConsoleType,
+}
+
+/// A parsed ROM file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rom {
+    pub header: Header,
+
+    /// Loaded at `$7000-$71FF` by some copiers
+    pub trainer: Option<Vec<u8>>,
+
+    pub prg_rom: Vec<u8>,
+    pub chr_rom: Vec<u8>,
+}
+
+/// Error returned when a ROM file can't be parsed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseError {
+    /// The file is shorter than the 16 byte header
+    MissingHeader,
+
+    /// The file doesn't start with `NES<EOF>`
+    InvalidMagic,
+
+    /// A NES 2.0 size in exponent-multiplier notation doesn't fit in memory
+    SizeOverflow,
+
+    /// The file ends before all the data the header describes
+    Truncated { expected: usize, actual: usize },
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MissingHeader => write!(f, "the file is too short to contain a header"),
+            Self::InvalidMagic => write!(f, "the file isn't an iNES or NES 2.0 ROM"),
+            Self::SizeOverflow => write!(f, "the header specifies a ROM size that's too large"),
+            Self::Truncated { expected, actual } => write!(
+                f,
+                "the file is truncated, expected {expected} bytes but it has {actual}"
+            ),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+impl Header {
+    /// Parse the first 16 bytes of a ROM file
+    ///
+    /// # Errors
+    /// Returns an error if there are less than 16 bytes, the magic number is wrong,
+    /// or a NES 2.0 ROM size is too large
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let header: &[u8; HEADER_SIZE] = bytes
+            .get(..HEADER_SIZE)
+            .and_then(|header| header.try_into().ok())
+            .ok_or(ParseError::MissingHeader)?;
+
+        if header[..4] != MAGIC {
+            return Err(ParseError::InvalidMagic);
+        }
+
+        let flags_6 = header[6];
+        let format = if header[7] & 0x0C == 0x08 {
+            Format::Nes20
+        } else {
+            Format::INes
+        };
+
+        // old dumping tools left garbage like "DiskDude!" in bytes 7-15 of iNES headers,
+        // flags 7 can't be trusted in that case
+        let flags_7 = if format == Format::INes && header[12..].iter().any(|&byte| byte != 0) {
+            0
+        } else {
+            header[7]
+        };
+
+        let mirroring = if flags_6 & 0x08 != 0 {
+            Mirroring::FourScreen
+        } else if flags_6 & 0x01 != 0 {
+            Mirroring::Vertical
+        } else {
+            Mirroring::Horizontal
+        };
+        let battery = flags_6 & 0x02 != 0;
+        let trainer = flags_6 & 0x04 != 0;
+        let mapper = ((flags_7 & 0xF0) | (flags_6 >> 4)) as u16;
+
+        let console_type = match flags_7 & 0x03 {
+            0 => ConsoleType::Nes,
+            1 => ConsoleType::VsSystem,
+            2 => ConsoleType::Playchoice10,
+            _ => ConsoleType::Extended(header[13] & 0x0F),
+        };
+
+        match format {
+            Format::INes => {
+                let chr_rom_size = header[5] as usize * CHR_ROM_UNIT;
+                // 0 means 8 KiB for compatibility
+                let prg_ram_size = header[8].max(1) as usize * PRG_RAM_UNIT;
+
+                Ok(Self {
+                    format,
+                    mapper,
+                    submapper: 0,
+                    prg_rom_size: header[4] as usize * PRG_ROM_UNIT,
+                    chr_rom_size,
+                    prg_ram_size: if battery { 0 } else { prg_ram_size },
+                    prg_nvram_size: if battery { prg_ram_size } else { 0 },
+                    chr_ram_size: if chr_rom_size == 0 { CHR_RAM_SIZE } else { 0 },
+                    chr_nvram_size: 0,
+                    battery,
+                    mirroring,
+                    trainer,
+                    tv_system: if header[9] & 0x01 != 0 {
+                        TvSystem::Pal
+                    } else {
+                        TvSystem::Ntsc
+                    },
+                    console_type,
+                })
+            }
+            Format::Nes20 => Ok(Self {
+                format,
+                mapper: mapper | (((header[8] & 0x0F) as u16) << 8),
+                submapper: header[8] >> 4,
+                prg_rom_size: nes20_rom_size(header[4], header[9] & 0x0F, PRG_ROM_UNIT)?,
+                chr_rom_size: nes20_rom_size(header[5], header[9] >> 4, CHR_ROM_UNIT)?,
+                prg_ram_size: nes20_ram_size(header[10] & 0x0F),
+                prg_nvram_size: nes20_ram_size(header[10] >> 4),
+                chr_ram_size: nes20_ram_size(header[11] & 0x0F),
+                chr_nvram_size: nes20_ram_size(header[11] >> 4),
+                battery,
+                mirroring,
+                trainer,
+                tv_system: match header[12] & 0x03 {
+                    0 => TvSystem::Ntsc,
+                    1 => TvSystem::Pal,
+                    2 => TvSystem::MultiRegion,
+                    _ => TvSystem::Dendy,
+                },
+                console_type,
+            }),
+        }
+    }
+}
+
+/// ROM size from the LSB in the size field and the MSB nibble in byte 9
+///
+/// An MSB nibble of `$F` switches to exponent-multiplier notation,
+/// the LSB is then `EEEEEEMM` and the size `2^E * (MM * 2 + 1)` bytes
+fn nes20_rom_size(lsb: u8, msb: u8, unit: usize) -> Result<usize, ParseError> {
+    if msb == 0x0F {
+        let exponent = (lsb >> 2) as u32;
+        let multiplier = (lsb & 0x03) as usize * 2 + 1;
+
+        1usize
+            .checked_shl(exponent)
+            .and_then(|size| size.checked_mul(multiplier))
+            .ok_or(ParseError::SizeOverflow)
+    } else {
+        Ok((((msb as usize) << 8) | lsb as usize) * unit)
+    }
+}
+
+/// RAM size from a shift count, 0 meaning there's no RAM and anything else `64 << shift` bytes
+fn nes20_ram_size(shift: u8) -> usize {
+    match shift {
+        0 => 0,
+        shift => 64 << shift,
+    }
+}
+
+impl Rom {
+    /// Parse a whole iNES or NES 2.0 file
+    ///
+    /// Data after CHR ROM, like the miscellaneous ROMs of NES 2.0, is ignored
+    ///
+    /// # Errors
+    /// Returns an error if the header can't be parsed
+    /// or the file is shorter than the header says it should be
+    pub fn parse(bytes: &[u8]) -> Result<Self, ParseError> {
+        let header = Header::parse(bytes)?;
+
+        let trainer_size = if header.trainer { TRAINER_SIZE } else { 0 };
+        let expected = [trainer_size, header.prg_rom_size, header.chr_rom_size]
+            .into_iter()
+            .try_fold(HEADER_SIZE, usize::checked_add)
+            .ok_or(ParseError::SizeOverflow)?;
+
+        if bytes.len() < expected {
+            return Err(ParseError::Truncated {
+                expected,
+                actual: bytes.len(),
+            });
+        }
+
+        let (trainer, rest) = bytes[HEADER_SIZE..].split_at(trainer_size);
+        let (prg_rom, rest) = rest.split_at(header.prg_rom_size);
+        let chr_rom = &rest[..header.chr_rom_size];
+
+        Ok(Self {
+            header,
+            trainer: header.trainer.then(|| trainer.to_vec()),
+            prg_rom: prg_rom.to_vec(),
+            chr_rom: chr_rom.to_vec(),
+        })
+    }
+}
@@ -0,0 +1,298 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use super::{Ciram, Nametables, ines::Rom};
+
+pub mod discrete;
+pub mod mmc1;
+pub mod mmc2;
+pub mod mmc3;
+pub mod mmc5;
+pub mod nrom;
+mod vrc;
+pub mod vrc4;
+pub mod vrc6;
+pub mod vrc7;
+
+pub use discrete::{Board, Discrete};
+pub use mmc1::Mmc1;
+pub use mmc2::{Chip, Mmc2};
+pub use mmc3::Mmc3;
+pub use mmc5::Mmc5;
+pub use nrom::Nrom;
+pub use vrc4::Vrc4;
+pub use vrc6::Vrc6;
+pub use vrc7::Vrc7;
+
+/// The start of the cartridge's CPU address space
+pub const CPU_START: u16 = 0x4020;
+
+/// The end of the pattern tables in the PPU address space, nametables come after it
+pub const CHR_END: u16 = 0x1FFF;
+
+/// Where PRG RAM is in the CPU address space, on the cartridges that have some
+pub const PRG_RAM_START: u16 = 0x6000;
+pub const PRG_RAM_END: u16 = 0x7FFF;
+
+/// The start of PRG ROM in the CPU address space, which goes up to `$FFFF`
+pub const PRG_ROM_START: u16 = 0x8000;
+
+/// The hardware on a cartridge that maps its memory into the CPU and PPU address spaces
+///
+/// Every mapper is described at https://www.nesdev.org/wiki/Mapper
+pub trait Mapper {
+    /// Read from the CPU address space, `$4020-$FFFF`
+    ///
+    /// `open_bus` is the last value on the data bus,
+    /// it's returned for addresses nothing on the cartridge responds to
+    ///
+    /// By default it's what `cpu_peek` returns, mappers with read side effects override it
+    fn cpu_read(&mut self, address: u16, open_bus: u8) -> u8 {
+        self.cpu_peek(address).unwrap_or(open_bus)
+    }
+
+    /// Read from the CPU address space without side effects
+    ///
+    /// Returns `None` if nothing responds to the address
+    /// or the value can't be known without side effects
+    fn cpu_peek(&self, address: u16) -> Option<u8>;
+
+    /// Write to the CPU address space, `$4020-$FFFF`
+    fn cpu_write(&mut self, address: u16, value: u8);
+
+    /// Read from the PPU address space, `$0000-$3EFF`,
+    /// which contains the pattern tables followed by the nametables
+    ///
+    /// The PPU must call it for every fetch it makes while rendering, not just for `$2007` reads,
+    /// some mappers react to the addresses it fetches from
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8;
+
+    /// Write to the PPU address space, `$0000-$3EFF`
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram);
+
+    /// Called for CPU writes to the PPU registers, `$2000-$2007` with the mirroring removed,
+    /// the cartridge sees them on the CPU bus even though it doesn't respond to them
+    fn ppu_register_write(&mut self, _address: u16, _value: u8) {}
+
+    /// Called by the PPU when it puts an address on its bus without reading or writing it,
+    /// like when `$2006` sets the VRAM address or `$2007` increments it
+    fn ppu_address_changed(&mut self, _address: u16) {}
+
+    /// Level of the IRQ line, `true` meaning the cartridge asserts it
+    fn irq(&self) -> bool {
+        false
+    }
+
+    /// Current output of the cartridge's expansion audio, already scaled to the APU's range
+    ///
+    /// Each chip has its own level relative to the APU,
+    /// mappers scale their output against an APU pulse at full volume, `mixer::APU_PULSE_MAX`.
+    /// Cartridges without expansion audio are silent
+    fn audio(&self) -> f32 {
+        0.0
+    }
+
+    /// Called once every CPU cycle, before the bus access of that cycle
+    fn cpu_cycle(&mut self) {}
+}
+
+impl<M: Mapper + ?Sized> Mapper for Box<M> {
+    fn cpu_read(&mut self, address: u16, open_bus: u8) -> u8 {
+        (**self).cpu_read(address, open_bus)
+    }
+
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        (**self).cpu_peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        (**self).cpu_write(address, value);
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        (**self).ppu_read(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        (**self).ppu_write(address, value, ciram);
+    }
+
+    fn ppu_register_write(&mut self, address: u16, value: u8) {
+        (**self).ppu_register_write(address, value);
+    }
+
+    fn ppu_address_changed(&mut self, address: u16) {
+        (**self).ppu_address_changed(address);
+    }
+
+    fn irq(&self) -> bool {
+        (**self).irq()
+    }
+
+    fn audio(&self) -> f32 {
+        (**self).audio()
+    }
+
+    fn cpu_cycle(&mut self) {
+        (**self).cpu_cycle();
+    }
+}
+
+/// Error returned when a ROM uses a mapper that isn't implemented
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnsupportedMapper {
+    pub mapper: u16,
+    pub submapper: u8,
+}
+
+impl Display for UnsupportedMapper {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "mapper {} (submapper {}) isn't supported",
+            self.mapper, self.submapper
+        )
+    }
+}
+
+impl Error for UnsupportedMapper {}
+
+/// Create the mapper the ROM's header asks for
+///
+/// # Errors
+/// Returns an error if the mapper isn't implemented
+pub fn from_rom(rom: Rom) -> Result<Box<dyn Mapper>, UnsupportedMapper> {
+    match rom.header.mapper {
+        0 => Ok(Box::new(Nrom::new(rom))),
+        1 => Ok(Box::new(Mmc1::new(rom))),
+        2 => Ok(Box::new(Discrete::new(rom, Board::Uxrom))),
+        3 => Ok(Box::new(Discrete::new(rom, Board::Cnrom))),
+        4 => Ok(Box::new(Mmc3::new(rom))),
+        5 => Ok(Box::new(Mmc5::new(rom))),
+        7 => Ok(Box::new(Discrete::new(rom, Board::Axrom))),
+        9 => Ok(Box::new(Mmc2::new(rom, Chip::Mmc2))),
+        10 => Ok(Box::new(Mmc2::new(rom, Chip::Mmc4))),
+        11 => Ok(Box::new(Discrete::new(rom, Board::ColorDreams))),
+        21 | 22 | 23 | 25 => Ok(Box::new(Vrc4::new(rom))),
+        24 | 26 => Ok(Box::new(Vrc6::new(rom))),
+        34 => {
+            // iNES files have no submapper, only NINA-001 has more than 8 KiB of CHR
+            let board = match rom.header.submapper {
+                1 => Board::Nina001,
+                2 => Board::Bnrom,
+                _ if rom.chr_rom.len() > 8 * 1024 => Board::Nina001,
+                _ => Board::Bnrom,
+            };
+            Ok(Box::new(Discrete::new(rom, board)))
+        }
+        66 => Ok(Box::new(Discrete::new(rom, Board::Gxrom))),
+        85 => Ok(Box::new(Vrc7::new(rom))),
+        mapper => Err(UnsupportedMapper {
+            mapper,
+            submapper: rom.header.submapper,
+        }),
+    }
+}
+
+/// Read PRG ROM or PRG RAM at an offset, wrapping around if it's past the end
+///
+/// Returns `None` if there's none of it
+pub fn read_prg(prg: &[u8], offset: usize) -> Option<u8> {
+    (!prg.is_empty()).then(|| prg[offset % prg.len()])
+}
+
+/// Offset in PRG RAM of an address in `$6000-$7FFF`, mirrored if there's less than 8 KiB of it
+///
+/// Returns `None` for other addresses or if there's no PRG RAM
+pub fn prg_ram_offset(prg_ram: &[u8], address: u16) -> Option<usize> {
+    match address {
+        PRG_RAM_START..=PRG_RAM_END if !prg_ram.is_empty() => {
+            Some((address - PRG_RAM_START) as usize % prg_ram.len())
+        }
+        _ => None,
+    }
+}
+
+/// Mappers with CHR banks at `$0000-$1FFF` and the nametables after them
+///
+/// Their `ppu_read` and `ppu_write` only need to pick between the 2
+/// with `read_chr_or_nametable` and `write_chr_or_nametable`
+trait BankedChr {
+    /// Offset in CHR of an address in `$0000-$1FFF`
+    fn chr_offset(&self, address: u16) -> usize;
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables);
+
+    fn read_chr_or_nametable(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        match address {
+            ..=CHR_END => {
+                let offset = self.chr_offset(address);
+                self.chr_and_nametables().0.read(offset)
+            }
+            _ => self.chr_and_nametables().1.read(address, ciram),
+        }
+    }
+
+    fn write_chr_or_nametable(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        match address {
+            ..=CHR_END => {
+                let offset = self.chr_offset(address);
+                self.chr_and_nametables().0.write(offset, value);
+            }
+            _ => self.chr_and_nametables().1.write(address, value, ciram),
+        }
+    }
+}
+
+/// CHR ROM, or CHR RAM for cartridges without CHR ROM
+#[derive(Debug, Clone)]
+pub struct Chr {
+    data: Vec<u8>,
+    writable: bool,
+}
+
+impl Chr {
+    /// Take the CHR ROM, or allocate CHR RAM of the size the header asks for if there's none
+    pub fn new(rom: &mut Rom) -> Self {
+        if rom.chr_rom.is_empty() {
+            let size = rom.header.chr_ram_size + rom.header.chr_nvram_size;
+            Self {
+                data: vec![0; size.max(8 * 1024)],
+                writable: true,
+            }
+        } else {
+            Self {
+                data: std::mem::take(&mut rom.chr_rom),
+                writable: false,
+            }
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub fn is_ram(&self) -> bool {
+        self.writable
+    }
+
+    /// Read the byte at an offset, wrapping around if it's past the end
+    pub fn read(&self, offset: usize) -> u8 {
+        self.data[offset % self.data.len()]
+    }
+
+    /// Write the byte at an offset, wrapping around if it's past the end,
+    /// writes to CHR ROM are ignored
+    pub fn write(&mut self, offset: usize, value: u8) {
+        if self.writable {
+            let len = self.data.len();
+            self.data[offset % len] = value;
+        }
+    }
+}
@@ -0,0 +1,194 @@
+use super::{BankedChr, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg};
+use crate::cartridge::{Ciram, Mirroring, Nametables, ines::Rom};
+
+const PRG_BANK_SIZE: usize = 16 * 1024;
+const CHR_BANK_SIZE: usize = 4 * 1024;
+
+/// NES 2.0 submapper of UxROM, CNROM and AxROM boards with bus conflicts
+const SUBMAPPER_BUS_CONFLICTS: u8 = 2;
+
+/// NINA-001 registers, in the PRG RAM range
+const NINA_001_PRG: u16 = 0x7FFD;
+const NINA_001_CHR_LOW: u16 = 0x7FFE;
+const NINA_001_CHR_HIGH: u16 = 0x7FFF;
+
+/// Boards made of discrete logic chips, they only have a latch holding bank numbers
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Board {
+    /// Mapper 2, switchable 16 KiB PRG bank at `$8000` and the last one fixed at `$C000`
+    Uxrom,
+
+    /// Mapper 3, switchable 8 KiB CHR bank
+    Cnrom,
+
+    /// Mapper 7, switchable 32 KiB PRG bank and single screen mirroring,
+    /// bit 4 of the latch selects the nametable
+    Axrom,
+
+    /// Mapper 11, switchable 32 KiB PRG bank in bits 0-1 and 8 KiB CHR bank in bits 4-7
+    ColorDreams,
+
+    /// Mapper 66, switchable 32 KiB PRG bank in bits 4-5 and 8 KiB CHR bank in bits 0-1
+    Gxrom,
+
+    /// Mapper 34 with CHR RAM, switchable 32 KiB PRG bank
+    Bnrom,
+
+    /// Mapper 34 with CHR ROM, registers at `$7FFD-$7FFF` switch a 32 KiB PRG bank
+    /// and 2 4 KiB CHR banks, PRG RAM is behind them
+    Nina001,
+}
+
+/// Mappers 2, 3, 7, 11, 34 and 66
+///
+/// Their registers are written anywhere in `$8000-$FFFF`, except for NINA-001.
+/// Boards with bus conflicts don't disable PRG ROM while it's written to,
+/// so both drive the data bus and the latch gets the written value ANDed with the ROM byte.
+/// Games avoid them by writing to a ROM byte holding the same value.
+/// UxROM, CNROM and AxROM have them when their NES 2.0 submapper is 2,
+/// Color Dreams, GxROM and BNROM always do.
+///
+/// See https://www.nesdev.org/wiki/Category:Discrete_logic_mappers
+#[derive(Debug, Clone)]
+pub struct Discrete {
+    pub board: Board,
+    pub bus_conflicts: bool,
+
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    /// In 16 KiB units for UxROM, 32 KiB units otherwise
+    prg_bank: u8,
+
+    /// 4 KiB banks at `$0000` and `$1000`
+    chr_banks: [u8; 2],
+}
+
+impl Discrete {
+    pub fn new(mut rom: Rom, board: Board) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+        let bus_conflicts = match board {
+            Board::Uxrom | Board::Cnrom | Board::Axrom => {
+                rom.header.submapper == SUBMAPPER_BUS_CONFLICTS
+            }
+            Board::ColorDreams | Board::Gxrom | Board::Bnrom => true,
+            Board::Nina001 => false,
+        };
+        let mirroring = match board {
+            Board::Axrom => Mirroring::SingleScreenLower,
+            _ => rom.header.mirroring,
+        };
+
+        Self {
+            board,
+            bus_conflicts,
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(mirroring),
+            prg_rom: rom.prg_rom,
+            prg_bank: 0,
+            chr_banks: [0, 1],
+        }
+    }
+
+    fn write_latch(&mut self, address: u16, value: u8) {
+        let value = if self.bus_conflicts {
+            value & self.cpu_peek(address).unwrap_or(0xFF)
+        } else {
+            value
+        };
+
+        match self.board {
+            Board::Uxrom | Board::Bnrom => self.prg_bank = value,
+            Board::Cnrom => self.set_chr_bank(value),
+            Board::Axrom => {
+                self.prg_bank = value & 0x07;
+                self.nametables.mirroring = if value & 0x10 == 0 {
+                    Mirroring::SingleScreenLower
+                } else {
+                    Mirroring::SingleScreenUpper
+                };
+            }
+            Board::ColorDreams => {
+                self.prg_bank = value & 0x03;
+                self.set_chr_bank(value >> 4);
+            }
+            Board::Gxrom => {
+                self.prg_bank = (value >> 4) & 0x03;
+                self.set_chr_bank(value & 0x03);
+            }
+            // NINA-001 has no registers here
+            Board::Nina001 => {}
+        }
+    }
+
+    /// Switch both halves of the pattern tables with an 8 KiB bank number
+    fn set_chr_bank(&mut self, bank: u8) {
+        let bank = bank.wrapping_mul(2);
+        self.chr_banks = [bank, bank.wrapping_add(1)];
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let offset = (address - PRG_ROM_START) as usize;
+
+        match self.board {
+            Board::Uxrom if address < 0xC000 => self.prg_bank as usize * PRG_BANK_SIZE + offset,
+            Board::Uxrom => {
+                self.prg_rom.len().saturating_sub(PRG_BANK_SIZE) + offset % PRG_BANK_SIZE
+            }
+            Board::Cnrom => offset,
+            _ => self.prg_bank as usize * 2 * PRG_BANK_SIZE + offset,
+        }
+    }
+}
+
+impl BankedChr for Discrete {
+    fn chr_offset(&self, address: u16) -> usize {
+        let bank = self.chr_banks[((address >> 12) & 1) as usize];
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Discrete {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        prg_ram_offset(&self.prg_ram, address).map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_latch(address, value);
+            return;
+        }
+
+        if let Some(offset) = prg_ram_offset(&self.prg_ram, address) {
+            self.prg_ram[offset] = value;
+        }
+
+        if self.board == Board::Nina001 {
+            match address {
+                NINA_001_PRG => self.prg_bank = value & 0x01,
+                NINA_001_CHR_LOW => self.chr_banks[0] = value & 0x0F,
+                NINA_001_CHR_HIGH => self.chr_banks[1] = value & 0x0F,
+                _ => {}
+            }
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+}
@@ -0,0 +1,212 @@
+use super::{BankedChr, Chr, Mapper, PRG_RAM_END, PRG_RAM_START, PRG_ROM_START, read_prg};
+use crate::cartridge::{Ciram, Mirroring, Nametables, ines::Rom};
+
+const PRG_BANK_SIZE: usize = 16 * 1024;
+const PRG_RAM_BANK_SIZE: usize = 8 * 1024;
+const CHR_BANK_SIZE: usize = 4 * 1024;
+
+/// Set in the shift register after a reset, reaching bit 0 means 5 bits were written
+const SHIFT_RESET: u8 = 0b1_0000;
+
+/// Control register value at power on, PRG mode 3 so the last bank is at `$C000`
+const CONTROL_POWER_ON: u8 = 0b0_1100;
+
+/// Boards with more than 256 KiB of PRG ROM use a CHR bank bit to select the 256 KiB half
+const PRG_OUTER_BANK_SIZE: usize = 256 * 1024;
+
+/// MMC1, mapper 1
+///
+/// Registers are written 1 bit at a time through a 5 bit shift register at `$8000-$FFFF`,
+/// the 5th write copies it to the register selected by bits 13 and 14 of its address.
+/// Writing a value with bit 7 set resets the shift register and selects PRG mode 3.
+///
+/// The MMC1 ignores a write on the cycle after another write,
+/// so the second write of a read-modify-write instruction has no effect.
+/// This relies on `cpu_cycle` being called every cycle.
+///
+/// Boards with 8 KiB of CHR RAM use the upper bits of the first CHR bank register for other things:
+/// - SNROM: bit 4 disables PRG RAM
+/// - SOROM: bit 3 selects one of the 2 PRG RAM banks
+/// - SXROM: bits 2 and 3 select one of the 4 PRG RAM banks
+/// - SUROM and SXROM: bit 4 selects the 256 KiB half of PRG ROM
+///
+/// The board is told apart by its PRG ROM and RAM sizes.
+///
+/// See https://www.nesdev.org/wiki/MMC1
+#[derive(Debug, Clone)]
+pub struct Mmc1 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    shift: u8,
+    control: u8,
+    chr_banks: [u8; 2],
+    prg_bank: u8,
+
+    wrote_this_cycle: bool,
+    wrote_last_cycle: bool,
+}
+
+impl Mmc1 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+
+        let mut mmc1 = Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+            shift: SHIFT_RESET,
+            control: CONTROL_POWER_ON,
+            chr_banks: [0; 2],
+            prg_bank: 0,
+            wrote_this_cycle: false,
+            wrote_last_cycle: false,
+        };
+        mmc1.update_mirroring();
+        mmc1
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        if value & 0x80 != 0 {
+            self.shift = SHIFT_RESET;
+            self.control |= CONTROL_POWER_ON;
+            return;
+        }
+
+        let full = self.shift & 1 != 0;
+        self.shift = (self.shift >> 1) | ((value & 1) << 4);
+
+        if full {
+            let value = self.shift;
+            self.shift = SHIFT_RESET;
+
+            match (address >> 13) & 0x03 {
+                0 => {
+                    self.control = value;
+                    self.update_mirroring();
+                }
+                1 => self.chr_banks[0] = value,
+                2 => self.chr_banks[1] = value,
+                _ => self.prg_bank = value,
+            }
+        }
+    }
+
+    fn update_mirroring(&mut self) {
+        // four screen boards don't connect the mirroring outputs
+        if self.nametables.mirroring == Mirroring::FourScreen {
+            return;
+        }
+
+        self.nametables.mirroring = match self.control & 0x03 {
+            0 => Mirroring::SingleScreenLower,
+            1 => Mirroring::SingleScreenUpper,
+            2 => Mirroring::Vertical,
+            _ => Mirroring::Horizontal,
+        };
+    }
+
+    /// Whether the board uses the CHR bank registers for PRG, which it does with 8 KiB of CHR RAM
+    fn chr_selects_prg(&self) -> bool {
+        self.chr.is_ram() && self.chr.len() <= 2 * CHR_BANK_SIZE
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let bank = (self.prg_bank & 0x0F) as usize;
+        let bank = match (self.control >> 2) & 0x03 {
+            // 32 KiB mode ignores the low bit
+            0 | 1 => (bank & !1) | ((address >> 14) & 1) as usize,
+            2 if address < 0xC000 => 0,
+            2 => bank,
+            _ if address < 0xC000 => bank,
+            _ => 0x0F,
+        };
+
+        let suxrom = self.chr_selects_prg() && self.prg_rom.len() > PRG_OUTER_BANK_SIZE;
+        let outer = if suxrom && self.chr_banks[0] & 0x10 != 0 {
+            PRG_OUTER_BANK_SIZE
+        } else {
+            0
+        };
+
+        outer + bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE)
+    }
+
+    fn prg_ram_offset(&self, address: u16) -> Option<usize> {
+        if !(PRG_RAM_START..=PRG_RAM_END).contains(&address) || self.prg_ram.is_empty() {
+            return None;
+        }
+
+        let chr_bank = self.chr_banks[0];
+        let snrom = self.chr_selects_prg()
+            && self.prg_rom.len() <= PRG_OUTER_BANK_SIZE
+            && self.prg_ram.len() <= PRG_RAM_BANK_SIZE;
+
+        if self.prg_bank & 0x10 != 0 || (snrom && chr_bank & 0x10 != 0) {
+            return None;
+        }
+
+        let bank = match self.prg_ram.len() / PRG_RAM_BANK_SIZE {
+            2 if self.chr_selects_prg() => (chr_bank >> 3) & 0x01,
+            4.. if self.chr_selects_prg() => (chr_bank >> 2) & 0x03,
+            _ => 0,
+        } as usize;
+
+        Some((bank * PRG_RAM_BANK_SIZE + (address - PRG_RAM_START) as usize) % self.prg_ram.len())
+    }
+}
+
+impl BankedChr for Mmc1 {
+    fn chr_offset(&self, address: u16) -> usize {
+        let bank = if self.control & 0x10 == 0 {
+            // 8 KiB mode ignores the low bit
+            (self.chr_banks[0] & !1) | ((address >> 12) & 1) as u8
+        } else {
+            self.chr_banks[((address >> 12) & 1) as usize]
+        };
+
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Mmc1 {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        self.prg_ram_offset(address)
+            .map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            if !self.wrote_last_cycle {
+                self.write_register(address, value);
+            }
+            self.wrote_this_cycle = true;
+        } else if let Some(offset) = self.prg_ram_offset(address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.wrote_last_cycle = self.wrote_this_cycle;
+        self.wrote_this_cycle = false;
+    }
+}
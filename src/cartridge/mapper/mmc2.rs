@@ -0,0 +1,170 @@
+use super::{BankedChr, CHR_END, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg};
+use crate::cartridge::{Ciram, Mirroring, Nametables, ines::Rom};
+
+/// PRG ROM visible at `$8000-$FFFF`, the switchable bank followed by the end of PRG ROM
+const PRG_WINDOW_SIZE: usize = 32 * 1024;
+const CHR_BANK_SIZE: usize = 4 * 1024;
+
+/// Fetching tile `$FD` or `$FE` sets the latch of the pattern table it's in
+const LATCH_FD: u16 = 0x0FD8;
+const LATCH_FE: u16 = 0x0FE8;
+
+/// The 2 chips share everything but PRG banking and which fetches set the first latch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chip {
+    /// Mapper 9, switchable 8 KiB PRG bank at `$8000` and the last 3 fixed at `$A000`.
+    /// The latch of `$0000` is only set by fetching the last byte of tile `$FD` or `$FE`
+    Mmc2,
+
+    /// Mapper 10, switchable 16 KiB PRG bank at `$8000` and the last one fixed at `$C000`
+    Mmc4,
+}
+
+/// MMC2 and MMC4, mappers 9 and 10
+///
+/// Each 4 KiB pattern table has 2 CHR bank registers,
+/// a latch selects which one is used and it's switched by the PPU fetching tile `$FD` or `$FE`.
+/// The new bank is used starting with the fetch after the one that set the latch.
+///
+/// | Range         | Register                                    |
+/// |---------------|---------------------------------------------|
+/// | `$A000-$AFFF` | PRG bank                                    |
+/// | `$B000-$BFFF` | CHR bank at `$0000` when the latch is `$FD` |
+/// | `$C000-$CFFF` | CHR bank at `$0000` when the latch is `$FE` |
+/// | `$D000-$DFFF` | CHR bank at `$1000` when the latch is `$FD` |
+/// | `$E000-$EFFF` | CHR bank at `$1000` when the latch is `$FE` |
+/// | `$F000-$FFFF` | Mirroring, 0 is vertical and 1 horizontal   |
+///
+/// See https://www.nesdev.org/wiki/MMC2 and https://www.nesdev.org/wiki/MMC4
+#[derive(Debug, Clone)]
+pub struct Mmc2 {
+    pub chip: Chip,
+
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    prg_bank: u8,
+
+    /// Banks of each pattern table, for the `$FD` and `$FE` latch values
+    chr_banks: [[u8; 2]; 2],
+
+    /// Which bank of each pattern table is used, `false` for `$FD` and `true` for `$FE`
+    latches: [bool; 2],
+}
+
+impl Mmc2 {
+    pub fn new(mut rom: Rom, chip: Chip) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+
+        Self {
+            chip,
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+            prg_bank: 0,
+            chr_banks: [[0; 2]; 2],
+            latches: [true; 2],
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        match address >> 12 {
+            0xA => self.prg_bank = value & 0x0F,
+            0xB => self.chr_banks[0][0] = value & 0x1F,
+            0xC => self.chr_banks[0][1] = value & 0x1F,
+            0xD => self.chr_banks[1][0] = value & 0x1F,
+            0xE => self.chr_banks[1][1] = value & 0x1F,
+            0xF if self.nametables.mirroring != Mirroring::FourScreen => {
+                self.nametables.mirroring = if value & 1 == 0 {
+                    Mirroring::Vertical
+                } else {
+                    Mirroring::Horizontal
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Set the latches after a pattern fetch
+    fn update_latches(&mut self, address: u16) {
+        let table = ((address >> 12) & 1) as usize;
+        let tile_address = address & 0x0FFF;
+
+        // the MMC2 only watches the last byte of the tiles in the first pattern table
+        let exact = self.chip == Chip::Mmc2 && table == 0;
+        let matches = |latch_address: u16| {
+            if exact {
+                tile_address == latch_address
+            } else {
+                tile_address & !0x07 == latch_address & !0x07
+            }
+        };
+
+        if matches(LATCH_FD) {
+            self.latches[table] = false;
+        } else if matches(LATCH_FE) {
+            self.latches[table] = true;
+        }
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let offset = (address - PRG_ROM_START) as usize;
+        let bank_size = match self.chip {
+            Chip::Mmc2 => 8 * 1024,
+            Chip::Mmc4 => 16 * 1024,
+        };
+
+        if offset < bank_size {
+            self.prg_bank as usize * bank_size + offset
+        } else {
+            self.prg_rom.len().saturating_sub(PRG_WINDOW_SIZE) + offset
+        }
+    }
+}
+
+impl BankedChr for Mmc2 {
+    fn chr_offset(&self, address: u16) -> usize {
+        let table = ((address >> 12) & 1) as usize;
+        let bank = self.chr_banks[table][self.latches[table] as usize];
+
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Mmc2 {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        prg_ram_offset(&self.prg_ram, address).map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_register(address, value);
+        } else if let Some(offset) = prg_ram_offset(&self.prg_ram, address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        let value = self.read_chr_or_nametable(address, ciram);
+        if address <= CHR_END {
+            self.update_latches(address);
+        }
+
+        value
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+}
@@ -0,0 +1,259 @@
+use super::{BankedChr, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg};
+use crate::cartridge::{Ciram, Mirroring, Nametables, ines::Rom};
+
+const PRG_BANK_SIZE: usize = 8 * 1024;
+const CHR_BANK_SIZE: usize = 1024;
+
+/// PPU address line whose rises clock the IRQ counter
+const PPU_A12: u16 = 0x1000;
+
+/// CPU cycles A12 has to stay low for its next rise to clock the IRQ counter,
+/// this filters out the rises caused by fetching sprite patterns from `$1000` for every sprite
+const A12_LOW_CYCLES: u8 = 3;
+
+/// NES 2.0 submapper for boards with the MMC3A
+const SUBMAPPER_MMC3A: u8 = 4;
+
+/// When the MMC3 asserts the IRQ line after clocking its counter
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IrqBehavior {
+    /// MMC3B and MMC3C made by Sharp, an IRQ happens every time the counter is 0 after being clocked,
+    /// so a latch of 0 causes an IRQ every scanline
+    Sharp,
+
+    /// MMC3A made by NEC, an IRQ only happens when the counter goes from 1 to 0
+    /// or is reloaded to 0 after a write to `$C001`, so a latch of 0 causes a single IRQ
+    Nec,
+}
+
+/// MMC3, mapper 4
+///
+/// PRG ROM is switched in 8 KiB banks and CHR in 1 and 2 KiB banks,
+/// through a bank select register at even addresses in `$8000-$9FFF` and bank data at odd ones.
+/// Other registers are selected by the address range and whether the address is even:
+///
+/// | Range         | Even                        | Odd             |
+/// |---------------|-----------------------------|-----------------|
+/// | `$8000-$9FFF` | Bank select                 | Bank data       |
+/// | `$A000-$BFFF` | Mirroring                   | PRG RAM protect |
+/// | `$C000-$DFFF` | IRQ latch                   | IRQ reload      |
+/// | `$E000-$FFFF` | IRQ disable and acknowledge | IRQ enable      |
+///
+/// The IRQ counter is clocked by rises of PPU A12 that come after it stayed low
+/// for a few CPU cycles, which happens once per scanline when the PPU fetches
+/// backgrounds from `$0000` and sprites from `$1000`,
+/// or when the PPU's VRAM address is set through `$2006` or incremented by `$2007`.
+/// This relies on `cpu_cycle` being called every cycle.
+///
+/// See https://www.nesdev.org/wiki/MMC3
+#[derive(Debug, Clone)]
+pub struct Mmc3 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    pub irq_behavior: IrqBehavior,
+
+    bank_select: u8,
+
+    /// R0 to R7, R0 and R1 are 2 KiB CHR banks, R2 to R5 1 KiB CHR banks, R6 and R7 PRG banks
+    banks: [u8; 8],
+
+    prg_ram_protect: u8,
+
+    irq_latch: u8,
+    irq_counter: u8,
+    irq_reload: bool,
+    irq_enabled: bool,
+    irq_pending: bool,
+
+    a12: bool,
+
+    /// CPU cycles since A12 went low, saturating
+    a12_low_cycles: u8,
+}
+
+impl Mmc3 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+        let irq_behavior = if rom.header.submapper == SUBMAPPER_MMC3A {
+            IrqBehavior::Nec
+        } else {
+            IrqBehavior::Sharp
+        };
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+            irq_behavior,
+            bank_select: 0,
+            banks: [0; 8],
+            // games rely on PRG RAM being usable without enabling it first
+            prg_ram_protect: 0x80,
+            irq_latch: 0,
+            irq_counter: 0,
+            irq_reload: false,
+            irq_enabled: false,
+            irq_pending: false,
+            a12: false,
+            a12_low_cycles: 0,
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        let even = address & 1 == 0;
+
+        match (address >> 13) & 0x03 {
+            0 if even => self.bank_select = value,
+            0 => self.banks[(self.bank_select & 0x07) as usize] = value,
+            // four screen boards don't connect the mirroring output
+            1 if even && self.nametables.mirroring != Mirroring::FourScreen => {
+                self.nametables.mirroring = if value & 1 == 0 {
+                    Mirroring::Vertical
+                } else {
+                    Mirroring::Horizontal
+                };
+            }
+            1 if even => {}
+            1 => self.prg_ram_protect = value,
+            2 if even => self.irq_latch = value,
+            2 => {
+                self.irq_counter = 0;
+                self.irq_reload = true;
+            }
+            _ if even => {
+                self.irq_enabled = false;
+                self.irq_pending = false;
+            }
+            _ => self.irq_enabled = true,
+        }
+    }
+
+    fn clock_irq_counter(&mut self) {
+        let previous = self.irq_counter;
+        let reloaded = self.irq_reload;
+
+        if self.irq_counter == 0 || self.irq_reload {
+            self.irq_counter = self.irq_latch;
+        } else {
+            self.irq_counter -= 1;
+        }
+        self.irq_reload = false;
+
+        let triggered = match self.irq_behavior {
+            IrqBehavior::Sharp => self.irq_counter == 0,
+            IrqBehavior::Nec => self.irq_counter == 0 && (previous != 0 || reloaded),
+        };
+        if triggered && self.irq_enabled {
+            self.irq_pending = true;
+        }
+    }
+
+    /// Watch PPU A12 for rises that clock the IRQ counter
+    fn ppu_address(&mut self, address: u16) {
+        let a12 = address & PPU_A12 != 0;
+
+        if a12 && !self.a12 && self.a12_low_cycles >= A12_LOW_CYCLES {
+            self.clock_irq_counter();
+        }
+        if !a12 && self.a12 {
+            self.a12_low_cycles = 0;
+        }
+
+        self.a12 = a12;
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let second_last = (self.prg_rom.len() / PRG_BANK_SIZE).saturating_sub(2);
+        let swapped = self.bank_select & 0x40 != 0;
+
+        let bank = match (address >> 13) & 0x03 {
+            0 if swapped => second_last,
+            0 => (self.banks[6] & 0x3F) as usize,
+            1 => (self.banks[7] & 0x3F) as usize,
+            2 if swapped => (self.banks[6] & 0x3F) as usize,
+            2 => second_last,
+            _ => second_last + 1,
+        };
+
+        bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE)
+    }
+
+    fn prg_ram_offset(&self, address: u16, write: bool) -> Option<usize> {
+        let enabled = self.prg_ram_protect & 0x80 != 0;
+        let write_protected = self.prg_ram_protect & 0x40 != 0;
+
+        prg_ram_offset(&self.prg_ram, address).filter(|_| enabled && !(write && write_protected))
+    }
+}
+
+impl BankedChr for Mmc3 {
+    fn chr_offset(&self, address: u16) -> usize {
+        // CHR inversion swaps the 2 KiB banks at $0000 and the 1 KiB banks at $1000
+        let address = if self.bank_select & 0x80 != 0 {
+            address ^ PPU_A12
+        } else {
+            address
+        };
+
+        let bank = match address >> 10 {
+            0 => self.banks[0] & !1,
+            1 => self.banks[0] | 1,
+            2 => self.banks[1] & !1,
+            3 => self.banks[1] | 1,
+            slot => self.banks[(slot - 2) as usize],
+        };
+
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Mmc3 {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        self.prg_ram_offset(address, false)
+            .map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_register(address, value);
+        } else if let Some(offset) = self.prg_ram_offset(address, true) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.ppu_address(address);
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.ppu_address(address);
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+
+    fn ppu_address_changed(&mut self, address: u16) {
+        self.ppu_address(address);
+    }
+
+    fn irq(&self) -> bool {
+        self.irq_pending
+    }
+
+    fn cpu_cycle(&mut self) {
+        if !self.a12 {
+            self.a12_low_cycles = self.a12_low_cycles.saturating_add(1);
+        }
+    }
+}
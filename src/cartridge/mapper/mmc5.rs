@@ -0,0 +1,597 @@
+use super::{CHR_END, Chr, Mapper, PRG_RAM_START, PRG_ROM_START, read_prg};
+use crate::cartridge::{CIRAM_SIZE, Ciram, ines::Rom};
+
+const AUDIO_START: u16 = 0x5000;
+const AUDIO_END: u16 = 0x5015;
+const EXRAM_START: u16 = 0x5C00;
+const EXRAM_END: u16 = 0x5FFF;
+
+/// Reading the NMI vector means the PPU is in vertical blank
+const NMI_VECTOR_LOW: u16 = 0xFFFA;
+const NMI_VECTOR_HIGH: u16 = 0xFFFB;
+
+const PRG_BANK_SIZE: usize = 8 * 1024;
+const CHR_SIZE: usize = 8 * 1024;
+const CHR_PAGE_SIZE: usize = 4 * 1024;
+const NAMETABLE_SIZE: usize = 1024;
+const EXRAM_SIZE: usize = 1024;
+
+/// Offset of the attribute table in a nametable
+const ATTRIBUTE_TABLE: usize = 0x03C0;
+
+/// Visible scanlines, the split scroll wraps around after them
+const VISIBLE_SCANLINES: u16 = 240;
+
+/// CPU cycles without PPU reads after which the PPU is considered not rendering
+const PPU_IDLE_CYCLES: u8 = 3;
+
+/// PPU reads made on a scanline, counted from the first background fetch the MMC5 recognizes
+///
+/// It sees a scanline start when the same nametable byte is read 3 times in a row,
+/// by the 2 dummy fetches at the end of a scanline and the first fetch of the next one
+/// which is the nametable byte of the 3rd tile, the first 2 being fetched on the previous scanline
+const BACKGROUND_FETCHES: u8 = 128;
+const SPRITE_FETCHES_END: u8 = BACKGROUND_FETCHES + 32;
+const PREFETCHES_END: u8 = SPRITE_FETCHES_END + 8;
+
+/// How ExRAM is used, selected by `$5104`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExramMode {
+    /// Usable as an extra nametable
+    Nametable,
+
+    /// Usable as a nametable, and each byte holds a 4 KiB CHR bank and the palette
+    /// of the background tile at the same position in the nametables
+    ExtendedAttributes,
+
+    /// Plain RAM the CPU can read and write
+    Ram,
+
+    /// RAM the CPU can only read
+    Rom,
+}
+
+/// What the PPU is fetching, worked out from the number of reads since the scanline started
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Fetch {
+    /// A background tile at a column of a scanline, relative to the current one
+    Background {
+        column: u8,
+        next_scanline: bool,
+    },
+
+    Sprite,
+
+    /// Not rendering, or the dummy nametable fetches at the end of a scanline
+    Other,
+}
+
+/// MMC5, mapper 5
+///
+/// PRG is switched in 8 to 32 KiB banks which can hold PRG RAM, and CHR in 1 to 8 KiB banks.
+/// There are 2 sets of CHR banks: with 8x16 sprites, set A is used for sprites and set B
+/// for backgrounds, otherwise the last set written to is used for everything.
+///
+/// The 1 KiB ExRAM can hold a nametable, extended attributes giving every background tile
+/// its own CHR bank and palette, or plain RAM. Each nametable can be mapped to a CIRAM page,
+/// ExRAM, or a fill mode returning the same tile and attribute everywhere.
+///
+/// The vertical split replaces the background left or right of a tile column with
+/// a nametable in ExRAM, with its own vertical scroll and CHR bank.
+///
+/// There's no scanline input, the MMC5 watches PPU reads to know what's being fetched,
+/// so the PPU must call `ppu_read` for every fetch and `cpu_cycle` must be called every cycle.
+/// It also watches writes to PPUCTRL for the sprite size and to PPUMASK for rendering.
+///
+/// The pulse and PCM audio channels aren't emulated, their registers are stored
+/// and the status registers read as if the channels were silent.
+///
+/// See https://www.nesdev.org/wiki/MMC5
+#[derive(Debug, Clone)]
+pub struct Mmc5 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    exram: [u8; EXRAM_SIZE],
+
+    prg_mode: u8,
+    chr_mode: u8,
+    prg_ram_protect: [u8; 2],
+    exram_mode: ExramMode,
+
+    /// 2 bits per nametable, CIRAM page 0 or 1, ExRAM, or the fill mode
+    nametable_mapping: u8,
+    fill_tile: u8,
+    fill_attribute: u8,
+
+    /// `$5113` to `$5117`, the PRG RAM bank at `$6000` followed by the 4 PRG banks
+    prg_banks: [u8; 5],
+
+    /// `$5120` to `$512B`, set A followed by set B, with the upper bits that were set when written
+    chr_banks: [u16; 12],
+    chr_upper: u8,
+    last_written_set_b: bool,
+
+    split_control: u8,
+    split_scroll: u8,
+    split_bank: u8,
+
+    irq_compare: u8,
+    irq_enabled: bool,
+    irq_pending: bool,
+    in_frame: bool,
+    scanline: u8,
+
+    multiplicand: u8,
+    multiplier: u8,
+
+    /// Set by PPUCTRL
+    large_sprites: bool,
+
+    /// Set by PPUMASK
+    rendering: bool,
+
+    last_ppu_address: u16,
+    repeated_reads: u8,
+    ppu_idle_cycles: u8,
+    fetch_index: u8,
+
+    /// ExRAM byte of the background tile being fetched, in extended attribute mode
+    extended_attribute: u8,
+
+    /// Tile number of the background tile being fetched when it's in the split
+    split_tile: Option<u8>,
+
+    audio_registers: [u8; (AUDIO_END - AUDIO_START + 1) as usize],
+}
+
+impl Mmc5 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            prg_rom: rom.prg_rom,
+            exram: [0; EXRAM_SIZE],
+            prg_mode: 3,
+            chr_mode: 0,
+            prg_ram_protect: [0; 2],
+            exram_mode: ExramMode::Nametable,
+            nametable_mapping: 0,
+            fill_tile: 0,
+            fill_attribute: 0,
+            prg_banks: [0, 0, 0, 0, 0xFF],
+            chr_banks: [0; 12],
+            chr_upper: 0,
+            last_written_set_b: false,
+            split_control: 0,
+            split_scroll: 0,
+            split_bank: 0,
+            irq_compare: 0,
+            irq_enabled: false,
+            irq_pending: false,
+            in_frame: false,
+            scanline: 0,
+            multiplicand: 0xFF,
+            multiplier: 0xFF,
+            large_sprites: false,
+            rendering: false,
+            last_ppu_address: 0,
+            repeated_reads: 0,
+            ppu_idle_cycles: 0,
+            fetch_index: 0,
+            extended_attribute: 0,
+            split_tile: None,
+            audio_registers: [0; (AUDIO_END - AUDIO_START + 1) as usize],
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        match address {
+            AUDIO_START..=AUDIO_END => {
+                self.audio_registers[(address - AUDIO_START) as usize] = value
+            }
+            0x5100 => self.prg_mode = value & 0x03,
+            0x5101 => self.chr_mode = value & 0x03,
+            0x5102 => self.prg_ram_protect[0] = value & 0x03,
+            0x5103 => self.prg_ram_protect[1] = value & 0x03,
+            0x5104 => {
+                self.exram_mode = match value & 0x03 {
+                    0 => ExramMode::Nametable,
+                    1 => ExramMode::ExtendedAttributes,
+                    2 => ExramMode::Ram,
+                    _ => ExramMode::Rom,
+                }
+            }
+            0x5105 => self.nametable_mapping = value,
+            0x5106 => self.fill_tile = value,
+            0x5107 => self.fill_attribute = value & 0x03,
+            0x5113..=0x5117 => self.prg_banks[(address - 0x5113) as usize] = value,
+            0x5120..=0x512B => {
+                let register = (address - 0x5120) as usize;
+                self.chr_banks[register] = ((self.chr_upper as u16) << 8) | value as u16;
+                self.last_written_set_b = register >= 8;
+            }
+            0x5130 => self.chr_upper = value & 0x03,
+            0x5200 => self.split_control = value,
+            0x5201 => self.split_scroll = value,
+            0x5202 => self.split_bank = value,
+            0x5203 => self.irq_compare = value,
+            0x5204 => self.irq_enabled = value & 0x80 != 0,
+            0x5205 => self.multiplicand = value,
+            0x5206 => self.multiplier = value,
+            EXRAM_START..=EXRAM_END => {
+                let offset = (address - EXRAM_START) as usize;
+                match self.exram_mode {
+                    // only writable while rendering when the PPU uses it, 0 is written otherwise
+                    ExramMode::Nametable | ExramMode::ExtendedAttributes => {
+                        self.exram[offset] = if self.in_frame { value } else { 0 };
+                    }
+                    ExramMode::Ram => self.exram[offset] = value,
+                    ExramMode::Rom => {}
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn irq_status(&self) -> u8 {
+        ((self.irq_pending as u8) << 7) | ((self.in_frame as u8) << 6)
+    }
+
+    fn product(&self) -> u16 {
+        self.multiplicand as u16 * self.multiplier as u16
+    }
+
+    fn read_register(&self, address: u16) -> Option<u8> {
+        match address {
+            // no channel is playing and there's no PCM IRQ
+            0x5010 | 0x5015 => Some(0),
+            0x5204 => Some(self.irq_status()),
+            0x5205 => Some(self.product() as u8),
+            0x5206 => Some((self.product() >> 8) as u8),
+            EXRAM_START..=EXRAM_END => match self.exram_mode {
+                ExramMode::Ram | ExramMode::Rom => {
+                    Some(self.exram[(address - EXRAM_START) as usize])
+                }
+                ExramMode::Nametable | ExramMode::ExtendedAttributes => None,
+            },
+            _ => None,
+        }
+    }
+
+    /// Where an address in `$6000-$FFFF` is, `true` meaning PRG ROM
+    fn prg_offset(&self, address: u16) -> (bool, usize) {
+        if address < PRG_ROM_START {
+            let bank = (self.prg_banks[0] & 0x0F) as usize;
+            return (
+                false,
+                bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE),
+            );
+        }
+
+        // register and size in 8 KiB banks
+        let slot = ((address - PRG_ROM_START) as usize) / PRG_BANK_SIZE;
+        let (register, size) = match (self.prg_mode, slot) {
+            (0, _) => (4, 4),
+            (1 | 2, 0 | 1) => (2, 2),
+            (1, _) => (4, 2),
+            (2 | 3, slot) => (slot + 1, 1),
+            _ => unreachable!("the PRG mode is masked to 2 bits"),
+        };
+
+        let value = self.prg_banks[register];
+        // $5117 always selects PRG ROM
+        let rom = register == 4 || value & 0x80 != 0;
+        let bank = ((value & 0x7F) as usize & !(size - 1)) | (slot & (size - 1));
+
+        (
+            rom,
+            bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE),
+        )
+    }
+
+    fn prg_ram_writable(&self) -> bool {
+        self.prg_ram_protect == [0b10, 0b01]
+    }
+
+    /// Work out what the PPU is fetching, keeping track of scanlines
+    fn next_fetch(&mut self, address: u16) -> Fetch {
+        self.ppu_idle_cycles = 0;
+
+        let nametable = (0x2000..0x3000).contains(&address);
+        if nametable && address == self.last_ppu_address {
+            self.repeated_reads = self.repeated_reads.saturating_add(1);
+            if self.repeated_reads == 2 {
+                self.scanline_start();
+            }
+        } else {
+            self.repeated_reads = 0;
+        }
+        self.last_ppu_address = address;
+
+        if !(self.in_frame && self.rendering) {
+            return Fetch::Other;
+        }
+
+        let index = self.fetch_index;
+        self.fetch_index = self.fetch_index.saturating_add(1);
+
+        match index {
+            ..BACKGROUND_FETCHES => Fetch::Background {
+                column: index / 4 + 2,
+                next_scanline: false,
+            },
+            BACKGROUND_FETCHES..SPRITE_FETCHES_END => Fetch::Sprite,
+            SPRITE_FETCHES_END..PREFETCHES_END => Fetch::Background {
+                column: (index - SPRITE_FETCHES_END) / 4,
+                next_scanline: true,
+            },
+            _ => Fetch::Other,
+        }
+    }
+
+    fn scanline_start(&mut self) {
+        if self.in_frame {
+            self.scanline = self.scanline.wrapping_add(1);
+            if self.scanline == self.irq_compare {
+                self.irq_pending = true;
+            }
+        } else {
+            self.in_frame = true;
+            self.scanline = 0;
+        }
+
+        self.fetch_index = 0;
+    }
+
+    fn leave_frame(&mut self) {
+        self.in_frame = false;
+        self.repeated_reads = 0;
+        self.last_ppu_address = 0;
+    }
+
+    fn split_column(&self, column: u8) -> bool {
+        let threshold = self.split_control & 0x1F;
+        let right_side = self.split_control & 0x40 != 0;
+        let column = column & 0x1F;
+
+        self.split_control & 0x80 != 0
+            && matches!(
+                self.exram_mode,
+                ExramMode::Nametable | ExramMode::ExtendedAttributes
+            )
+            && if right_side {
+                column >= threshold
+            } else {
+                column < threshold
+            }
+    }
+
+    /// Vertical position in the split nametable
+    fn split_y(&self, next_scanline: bool) -> usize {
+        let scanline = self.scanline as u16 + next_scanline as u16;
+        ((self.split_scroll as u16 + scanline) % VISIBLE_SCANLINES) as usize
+    }
+
+    fn nametable_read(&self, address: u16, ciram: &Ciram) -> u8 {
+        let table = (address >> 10) & 0x03;
+        let offset = address as usize % NAMETABLE_SIZE;
+
+        match (self.nametable_mapping >> (table * 2)) & 0x03 {
+            page @ (0 | 1) => ciram[(page as usize * NAMETABLE_SIZE + offset) % CIRAM_SIZE],
+            2 => match self.exram_mode {
+                ExramMode::Nametable | ExramMode::ExtendedAttributes => self.exram[offset],
+                ExramMode::Ram | ExramMode::Rom => 0,
+            },
+            _ if offset >= ATTRIBUTE_TABLE => self.fill_attribute * 0x55,
+            _ => self.fill_tile,
+        }
+    }
+
+    fn nametable_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        let table = (address >> 10) & 0x03;
+        let offset = address as usize % NAMETABLE_SIZE;
+
+        match (self.nametable_mapping >> (table * 2)) & 0x03 {
+            page @ (0 | 1) => ciram[(page as usize * NAMETABLE_SIZE + offset) % CIRAM_SIZE] = value,
+            2 => match self.exram_mode {
+                ExramMode::Nametable | ExramMode::ExtendedAttributes => self.exram[offset] = value,
+                ExramMode::Ram | ExramMode::Rom => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Read a nametable byte for a background fetch, from the split if the tile is in it
+    fn background_nametable_read(
+        &mut self,
+        address: u16,
+        column: u8,
+        next_scanline: bool,
+        ciram: &Ciram,
+    ) -> u8 {
+        let offset = address as usize % NAMETABLE_SIZE;
+        let tile = offset < ATTRIBUTE_TABLE;
+
+        if tile {
+            self.split_tile = self.split_column(column).then(|| {
+                let y = self.split_y(next_scanline);
+                self.exram[(y / 8) * 32 + (column & 0x1F) as usize]
+            });
+            if let Some(tile) = self.split_tile {
+                return tile;
+            }
+
+            self.extended_attribute = self.exram[offset];
+            return self.nametable_read(address, ciram);
+        }
+
+        let palette = if self.split_tile.is_some() {
+            let y = self.split_y(next_scanline);
+            let column = (column & 0x1F) as usize;
+            let attribute = self.exram[ATTRIBUTE_TABLE + (y / 32) * 8 + column / 4];
+            let shift = ((y / 16) & 1) * 4 + ((column / 2) & 1) * 2;
+            (attribute >> shift) & 0x03
+        } else if self.exram_mode == ExramMode::ExtendedAttributes {
+            self.extended_attribute >> 6
+        } else {
+            return self.nametable_read(address, ciram);
+        };
+
+        // the PPU picks 2 bits depending on where it thinks the tile is, so they're all the same
+        palette * 0x55
+    }
+
+    fn background_pattern_read(&self, address: u16, next_scanline: bool) -> u8 {
+        if let Some(tile) = self.split_tile {
+            let fine_y = self.split_y(next_scanline) % 8;
+            let offset = self.split_bank as usize * CHR_PAGE_SIZE
+                + tile as usize * 16
+                + (address as usize & 0x08)
+                + fine_y;
+            return self.chr.read(offset);
+        }
+
+        if self.exram_mode == ExramMode::ExtendedAttributes {
+            let bank = ((self.chr_upper as usize) << 6) | (self.extended_attribute & 0x3F) as usize;
+            return self
+                .chr
+                .read(bank * CHR_PAGE_SIZE + (address as usize % CHR_PAGE_SIZE));
+        }
+
+        self.chr
+            .read(self.chr_offset(address, self.set_b(Fetch::Other, true)))
+    }
+
+    /// Whether CHR set B is used for a fetch, `background` telling what set B is for with 8x16 sprites
+    fn set_b(&self, fetch: Fetch, background: bool) -> bool {
+        if self.large_sprites && self.in_frame && self.rendering {
+            match fetch {
+                Fetch::Sprite => false,
+                Fetch::Background { .. } => true,
+                Fetch::Other => background,
+            }
+        } else {
+            self.last_written_set_b
+        }
+    }
+
+    fn chr_offset(&self, address: u16, set_b: bool) -> usize {
+        let unit = CHR_SIZE >> self.chr_mode;
+        let banks_per_unit = 8 >> self.chr_mode;
+
+        let register = if set_b {
+            // set B only covers 4 KiB and is repeated in both pattern tables
+            let local = if self.chr_mode == 0 {
+                address as usize
+            } else {
+                address as usize % CHR_PAGE_SIZE
+            };
+            8 + ((local / unit + 1) * banks_per_unit - 1).min(3)
+        } else {
+            (address as usize / unit + 1) * banks_per_unit - 1
+        };
+
+        self.chr_banks[register] as usize * unit + (address as usize % unit)
+    }
+}
+
+impl Mapper for Mmc5 {
+    fn cpu_read(&mut self, address: u16, open_bus: u8) -> u8 {
+        match address {
+            0x5204 => {
+                let status = self.irq_status();
+                self.irq_pending = false;
+                status
+            }
+            NMI_VECTOR_LOW | NMI_VECTOR_HIGH => {
+                self.leave_frame();
+                self.cpu_peek(address).unwrap_or(open_bus)
+            }
+            _ => self.cpu_peek(address).unwrap_or(open_bus),
+        }
+    }
+
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address < PRG_RAM_START {
+            return self.read_register(address);
+        }
+
+        match self.prg_offset(address) {
+            (true, offset) => read_prg(&self.prg_rom, offset),
+            (false, offset) => read_prg(&self.prg_ram, offset),
+        }
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address < PRG_RAM_START {
+            self.write_register(address, value);
+            return;
+        }
+
+        if let (false, offset) = self.prg_offset(address)
+            && self.prg_ram_writable()
+            && !self.prg_ram.is_empty()
+        {
+            let len = self.prg_ram.len();
+            self.prg_ram[offset % len] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        let fetch = self.next_fetch(address);
+
+        match (address, fetch) {
+            (..=CHR_END, Fetch::Background { next_scanline, .. }) => {
+                self.background_pattern_read(address, next_scanline)
+            }
+            (..=CHR_END, Fetch::Other | Fetch::Sprite) => self
+                .chr
+                .read(self.chr_offset(address, self.set_b(fetch, false))),
+            (
+                _,
+                Fetch::Background {
+                    column,
+                    next_scanline,
+                },
+            ) => self.background_nametable_read(address, column, next_scanline, ciram),
+            _ => self.nametable_read(address, ciram),
+        }
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        match address {
+            ..=CHR_END => {
+                let offset = self.chr_offset(address, self.set_b(Fetch::Other, false));
+                self.chr.write(offset, value);
+            }
+            _ => self.nametable_write(address, value, ciram),
+        }
+    }
+
+    fn ppu_register_write(&mut self, address: u16, value: u8) {
+        match address {
+            0x2000 => self.large_sprites = value & 0x20 != 0,
+            0x2001 => {
+                self.rendering = value & 0x18 != 0;
+                if !self.rendering {
+                    self.leave_frame();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn irq(&self) -> bool {
+        self.irq_pending && self.irq_enabled
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.ppu_idle_cycles = self.ppu_idle_cycles.saturating_add(1);
+        if self.ppu_idle_cycles >= PPU_IDLE_CYCLES {
+            self.leave_frame();
+        }
+    }
+}
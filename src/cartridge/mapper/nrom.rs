@@ -0,0 +1,62 @@
+use super::{BankedChr, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg};
+use crate::cartridge::{Ciram, Nametables, ines::Rom};
+
+/// NROM, mapper 0, has no banking at all
+///
+/// NROM-128 has 16 KiB of PRG ROM mirrored at `$8000` and `$C000`, NROM-256 has 32 KiB.
+/// PRG RAM at `$6000-$7FFF` is only present if the header asks for it,
+/// it's mirrored if it's smaller than 8 KiB
+#[derive(Debug, Clone)]
+pub struct Nrom {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+}
+
+impl Nrom {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+        }
+    }
+}
+
+impl BankedChr for Nrom {
+    fn chr_offset(&self, address: u16) -> usize {
+        address as usize
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Nrom {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, (address - PRG_ROM_START) as usize);
+        }
+
+        prg_ram_offset(&self.prg_ram, address).map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if let Some(offset) = prg_ram_offset(&self.prg_ram, address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+}
@@ -0,0 +1,111 @@
+use crate::cartridge::Mirroring;
+
+/// PPU dots per scanline, the prescaler counts them 3 at a time, one CPU cycle
+const PRESCALER_PERIOD: i16 = 341;
+const PPU_DOTS_PER_CPU_CYCLE: i16 = 3;
+
+/// Which CPU address lines a board connects to the chip's 2 register select inputs
+///
+/// Boards using the same chip wire them differently, so each variant is its own mapper
+/// or NES 2.0 submapper. iNES files don't say which one a game uses,
+/// so several lines can be ORed together, no game writes to an address where they disagree.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Wiring {
+    /// Address lines connected to the first register select input
+    pub a0: u16,
+
+    /// Address lines connected to the second register select input
+    pub a1: u16,
+}
+
+impl Wiring {
+    /// The register an address selects, its top 4 bits followed by the 2 register select inputs
+    pub fn register(self, address: u16) -> u16 {
+        let a0 = address & self.a0 != 0;
+        let a1 = address & self.a1 != 0;
+
+        (address & 0xF000) | ((a1 as u16) << 1) | a0 as u16
+    }
+}
+
+/// The mirroring selected by 2 bits of a register, used by every chip but the VRC2
+pub fn mirroring(value: u8) -> Mirroring {
+    match value & 0x03 {
+        0 => Mirroring::Vertical,
+        1 => Mirroring::Horizontal,
+        2 => Mirroring::SingleScreenLower,
+        _ => Mirroring::SingleScreenUpper,
+    }
+}
+
+/// IRQ counter of the VRC4, VRC6 and VRC7
+///
+/// The 8 bit counter counts up from the latch and asserts the IRQ line when it overflows,
+/// it's reloaded from the latch at the same time. In cycle mode it's clocked every CPU cycle,
+/// in scanline mode a prescaler clocks it every 341 PPU dots, 113 2/3 CPU cycles.
+/// Unlike the MMC3 it doesn't watch the PPU, so it keeps counting during vertical blank.
+///
+/// See https://www.nesdev.org/wiki/VRC_IRQ
+#[derive(Debug, Clone, Default)]
+pub struct Irq {
+    pub latch: u8,
+    counter: u8,
+    prescaler: i16,
+
+    enabled: bool,
+    enable_after_acknowledge: bool,
+    cycle_mode: bool,
+    pending: bool,
+}
+
+impl Irq {
+    /// Write the control register, this acknowledges the IRQ
+    /// and reloads the counter and prescaler if it's enabled
+    pub fn write_control(&mut self, value: u8) {
+        self.enable_after_acknowledge = value & 0x01 != 0;
+        self.enabled = value & 0x02 != 0;
+        self.cycle_mode = value & 0x04 != 0;
+        self.pending = false;
+
+        if self.enabled {
+            self.counter = self.latch;
+            self.prescaler = PRESCALER_PERIOD;
+        }
+    }
+
+    /// Acknowledge the IRQ, the counter is enabled again if the control register asked for it
+    pub fn acknowledge(&mut self) {
+        self.pending = false;
+        self.enabled = self.enable_after_acknowledge;
+    }
+
+    pub fn pending(&self) -> bool {
+        self.pending
+    }
+
+    pub fn cpu_cycle(&mut self) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.cycle_mode {
+            self.clock();
+            return;
+        }
+
+        self.prescaler -= PPU_DOTS_PER_CPU_CYCLE;
+        if self.prescaler <= 0 {
+            self.prescaler += PRESCALER_PERIOD;
+            self.clock();
+        }
+    }
+
+    fn clock(&mut self) {
+        if self.counter == 0xFF {
+            self.counter = self.latch;
+            self.pending = true;
+        } else {
+            self.counter += 1;
+        }
+    }
+}
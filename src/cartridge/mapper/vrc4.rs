@@ -0,0 +1,234 @@
+use super::{
+    BankedChr, Chr, Mapper, PRG_RAM_START, PRG_ROM_START, prg_ram_offset, read_prg,
+    vrc::{self, Irq, Wiring},
+};
+use crate::cartridge::{Ciram, Mirroring, Nametables, ines::Rom};
+
+/// VRC2 boards without PRG RAM have a 1 bit latch there instead
+const LATCH_END: u16 = 0x6FFF;
+
+const PRG_BANK_SIZE: usize = 8 * 1024;
+const CHR_BANK_SIZE: usize = 1024;
+
+const A0: u16 = 0x0001;
+const A1: u16 = 0x0002;
+const A2: u16 = 0x0004;
+const A3: u16 = 0x0008;
+const A6: u16 = 0x0040;
+const A7: u16 = 0x0080;
+
+/// Which of the 2 chips a board has
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Revision {
+    /// No IRQ, 1 bit mirroring and 8 bit CHR banks
+    Vrc2,
+
+    /// IRQ counter, single screen mirroring, PRG swap mode and 9 bit CHR banks
+    Vrc4,
+}
+
+/// VRC2 and VRC4, mappers 21, 22, 23 and 25
+///
+/// PRG ROM is switched in 8 KiB banks and CHR in 1 KiB banks.
+/// Registers are selected by the top 4 bits of the address and 2 address lines
+/// that depend on the board, the registers below use the VRC4f wiring:
+///
+/// | Register      | Use                                                               |
+/// |---------------|-------------------------------------------------------------------|
+/// | `$8000`       | PRG bank at `$8000`, or `$C000` in PRG swap mode                  |
+/// | `$9000-$9001` | Mirroring, all 4 registers on the VRC2                            |
+/// | `$9002`       | PRG swap mode in bit 1, VRC4 only                                 |
+/// | `$A000`       | PRG bank at `$A000`                                               |
+/// | `$B000-$E003` | CHR banks, 2 registers each for the low and high 4 bits           |
+/// | `$F000-$F003` | IRQ latch low and high 4 bits, control and acknowledge, VRC4 only |
+///
+/// The last 2 PRG banks are fixed to `$C000`, or `$8000` in PRG swap mode, and `$E000`.
+/// The board wiring comes from the mapper and NES 2.0 submapper:
+///
+/// | Mapper | Submapper 1 | Submapper 2 | Submapper 3 |
+/// |--------|-------------|-------------|-------------|
+/// | 21     | VRC4a       | VRC4c       |             |
+/// | 22     | VRC2a       |             |             |
+/// | 23     | VRC4f       | VRC4e       | VRC2b       |
+/// | 25     | VRC4b       | VRC4d       | VRC2c       |
+///
+/// Without a submapper the address lines of every variant of the mapper are ORed together,
+/// and mappers 21, 23 and 25 use a VRC4, which also runs the VRC2 games.
+/// The PRG RAM enable bit of `$9002` is ignored, VRC2 games on the same mappers never set it.
+///
+/// See https://www.nesdev.org/wiki/VRC2_and_VRC4
+#[derive(Debug, Clone)]
+pub struct Vrc4 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    pub revision: Revision,
+    pub wiring: Wiring,
+
+    /// VRC2a only connects the upper 7 bits of CHR banks, the value is shifted right once
+    chr_shift: u8,
+
+    prg_banks: [u8; 2],
+    prg_swap: bool,
+    chr_banks: [u16; 8],
+    latch: u8,
+    irq: Irq,
+}
+
+impl Vrc4 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+
+        let (revision, a0, a1) = match (rom.header.mapper, rom.header.submapper) {
+            (21, 1) => (Revision::Vrc4, A1, A2),
+            (21, 2) => (Revision::Vrc4, A6, A7),
+            (21, _) => (Revision::Vrc4, A1 | A6, A2 | A7),
+            (22, _) => (Revision::Vrc2, A1, A0),
+            (23, 1) => (Revision::Vrc4, A0, A1),
+            (23, 2) => (Revision::Vrc4, A2, A3),
+            (23, 3) => (Revision::Vrc2, A0, A1),
+            (23, _) => (Revision::Vrc4, A0 | A2, A1 | A3),
+            (25, 1) => (Revision::Vrc4, A1, A0),
+            (25, 2) => (Revision::Vrc4, A3, A2),
+            (25, 3) => (Revision::Vrc2, A1, A0),
+            _ => (Revision::Vrc4, A1 | A3, A0 | A2),
+        };
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            chr_shift: (rom.header.mapper == 22) as u8,
+            prg_rom: rom.prg_rom,
+            revision,
+            wiring: Wiring { a0, a1 },
+            prg_banks: [0; 2],
+            prg_swap: false,
+            chr_banks: [0; 8],
+            latch: 0,
+            irq: Irq::default(),
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        let register = self.wiring.register(address);
+        let vrc4 = self.revision == Revision::Vrc4;
+
+        match register {
+            0x8000..=0x8003 => self.prg_banks[0] = value & 0x1F,
+            0x9000..=0x9003 if !vrc4 => {
+                self.nametables.mirroring = if value & 1 == 0 {
+                    Mirroring::Vertical
+                } else {
+                    Mirroring::Horizontal
+                };
+            }
+            0x9000 | 0x9001 => self.nametables.mirroring = vrc::mirroring(value),
+            0x9002 => self.prg_swap = value & 0x02 != 0,
+            0xA000..=0xA003 => self.prg_banks[1] = value & 0x1F,
+            0xB000..=0xE003 => self.write_chr_bank(register, value),
+            0xF000 if vrc4 => self.irq.latch = (self.irq.latch & 0xF0) | (value & 0x0F),
+            0xF001 if vrc4 => self.irq.latch = (self.irq.latch & 0x0F) | (value << 4),
+            0xF002 if vrc4 => self.irq.write_control(value),
+            0xF003 if vrc4 => self.irq.acknowledge(),
+            _ => {}
+        }
+    }
+
+    /// Write half of a CHR bank, the first 2 registers of each range are the low and high bits
+    /// of a bank, the last 2 the ones of the next bank
+    fn write_chr_bank(&mut self, register: u16, value: u8) {
+        let index = (((register - 0xB000) >> 12) * 2 + ((register >> 1) & 1)) as usize;
+        let bank = &mut self.chr_banks[index];
+
+        if register & 1 == 0 {
+            *bank = (*bank & !0x0F) | (value & 0x0F) as u16;
+        } else {
+            let mask = match self.revision {
+                Revision::Vrc2 => 0x0F,
+                Revision::Vrc4 => 0x1F,
+            };
+            *bank = (*bank & 0x0F) | (((value & mask) as u16) << 4);
+        }
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let second_last = (self.prg_rom.len() / PRG_BANK_SIZE).saturating_sub(2);
+
+        let bank = match (address >> 13) & 0x03 {
+            0 if self.prg_swap => second_last,
+            0 => self.prg_banks[0] as usize,
+            1 => self.prg_banks[1] as usize,
+            2 if self.prg_swap => self.prg_banks[0] as usize,
+            2 => second_last,
+            _ => second_last + 1,
+        };
+
+        bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE)
+    }
+
+    /// Whether an address is the VRC2 latch, which replaces PRG RAM in the first 4 KiB
+    fn is_latch(&self, address: u16) -> bool {
+        self.revision == Revision::Vrc2
+            && self.prg_ram.is_empty()
+            && (PRG_RAM_START..=LATCH_END).contains(&address)
+    }
+}
+
+impl BankedChr for Vrc4 {
+    fn chr_offset(&self, address: u16) -> usize {
+        let bank = self.chr_banks[(address >> 10) as usize] >> self.chr_shift;
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Vrc4 {
+    fn cpu_read(&mut self, address: u16, open_bus: u8) -> u8 {
+        if self.is_latch(address) {
+            // only bit 0 is driven
+            return (open_bus & !1) | self.latch;
+        }
+
+        self.cpu_peek(address).unwrap_or(open_bus)
+    }
+
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        prg_ram_offset(&self.prg_ram, address).map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_register(address, value);
+        } else if self.is_latch(address) {
+            self.latch = value & 1;
+        } else if let Some(offset) = prg_ram_offset(&self.prg_ram, address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+
+    fn irq(&self) -> bool {
+        self.irq.pending()
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.irq.cpu_cycle();
+    }
+}
@@ -0,0 +1,337 @@
+use super::{
+    BankedChr, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg,
+    vrc::{self, Irq, Wiring},
+};
+use crate::{
+    cartridge::{Ciram, Nametables, ines::Rom},
+    mixer::APU_PULSE_MAX,
+};
+
+const PRG_BANK_SIZE: usize = 8 * 1024;
+const CHR_BANK_SIZE: usize = 1024;
+
+/// Output of one step of the channels' sum, a pulse at volume 15 is as loud as an APU pulse
+const AUDIO_STEP: f32 = APU_PULSE_MAX / 15.0;
+
+/// A pulse channel, `$9000-$9002` and `$A000-$A002`
+///
+/// It steps through 16 duty positions, the output is its volume while the position
+/// is at most the duty, or all the time in constant mode
+#[derive(Debug, Clone, Default)]
+struct Pulse {
+    volume: u8,
+    duty: u8,
+    constant: bool,
+    enabled: bool,
+    period: u16,
+    divider: u16,
+    position: u8,
+}
+
+impl Pulse {
+    fn write(&mut self, register: u16, value: u8) {
+        match register {
+            0 => {
+                self.constant = value & 0x80 != 0;
+                self.duty = (value >> 4) & 0x07;
+                self.volume = value & 0x0F;
+            }
+            1 => self.period = (self.period & 0x0F00) | value as u16,
+            _ => {
+                self.period = (self.period & 0x00FF) | (((value & 0x0F) as u16) << 8);
+                self.enabled = value & 0x80 != 0;
+                // disabling the channel restarts the duty cycle
+                if !self.enabled {
+                    self.position = 0x0F;
+                }
+            }
+        }
+    }
+
+    fn cpu_cycle(&mut self, shift: u8) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.divider == 0 {
+            self.divider = self.period >> shift;
+            self.position = self.position.wrapping_sub(1) & 0x0F;
+        } else {
+            self.divider -= 1;
+        }
+    }
+
+    fn output(&self) -> u8 {
+        if self.enabled && (self.constant || self.position <= self.duty) {
+            self.volume
+        } else {
+            0
+        }
+    }
+}
+
+/// The sawtooth channel, `$B000-$B002`
+///
+/// An accumulator has the rate added to it every 2 clocks and is reset every 14,
+/// its top 5 bits are the output
+#[derive(Debug, Clone, Default)]
+struct Sawtooth {
+    rate: u8,
+    enabled: bool,
+    period: u16,
+    divider: u16,
+    step: u8,
+    accumulator: u8,
+}
+
+impl Sawtooth {
+    const STEPS: u8 = 14;
+
+    fn write(&mut self, register: u16, value: u8) {
+        match register {
+            0 => self.rate = value & 0x3F,
+            1 => self.period = (self.period & 0x0F00) | value as u16,
+            _ => {
+                self.period = (self.period & 0x00FF) | (((value & 0x0F) as u16) << 8);
+                self.enabled = value & 0x80 != 0;
+                if !self.enabled {
+                    self.step = 0;
+                    self.accumulator = 0;
+                }
+            }
+        }
+    }
+
+    fn cpu_cycle(&mut self, shift: u8) {
+        if !self.enabled {
+            return;
+        }
+
+        if self.divider != 0 {
+            self.divider -= 1;
+            return;
+        }
+
+        self.divider = self.period >> shift;
+        self.step += 1;
+        if self.step == Self::STEPS {
+            self.step = 0;
+            self.accumulator = 0;
+        } else if self.step.is_multiple_of(2) {
+            self.accumulator = self.accumulator.wrapping_add(self.rate);
+        }
+    }
+
+    fn output(&self) -> u8 {
+        self.accumulator >> 3
+    }
+}
+
+/// VRC6, mappers 24 and 26
+///
+/// PRG ROM is switched in a 16 KiB bank and an 8 KiB bank, CHR in 1 or 2 KiB banks.
+/// Registers are selected by the top 4 bits of the address and 2 address lines,
+/// mapper 26 swaps them, the registers below use mapper 24's wiring:
+///
+/// | Register      | Use                                                   |
+/// |---------------|-------------------------------------------------------|
+/// | `$8000`       | 16 KiB PRG bank at `$8000`                            |
+/// | `$9000-$9002` | Pulse 1                                               |
+/// | `$9003`       | Audio halt and frequency scaling                      |
+/// | `$A000-$A002` | Pulse 2                                               |
+/// | `$B000-$B002` | Sawtooth                                              |
+/// | `$B003`       | CHR banking mode, mirroring and PRG RAM enable        |
+/// | `$C000`       | 8 KiB PRG bank at `$C000`, the last one is at `$E000` |
+/// | `$D000-$E003` | CHR banks R0 to R7                                    |
+/// | `$F000-$F002` | IRQ latch, control and acknowledge                    |
+///
+/// The CHR banking modes of `$B003` are:
+/// - 0: R0 to R7 are 1 KiB banks
+/// - 1: R0 to R3 are 2 KiB banks
+/// - 2 and 3: R0 to R3 are 1 KiB banks at `$0000`, R4 and R5 2 KiB banks at `$1000`
+///
+/// For 2 KiB banks, bit 5 of `$B003` takes their 1 KiB half from the PPU address,
+/// otherwise both halves are the same 1 KiB bank.
+/// Nametables are always in CIRAM, the mode using CHR ROM as nametables isn't supported.
+///
+/// The expansion audio is clocked by `cpu_cycle` and returned by `audio`.
+///
+/// See https://www.nesdev.org/wiki/VRC6
+#[derive(Debug, Clone)]
+pub struct Vrc6 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    pub wiring: Wiring,
+
+    prg_16k_bank: u8,
+    prg_8k_bank: u8,
+    chr_banks: [u8; 8],
+    banking_control: u8,
+    irq: Irq,
+
+    pulses: [Pulse; 2],
+    sawtooth: Sawtooth,
+
+    /// `$9003`
+    audio_control: u8,
+}
+
+impl Vrc6 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+        let wiring = match rom.header.mapper {
+            26 => Wiring {
+                a0: 0x0002,
+                a1: 0x0001,
+            },
+            _ => Wiring {
+                a0: 0x0001,
+                a1: 0x0002,
+            },
+        };
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+            wiring,
+            prg_16k_bank: 0,
+            prg_8k_bank: 0,
+            chr_banks: [0; 8],
+            banking_control: 0,
+            irq: Irq::default(),
+            pulses: [Pulse::default(), Pulse::default()],
+            sawtooth: Sawtooth::default(),
+            audio_control: 0,
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        let register = self.wiring.register(address);
+
+        match register {
+            0x8000..=0x8003 => self.prg_16k_bank = value & 0x0F,
+            0x9000..=0x9002 => self.pulses[0].write(register & 0x03, value),
+            0x9003 => self.audio_control = value & 0x07,
+            0xA000..=0xA002 => self.pulses[1].write(register & 0x03, value),
+            0xB000..=0xB002 => self.sawtooth.write(register & 0x03, value),
+            0xB003 => {
+                self.banking_control = value;
+                self.nametables.mirroring = vrc::mirroring(value >> 2);
+            }
+            0xC000..=0xC003 => self.prg_8k_bank = value & 0x1F,
+            0xD000..=0xD003 => self.chr_banks[(register & 0x03) as usize] = value,
+            0xE000..=0xE003 => self.chr_banks[4 + (register & 0x03) as usize] = value,
+            0xF000 => self.irq.latch = value,
+            0xF001 => self.irq.write_control(value),
+            0xF002 => self.irq.acknowledge(),
+            _ => {}
+        }
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let bank = match (address >> 13) & 0x03 {
+            0 => self.prg_16k_bank as usize * 2,
+            1 => self.prg_16k_bank as usize * 2 + 1,
+            2 => self.prg_8k_bank as usize,
+            _ => (self.prg_rom.len() / PRG_BANK_SIZE).saturating_sub(1),
+        };
+
+        bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE)
+    }
+
+    fn prg_ram_offset(&self, address: u16) -> Option<usize> {
+        let enabled = self.banking_control & 0x80 != 0;
+        prg_ram_offset(&self.prg_ram, address).filter(|_| enabled)
+    }
+
+    /// How many bits the channel periods are shifted right by
+    fn frequency_shift(&self) -> u8 {
+        match self.audio_control {
+            control if control & 0x04 != 0 => 8,
+            control if control & 0x02 != 0 => 4,
+            _ => 0,
+        }
+    }
+}
+
+impl BankedChr for Vrc6 {
+    fn chr_offset(&self, address: u16) -> usize {
+        let slot = (address >> 10) as usize;
+        let a10_from_address = self.banking_control & 0x20 != 0;
+
+        // 1 KiB banks index the registers directly, 2 KiB banks use one register for 2 slots
+        let (register, large) = match self.banking_control & 0x03 {
+            0 => (slot, false),
+            1 => (slot / 2, true),
+            _ if slot < 4 => (slot, false),
+            _ => (4 + (slot - 4) / 2, true),
+        };
+
+        let bank = self.chr_banks[register] as usize;
+        let bank = if large && a10_from_address {
+            (bank & !1) | (slot & 1)
+        } else {
+            bank
+        };
+
+        bank * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Vrc6 {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        self.prg_ram_offset(address)
+            .map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_register(address, value);
+        } else if let Some(offset) = self.prg_ram_offset(address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+
+    fn irq(&self) -> bool {
+        self.irq.pending()
+    }
+
+    fn audio(&self) -> f32 {
+        let output = self.pulses[0].output() + self.pulses[1].output() + self.sawtooth.output();
+        output as f32 * AUDIO_STEP
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.irq.cpu_cycle();
+
+        // bit 0 halts every channel
+        if self.audio_control & 0x01 == 0 {
+            let shift = self.frequency_shift();
+            for pulse in &mut self.pulses {
+                pulse.cpu_cycle(shift);
+            }
+            self.sawtooth.cpu_cycle(shift);
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use super::{
+    BankedChr, Chr, Mapper, PRG_ROM_START, prg_ram_offset, read_prg,
+    vrc::{self, Irq, Wiring},
+};
+use crate::cartridge::{Ciram, Nametables, ines::Rom};
+
+const PRG_BANK_SIZE: usize = 8 * 1024;
+const CHR_BANK_SIZE: usize = 1024;
+
+/// The audio ports are decoded by the chip itself, they don't depend on the board wiring
+const AUDIO_PORT_MASK: u16 = 0xF030;
+const AUDIO_REGISTER_SELECT: u16 = 0x9010;
+const AUDIO_REGISTER_DATA: u16 = 0x9030;
+
+/// Registers of the FM synthesizer, `$00-$3F`
+const AUDIO_REGISTERS: usize = 0x40;
+
+const A3: u16 = 0x0008;
+const A4: u16 = 0x0010;
+
+/// VRC7, mapper 85
+///
+/// PRG ROM is switched in 8 KiB banks and CHR in 1 KiB banks.
+/// Registers are selected by the top 4 bits of the address and one address line,
+/// A4 on VRC7a boards (NES 2.0 submapper 2) and A3 on VRC7b boards (submapper 1),
+/// the registers below use the VRC7a wiring:
+///
+/// | Register      | Use                                                  |
+/// |---------------|------------------------------------------------------|
+/// | `$8000`       | PRG bank at `$8000`                                  |
+/// | `$8010`       | PRG bank at `$A000`                                  |
+/// | `$9000`       | PRG bank at `$C000`, the last one is at `$E000`      |
+/// | `$9010`       | Audio register select                                |
+/// | `$9030`       | Audio register data                                  |
+/// | `$A000-$D010` | CHR banks                                            |
+/// | `$E000`       | Mirroring, audio silence and PRG RAM enable in bit 7 |
+/// | `$E010`       | IRQ latch                                            |
+/// | `$F000-$F010` | IRQ control and acknowledge                          |
+///
+/// Without a submapper both lines are ORed together.
+/// The FM synthesizer isn't emulated, its registers are stored and it's silent.
+///
+/// See https://www.nesdev.org/wiki/VRC7
+#[derive(Debug, Clone)]
+pub struct Vrc7 {
+    prg_rom: Vec<u8>,
+    prg_ram: Vec<u8>,
+    chr: Chr,
+    nametables: Nametables,
+
+    pub wiring: Wiring,
+
+    prg_banks: [u8; 3],
+    chr_banks: [u8; 8],
+    control: u8,
+    irq: Irq,
+
+    audio_address: u8,
+    audio_registers: [u8; AUDIO_REGISTERS],
+}
+
+impl Vrc7 {
+    pub fn new(mut rom: Rom) -> Self {
+        let prg_ram_size = rom.header.prg_ram_size + rom.header.prg_nvram_size;
+        let a0 = match rom.header.submapper {
+            1 => A3,
+            2 => A4,
+            _ => A3 | A4,
+        };
+
+        Self {
+            chr: Chr::new(&mut rom),
+            prg_ram: vec![0; prg_ram_size],
+            nametables: Nametables::new(rom.header.mirroring),
+            prg_rom: rom.prg_rom,
+            wiring: Wiring { a0, a1: 0 },
+            prg_banks: [0; 3],
+            chr_banks: [0; 8],
+            control: 0,
+            irq: Irq::default(),
+            audio_address: 0,
+            audio_registers: [0; AUDIO_REGISTERS],
+        }
+    }
+
+    fn write_register(&mut self, address: u16, value: u8) {
+        match address & AUDIO_PORT_MASK {
+            AUDIO_REGISTER_SELECT => {
+                self.audio_address = value;
+                return;
+            }
+            AUDIO_REGISTER_DATA => {
+                if let Some(register) = self.audio_registers.get_mut(self.audio_address as usize) {
+                    *register = value;
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let register = self.wiring.register(address);
+        match register {
+            0x8000 | 0x8001 => self.prg_banks[(register & 1) as usize] = value & 0x3F,
+            0x9000 => self.prg_banks[2] = value & 0x3F,
+            0xA000..=0xD001 => {
+                let index = ((register - 0xA000) >> 12) * 2 + (register & 1);
+                self.chr_banks[index as usize] = value;
+            }
+            0xE000 => {
+                self.control = value;
+                self.nametables.mirroring = vrc::mirroring(value);
+            }
+            0xE001 => self.irq.latch = value,
+            0xF000 => self.irq.write_control(value),
+            0xF001 => self.irq.acknowledge(),
+            _ => {}
+        }
+    }
+
+    fn prg_rom_offset(&self, address: u16) -> usize {
+        let bank = match (address >> 13) & 0x03 {
+            3 => (self.prg_rom.len() / PRG_BANK_SIZE).saturating_sub(1),
+            slot => self.prg_banks[slot as usize] as usize,
+        };
+
+        bank * PRG_BANK_SIZE + (address as usize % PRG_BANK_SIZE)
+    }
+
+    fn prg_ram_offset(&self, address: u16) -> Option<usize> {
+        let enabled = self.control & 0x80 != 0;
+        prg_ram_offset(&self.prg_ram, address).filter(|_| enabled)
+    }
+}
+
+impl BankedChr for Vrc7 {
+    fn chr_offset(&self, address: u16) -> usize {
+        let bank = self.chr_banks[(address >> 10) as usize];
+        bank as usize * CHR_BANK_SIZE + (address as usize % CHR_BANK_SIZE)
+    }
+
+    fn chr_and_nametables(&mut self) -> (&mut Chr, &mut Nametables) {
+        (&mut self.chr, &mut self.nametables)
+    }
+}
+
+impl Mapper for Vrc7 {
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        if address >= PRG_ROM_START {
+            return read_prg(&self.prg_rom, self.prg_rom_offset(address));
+        }
+
+        self.prg_ram_offset(address)
+            .map(|offset| self.prg_ram[offset])
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        if address >= PRG_ROM_START {
+            self.write_register(address, value);
+        } else if let Some(offset) = self.prg_ram_offset(address) {
+            self.prg_ram[offset] = value;
+        }
+    }
+
+    fn ppu_read(&mut self, address: u16, ciram: &Ciram) -> u8 {
+        self.read_chr_or_nametable(address, ciram)
+    }
+
+    fn ppu_write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        self.write_chr_or_nametable(address, value, ciram);
+    }
+
+    fn irq(&self) -> bool {
+        self.irq.pending()
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.irq.cpu_cycle();
+    }
+}
@@ -0,0 +1,66 @@
+use super::{Ciram, Mirroring};
+
+const NAMETABLE_SIZE: u16 = 0x0400;
+const FOUR_SCREEN_VRAM_SIZE: usize = 2 * 1024;
+
+/// Maps the PPU nametable range `$2000-$3EFF` onto VRAM according to the mirroring
+///
+/// Four screen cartridges provide 2 KiB of VRAM for the last 2 nametables,
+/// the first 2 stay in the console's CIRAM
+#[derive(Debug, Clone)]
+pub struct Nametables {
+    pub mirroring: Mirroring,
+    four_screen_vram: Vec<u8>,
+}
+
+/// Where a nametable address ends up
+enum Vram {
+    Ciram(usize),
+    Cartridge(usize),
+}
+
+impl Nametables {
+    pub fn new(mirroring: Mirroring) -> Self {
+        let four_screen_vram = match mirroring {
+            Mirroring::FourScreen => vec![0; FOUR_SCREEN_VRAM_SIZE],
+            _ => Vec::new(),
+        };
+
+        Self {
+            mirroring,
+            four_screen_vram,
+        }
+    }
+
+    pub fn read(&self, address: u16, ciram: &Ciram) -> u8 {
+        match self.map(address) {
+            Vram::Ciram(offset) => ciram[offset],
+            Vram::Cartridge(offset) => self.four_screen_vram[offset],
+        }
+    }
+
+    pub fn write(&mut self, address: u16, value: u8, ciram: &mut Ciram) {
+        match self.map(address) {
+            Vram::Ciram(offset) => ciram[offset] = value,
+            Vram::Cartridge(offset) => self.four_screen_vram[offset] = value,
+        }
+    }
+
+    fn map(&self, address: u16) -> Vram {
+        let table = (address >> 10) & 0x03;
+        let offset = (address % NAMETABLE_SIZE) as usize;
+
+        let page = match self.mirroring {
+            Mirroring::Horizontal => table >> 1,
+            Mirroring::Vertical => table & 1,
+            Mirroring::FourScreen if table >= 2 && !self.four_screen_vram.is_empty() => {
+                return Vram::Cartridge(((table & 1) * NAMETABLE_SIZE) as usize + offset);
+            }
+            Mirroring::FourScreen => table & 1,
+            Mirroring::SingleScreenLower => 0,
+            Mirroring::SingleScreenUpper => 1,
+        };
+
+        Vram::Ciram((page * NAMETABLE_SIZE) as usize + offset)
+    }
+}
@@ -0,0 +1,3 @@
+mod ines;
+mod mapper;
+mod nametables;
@@ -0,0 +1,198 @@
+use crate::cartridge::{
+    Mirroring,
+    ines::{ConsoleType, Format, HEADER_SIZE, Header, ParseError, Rom, TRAINER_SIZE, TvSystem},
+};
+
+/// Build a header from the bytes after the magic number
+fn header(bytes: [u8; 12]) -> Vec<u8> {
+    let mut header = b"NES\x1A".to_vec();
+    header.extend_from_slice(&bytes);
+    header
+}
+
+/// Build a whole file, filling PRG ROM with 1s and CHR ROM with 2s
+fn rom(header_bytes: [u8; 12]) -> Vec<u8> {
+    let mut file = header(header_bytes);
+    let parsed = Header::parse(&file).unwrap();
+
+    if parsed.trainer {
+        file.extend_from_slice(&[3; TRAINER_SIZE]);
+    }
+    file.extend(std::iter::repeat_n(1, parsed.prg_rom_size));
+    file.extend(std::iter::repeat_n(2, parsed.chr_rom_size));
+    file
+}
+
+#[test]
+fn ines() {
+    // 2 PRG banks, 1 CHR bank, vertical mirroring, battery, mapper 0x41
+    let header = Header::parse(&header([2, 1, 0x13, 0x40, 0, 1, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(
+        header,
+        Header {
+            format: Format::INes,
+            mapper: 0x41,
+            submapper: 0,
+            prg_rom_size: 32 * 1024,
+            chr_rom_size: 8 * 1024,
+            prg_ram_size: 0,
+            prg_nvram_size: 8 * 1024,
+            chr_ram_size: 0,
+            chr_nvram_size: 0,
+            battery: true,
+            mirroring: Mirroring::Vertical,
+            trainer: false,
+            tv_system: TvSystem::Pal,
+            console_type: ConsoleType::Nes,
+        }
+    );
+}
+
+#[test]
+fn ines_chr_ram() {
+    let header = Header::parse(&header([1, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(header.chr_rom_size, 0);
+    assert_eq!(header.chr_ram_size, 8 * 1024);
+    assert_eq!(header.prg_ram_size, 16 * 1024);
+    assert_eq!(header.mirroring, Mirroring::Horizontal);
+    assert_eq!(header.tv_system, TvSystem::Ntsc);
+}
+
+#[test]
+fn ines_four_screen() {
+    let header = Header::parse(&header([1, 1, 0x09, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(header.mirroring, Mirroring::FourScreen);
+}
+
+#[test]
+fn ines_garbage_in_padding() {
+    // "DiskDude!" starting at byte 7
+    let header = Header::parse(&header([
+        1, 1, 0x10, b'D', b'i', b's', b'k', b'D', b'u', b'd', b'e', b'!',
+    ]))
+    .unwrap();
+
+    assert_eq!(header.format, Format::INes);
+    assert_eq!(header.mapper, 1, "the upper mapper nibble must be ignored");
+    assert_eq!(header.console_type, ConsoleType::Nes);
+}
+
+#[test]
+fn nes20() {
+    let header = Header::parse(&header([
+        0x02, // PRG ROM LSB
+        0x10, // CHR ROM LSB
+        0x01, // vertical mirroring, mapper D0-D3
+        0x48, // NES 2.0, mapper D4-D7
+        0x53, // submapper 5, mapper D8-D11
+        0x21, // CHR ROM MSB 2, PRG ROM MSB 1
+        0x07, // no PRG NVRAM, 8 KiB PRG RAM
+        0x70, // 8 KiB CHR NVRAM, no CHR RAM
+        0x03, // Dendy
+        0x00, 0x00, 0x00,
+    ]))
+    .unwrap();
+
+    assert_eq!(
+        header,
+        Header {
+            format: Format::Nes20,
+            mapper: 0x340,
+            submapper: 5,
+            prg_rom_size: 0x102 * 16 * 1024,
+            chr_rom_size: 0x210 * 8 * 1024,
+            prg_ram_size: 8 * 1024,
+            prg_nvram_size: 0,
+            chr_ram_size: 0,
+            chr_nvram_size: 8 * 1024,
+            battery: false,
+            mirroring: Mirroring::Vertical,
+            trainer: false,
+            tv_system: TvSystem::Dendy,
+            console_type: ConsoleType::Nes,
+        }
+    );
+}
+
+#[test]
+fn nes20_exponent_multiplier() {
+    // PRG ROM 2^10 * 3, CHR ROM 2^4 * 7
+    let header = Header::parse(&header([0x29, 0x13, 0, 0x08, 0, 0xFF, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(header.prg_rom_size, 1024 * 3);
+    assert_eq!(header.chr_rom_size, 16 * 7);
+}
+
+#[test]
+fn nes20_size_overflow() {
+    let result = Header::parse(&header([0xFF, 0, 0, 0x08, 0, 0x0F, 0, 0, 0, 0, 0, 0]));
+
+    assert_eq!(result, Err(ParseError::SizeOverflow));
+}
+
+#[test]
+fn nes20_console_type() {
+    for (flags_7, extended, expected) in [
+        (0x09, 0, ConsoleType::VsSystem),
+        (0x0A, 0, ConsoleType::Playchoice10),
+        (0x0B, 0x15, ConsoleType::Extended(5)),
+    ] {
+        let header =
+            Header::parse(&header([1, 1, 0, flags_7, 0, 0, 0, 0, 0, extended, 0, 0])).unwrap();
+
+        assert_eq!(header.console_type, expected);
+    }
+}
+
+#[test]
+fn missing_header() {
+    assert_eq!(Rom::parse(b"NES\x1A"), Err(ParseError::MissingHeader));
+    assert_eq!(Rom::parse(&[]), Err(ParseError::MissingHeader));
+}
+
+#[test]
+fn invalid_magic() {
+    let mut file = rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    file[3] = 0;
+
+    assert_eq!(Rom::parse(&file), Err(ParseError::InvalidMagic));
+}
+
+#[test]
+fn truncated() {
+    let mut file = rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    file.pop();
+
+    assert_eq!(
+        Rom::parse(&file),
+        Err(ParseError::Truncated {
+            expected: HEADER_SIZE + 16 * 1024 + 8 * 1024,
+            actual: HEADER_SIZE + 16 * 1024 + 8 * 1024 - 1,
+        })
+    );
+}
+
+#[test]
+fn data() {
+    let rom = Rom::parse(&rom([2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(rom.trainer, None);
+    assert_eq!(rom.prg_rom, vec![1; 32 * 1024]);
+    assert_eq!(rom.chr_rom, vec![2; 8 * 1024]);
+}
+
+#[test]
+fn trainer() {
+    let mut file = rom([1, 1, 0x04, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    // trailing data is ignored
+    file.extend_from_slice(&[4; 100]);
+
+    let rom = Rom::parse(&file).unwrap();
+
+    assert_eq!(rom.trainer, Some(vec![3; TRAINER_SIZE]));
+    assert_eq!(rom.prg_rom, vec![1; 16 * 1024]);
+    assert_eq!(rom.chr_rom, vec![2; 8 * 1024]);
+}
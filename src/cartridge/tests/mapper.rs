@@ -0,0 +1,97 @@
+use crate::{
+    cartridge::{
+        CIRAM_SIZE, Ciram, Mapper,
+        ines::{Header, Rom},
+        mapper::{self, UnsupportedMapper},
+    },
+    memory::{AccessKind, Memory, bus::PpuRegisters},
+};
+
+mod discrete;
+mod mmc1;
+mod mmc2;
+mod mmc3;
+mod mmc5;
+mod nrom;
+mod vrc4;
+mod vrc6;
+mod vrc7;
+
+/// Build a ROM from the bytes after the magic number,
+/// every byte of PRG and CHR ROM holds the number of the 1 KiB block it's in
+fn rom(header_bytes: [u8; 12]) -> Rom {
+    let mut file = b"NES\x1A".to_vec();
+    file.extend_from_slice(&header_bytes);
+    let header = Header::parse(&file).unwrap();
+
+    for size in [header.prg_rom_size, header.chr_rom_size] {
+        file.extend((0..size).map(|offset| (offset / 1024) as u8));
+    }
+
+    Rom::parse(&file).unwrap()
+}
+
+fn ciram() -> Ciram {
+    [0; CIRAM_SIZE]
+}
+
+/// Stands in for everything on the CPU bus that isn't the cartridge
+struct Unmapped;
+
+impl Memory for Unmapped {
+    fn load(&mut self, _address: u16) -> u8 {
+        0
+    }
+
+    fn store(&mut self, _address: u16, _value: u8) {}
+}
+
+impl PpuRegisters for Unmapped {
+    fn read_register(
+        &mut self,
+        _register: u16,
+        _kind: AccessKind,
+        _open_bus: u8,
+        _cartridge: &mut impl Mapper,
+    ) -> u8 {
+        0
+    }
+
+    fn write_register(
+        &mut self,
+        _register: u16,
+        _value: u8,
+        _kind: AccessKind,
+        _cartridge: &mut impl Mapper,
+    ) {
+    }
+}
+
+#[test]
+fn unsupported_mapper() {
+    let result = mapper::from_rom(rom([1, 1, 0xF0, 0xF0, 0, 0, 0, 0, 0, 0, 0, 0]));
+
+    assert_eq!(
+        result.err(),
+        Some(UnsupportedMapper {
+            mapper: 0xFF,
+            submapper: 0
+        })
+    );
+}
+
+#[test]
+fn prg_helpers() {
+    assert_eq!(mapper::read_prg(&[], 0), None);
+    assert_eq!(
+        mapper::read_prg(&[1, 2, 3], 4),
+        Some(2),
+        "reads must wrap around"
+    );
+
+    let prg_ram = [0; 2 * 1024];
+    assert_eq!(mapper::prg_ram_offset(&prg_ram, 0x6801), Some(0x0001));
+    assert_eq!(mapper::prg_ram_offset(&prg_ram, 0x5FFF), None);
+    assert_eq!(mapper::prg_ram_offset(&prg_ram, 0x8000), None);
+    assert_eq!(mapper::prg_ram_offset(&[], 0x6000), None);
+}
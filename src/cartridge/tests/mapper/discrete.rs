@@ -0,0 +1,221 @@
+use super::{ciram, rom};
+use crate::cartridge::{
+    Mapper,
+    ines::Rom,
+    mapper::{self, Board, Discrete},
+};
+
+/// Build a ROM where the last byte of every 1 KiB block is `$FF`,
+/// so writes to `$FFFF` never have bus conflicts
+fn conflict_free_rom(header_bytes: [u8; 12]) -> Rom {
+    let mut rom = rom(header_bytes);
+    for block in rom.prg_rom.chunks_mut(1024) {
+        block[1023] = 0xFF;
+    }
+
+    rom
+}
+
+fn mapper(header_bytes: [u8; 12]) -> Box<dyn Mapper> {
+    mapper::from_rom(conflict_free_rom(header_bytes)).unwrap()
+}
+
+/// The 1 KiB PRG block numbers at `$8000` and `$C000`
+fn prg_blocks(mapper: &dyn Mapper) -> (u8, u8) {
+    (
+        mapper.cpu_peek(0x8000).unwrap(),
+        mapper.cpu_peek(0xC000).unwrap(),
+    )
+}
+
+/// The 1 KiB CHR block numbers at `$0000` and `$1000`
+fn chr_blocks(mapper: &mut dyn Mapper) -> (u8, u8) {
+    let ciram = ciram();
+    (
+        mapper.ppu_read(0x0000, &ciram),
+        mapper.ppu_read(0x1000, &ciram),
+    )
+}
+
+#[test]
+fn uxrom() {
+    // 128 KiB of PRG ROM and CHR RAM
+    let mut uxrom = mapper([8, 0, 0x20, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(prg_blocks(&*uxrom), (0, 112), "the last bank must be fixed");
+
+    uxrom.cpu_write(0xFFFF, 5);
+    assert_eq!(prg_blocks(&*uxrom), (80, 112));
+
+    let mut ciram = ciram();
+    uxrom.ppu_write(0x1234, 0x42, &mut ciram);
+    assert_eq!(uxrom.ppu_read(0x1234, &ciram), 0x42);
+}
+
+#[test]
+fn uxrom_bus_conflicts() {
+    // $C400 holds $71
+    let mut uxrom = mapper([8, 0, 0x20, 0x08, 0x20, 0, 0, 0x07, 0, 0, 0, 0]);
+
+    uxrom.cpu_write(0xC400, 0x07);
+
+    assert_eq!(
+        prg_blocks(&*uxrom),
+        (16, 112),
+        "the written value must be ANDed with the ROM"
+    );
+}
+
+#[test]
+fn uxrom_without_bus_conflicts() {
+    for submapper in [0x00, 0x10] {
+        let mut uxrom = mapper([8, 0, 0x20, 0x08, submapper, 0, 0, 0x07, 0, 0, 0, 0]);
+
+        uxrom.cpu_write(0xC400, 0x07);
+
+        assert_eq!(prg_blocks(&*uxrom), (112, 112), "submapper {submapper:02X}");
+    }
+}
+
+#[test]
+fn cnrom() {
+    // 32 KiB of PRG ROM and 32 KiB of CHR ROM
+    let mut cnrom = mapper([2, 4, 0x30, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    assert_eq!(prg_blocks(&*cnrom), (0, 16));
+
+    cnrom.cpu_write(0xFFFF, 3);
+
+    assert_eq!(chr_blocks(&mut *cnrom), (24, 28));
+    assert_eq!(prg_blocks(&*cnrom), (0, 16), "PRG must stay fixed");
+}
+
+#[test]
+fn cnrom_bus_conflicts() {
+    // NES 2.0 submapper 2, $8C00 holds $03
+    let mut cnrom = mapper([2, 4, 0x30, 0x08, 0x20, 0, 0, 0, 0, 0, 0, 0]);
+
+    cnrom.cpu_write(0x8C00, 0x02);
+
+    assert_eq!(chr_blocks(&mut *cnrom), (16, 20));
+}
+
+#[test]
+fn axrom() {
+    // 256 KiB of PRG ROM and CHR RAM
+    let mut axrom = mapper([16, 0, 0x71, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let mut ciram = ciram();
+    assert_eq!(prg_blocks(&*axrom), (0, 16));
+
+    axrom.ppu_write(0x2C00, 0x42, &mut ciram);
+    assert_eq!(
+        [ciram[0], ciram[0x0400]],
+        [0x42, 0],
+        "the header's mirroring must be ignored"
+    );
+
+    axrom.cpu_write(0xFFFF, 0x13);
+    assert_eq!(prg_blocks(&*axrom), (96, 112));
+
+    axrom.ppu_write(0x2000, 0x43, &mut ciram);
+    assert_eq!([ciram[0], ciram[0x0400]], [0x42, 0x43]);
+    assert_eq!(axrom.ppu_read(0x2800, &ciram), 0x43);
+}
+
+#[test]
+fn color_dreams() {
+    // 128 KiB of PRG ROM and 128 KiB of CHR ROM
+    let mut color_dreams = mapper([8, 16, 0xB0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    color_dreams.cpu_write(0xFFFF, 0xF3);
+
+    assert_eq!(prg_blocks(&*color_dreams), (96, 112));
+    assert_eq!(chr_blocks(&mut *color_dreams), (120, 124));
+}
+
+#[test]
+fn color_dreams_bus_conflicts() {
+    let mut color_dreams = mapper([8, 16, 0xB0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    // $8000 holds $00
+    color_dreams.cpu_write(0x8000, 0xF3);
+
+    assert_eq!(prg_blocks(&*color_dreams), (0, 16));
+    assert_eq!(chr_blocks(&mut *color_dreams), (0, 4));
+}
+
+#[test]
+fn gxrom() {
+    // 128 KiB of PRG ROM and 32 KiB of CHR ROM
+    let mut gxrom = mapper([8, 4, 0x20, 0x40, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    gxrom.cpu_write(0xFFFF, 0x32);
+
+    assert_eq!(prg_blocks(&*gxrom), (96, 112));
+    assert_eq!(chr_blocks(&mut *gxrom), (16, 20));
+}
+
+#[test]
+fn bnrom() {
+    // 128 KiB of PRG ROM and CHR RAM
+    let mut bnrom = Discrete::new(
+        conflict_free_rom([8, 0, 0x20, 0x20, 0, 0, 0, 0, 0, 0, 0, 0]),
+        Board::Bnrom,
+    );
+    assert!(bnrom.bus_conflicts);
+
+    bnrom.cpu_write(0xFFFF, 2);
+    assert_eq!(prg_blocks(&bnrom), (64, 80));
+
+    // $9000 holds $44
+    bnrom.cpu_write(0x9000, 0x03);
+    assert_eq!(prg_blocks(&bnrom), (0, 16));
+}
+
+#[test]
+fn mapper_34_board() {
+    for (header_bytes, board) in [
+        ([8, 0, 0x20, 0x20, 0, 0, 0, 0, 0, 0, 0, 0], Board::Bnrom),
+        ([4, 8, 0x20, 0x20, 0, 0, 0, 0, 0, 0, 0, 0], Board::Nina001),
+        // NES 2.0 submappers
+        (
+            [8, 0, 0x20, 0x28, 0x20, 0, 0, 0x07, 0, 0, 0, 0],
+            Board::Bnrom,
+        ),
+        (
+            [4, 1, 0x20, 0x28, 0x10, 0, 0x07, 0, 0, 0, 0, 0],
+            Board::Nina001,
+        ),
+    ] {
+        let mut mapper = mapper(header_bytes);
+
+        // NINA-001 switches PRG through $7FFD, BNROM through $8000-$FFFF
+        mapper.cpu_write(0x7FFD, 1);
+        let expected = match board {
+            Board::Nina001 => 32,
+            _ => 0,
+        };
+        assert_eq!(prg_blocks(&*mapper).0, expected, "{header_bytes:?}");
+    }
+}
+
+#[test]
+fn nina_001() {
+    // 64 KiB of PRG ROM, 64 KiB of CHR ROM and 8 KiB of PRG RAM
+    let mut nina_001 = mapper([4, 8, 0x20, 0x20, 0, 0, 0, 0, 0, 0, 0, 0]);
+
+    nina_001.cpu_write(0x7FFD, 1);
+    nina_001.cpu_write(0x7FFE, 5);
+    nina_001.cpu_write(0x7FFF, 0xF7);
+    nina_001.cpu_write(0xFFFF, 0);
+
+    assert_eq!(prg_blocks(&*nina_001), (32, 48));
+    assert_eq!(
+        chr_blocks(&mut *nina_001),
+        (20, 28),
+        "CHR banks must only use 4 bits"
+    );
+    assert_eq!(
+        nina_001.cpu_peek(0x7FFE),
+        Some(5),
+        "registers must also write to PRG RAM"
+    );
+}
@@ -0,0 +1,278 @@
+use super::{Unmapped, ciram, rom};
+use crate::{
+    cartridge::{Mapper, mapper::Mmc1},
+    cpu::Cpu,
+    memory::bus::Bus,
+};
+
+const PRG_BANK_SIZE: usize = 16 * 1024;
+const INC_ABSOLUTE: u8 = 0xEE;
+
+/// Build an MMC1 cartridge where the first byte of every 16 KiB PRG ROM bank holds its number
+fn mmc1(header_bytes: [u8; 12]) -> Mmc1 {
+    let mut rom = rom(header_bytes);
+    for (bank, data) in rom.prg_rom.chunks_mut(PRG_BANK_SIZE).enumerate() {
+        data[0] = bank as u8;
+    }
+
+    Mmc1::new(rom)
+}
+
+/// 128 KiB of PRG ROM, 32 KiB of CHR ROM and 8 KiB of PRG RAM
+fn sgrom() -> Mmc1 {
+    mmc1([8, 4, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0])
+}
+
+/// Write 1 bit at a time, leaving a cycle between each write like real code does
+fn write_register(mmc1: &mut Mmc1, address: u16, value: u8) {
+    for bit in 0..5 {
+        mmc1.cpu_cycle();
+        mmc1.cpu_cycle();
+        mmc1.cpu_write(address, value >> bit);
+    }
+}
+
+fn prg_banks(mmc1: &Mmc1) -> (u8, u8) {
+    (
+        mmc1.cpu_peek(0x8000).unwrap(),
+        mmc1.cpu_peek(0xC000).unwrap(),
+    )
+}
+
+#[test]
+fn power_on_fixes_last_bank() {
+    let mmc1 = sgrom();
+
+    assert_eq!(prg_banks(&mmc1), (0, 7));
+}
+
+#[test]
+fn prg_bank_modes() {
+    let mut mmc1 = sgrom();
+
+    write_register(&mut mmc1, 0xE000, 5);
+    assert_eq!(prg_banks(&mmc1), (5, 7), "mode 3 switches $8000");
+
+    write_register(&mut mmc1, 0x8000, 0b0_1000);
+    assert_eq!(prg_banks(&mmc1), (0, 5), "mode 2 switches $C000");
+
+    write_register(&mut mmc1, 0x8000, 0b0_0000);
+    assert_eq!(prg_banks(&mmc1), (4, 5), "32 KiB mode ignores the low bit");
+
+    write_register(&mut mmc1, 0xFFFF, 2);
+    assert_eq!(prg_banks(&mmc1), (2, 3));
+}
+
+#[test]
+fn registers_selected_by_address() {
+    let mut mmc1 = sgrom();
+
+    // all the writes but the last one can go to any address
+    for (bit, address) in [0x8000, 0xA000, 0xC000, 0x8000, 0xE000]
+        .into_iter()
+        .enumerate()
+    {
+        mmc1.cpu_cycle();
+        mmc1.cpu_cycle();
+        mmc1.cpu_write(address, 6 >> bit);
+    }
+
+    assert_eq!(prg_banks(&mmc1), (6, 7));
+}
+
+#[test]
+fn reset_bit_clears_shift_register() {
+    let mut mmc1 = sgrom();
+    write_register(&mut mmc1, 0x8000, 0b0_1000);
+
+    mmc1.cpu_cycle();
+    mmc1.cpu_write(0xE000, 1);
+    mmc1.cpu_cycle();
+    mmc1.cpu_cycle();
+    mmc1.cpu_write(0xE000, 0x80);
+    assert_eq!(
+        prg_banks(&mmc1),
+        (0, 7),
+        "the reset bit must select PRG mode 3"
+    );
+
+    write_register(&mut mmc1, 0xE000, 2);
+    assert_eq!(prg_banks(&mmc1), (2, 7));
+}
+
+#[test]
+fn consecutive_writes_are_ignored() {
+    let mut mmc1 = sgrom();
+
+    for bit in 0..5 {
+        mmc1.cpu_cycle();
+        mmc1.cpu_cycle();
+        mmc1.cpu_write(0xE000, 3 >> bit);
+        mmc1.cpu_cycle();
+        mmc1.cpu_write(0xE000, 0);
+    }
+
+    assert_eq!(prg_banks(&mmc1), (3, 7));
+}
+
+#[test]
+fn read_modify_write_dummy_write() {
+    // INC $8000 in the fixed bank, $8000 holds $FF so the dummy write resets the shift register
+    // and the $00 written right after must be ignored
+    let mut rom = rom([8, 4, 0x10, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    rom.prg_rom[0] = 0xFF;
+    rom.prg_rom[7 * PRG_BANK_SIZE + 0x10..][..3].copy_from_slice(&[INC_ABSOLUTE, 0x00, 0x80]);
+    for (bank, data) in rom.prg_rom.chunks_mut(PRG_BANK_SIZE).enumerate().skip(1) {
+        data[0] = bank as u8;
+    }
+
+    let mut bus = Bus::new(Unmapped, Unmapped, Mmc1::new(rom));
+    let mut cpu = Cpu::new();
+    cpu.pc = 0xC010;
+
+    bus.cartridge.cpu_write(0x8000, 1);
+    cpu.execute_next_instruction(&mut bus).unwrap();
+    write_register(&mut bus.cartridge, 0xE000, 1);
+
+    assert_eq!(prg_banks(&bus.cartridge), (1, 7));
+}
+
+#[test]
+fn mirroring() {
+    let mut mmc1 = sgrom();
+
+    // values read back from the 4 nametables after writing 1 to 4 to them, then the 2 CIRAM pages
+    for (control, expected_tables, expected_pages) in [
+        (0, [4, 4, 4, 4], [4, 0]),
+        (1, [4, 4, 4, 4], [0, 4]),
+        (2, [3, 4, 3, 4], [3, 4]),
+        (3, [2, 2, 4, 4], [2, 4]),
+    ] {
+        write_register(&mut mmc1, 0x8000, control);
+
+        let mut ciram = ciram();
+        for table in 0..4 {
+            mmc1.ppu_write(0x2000 + table * 0x0400, table as u8 + 1, &mut ciram);
+        }
+
+        let tables = [0, 1, 2, 3].map(|table| mmc1.ppu_read(0x2000 + table * 0x0400, &ciram));
+        assert_eq!(tables, expected_tables, "control {control}");
+        assert_eq!(
+            [ciram[0], ciram[0x0400]],
+            expected_pages,
+            "control {control}"
+        );
+    }
+}
+
+#[test]
+fn chr_bank_modes() {
+    let mut mmc1 = sgrom();
+    let ciram = ciram();
+
+    write_register(&mut mmc1, 0xA000, 3);
+    write_register(&mut mmc1, 0xC000, 5);
+    assert_eq!(
+        [mmc1.ppu_read(0x0000, &ciram), mmc1.ppu_read(0x1000, &ciram)],
+        [8, 12],
+        "8 KiB mode ignores the low bit and the second register"
+    );
+
+    write_register(&mut mmc1, 0x8000, 0b1_0000);
+    assert_eq!(
+        [mmc1.ppu_read(0x0000, &ciram), mmc1.ppu_read(0x1000, &ciram)],
+        [12, 20]
+    );
+}
+
+#[test]
+fn prg_ram_disable() {
+    let mut mmc1 = sgrom();
+    mmc1.cpu_write(0x6000, 0x42);
+
+    write_register(&mut mmc1, 0xE000, 0b1_0000);
+    mmc1.cpu_write(0x6000, 0x43);
+    assert_eq!(mmc1.cpu_read(0x6000, 0xA5), 0xA5);
+
+    write_register(&mut mmc1, 0xE000, 0);
+    assert_eq!(mmc1.cpu_read(0x6000, 0xA5), 0x42);
+}
+
+#[test]
+fn snrom() {
+    // 256 KiB of PRG ROM, 8 KiB of CHR RAM and 8 KiB of PRG RAM
+    let mut mmc1 = mmc1([16, 0, 0x10, 0x08, 0, 0, 0x07, 0x07, 0, 0, 0, 0]);
+    let mut ciram = ciram();
+    mmc1.cpu_write(0x6000, 0x42);
+
+    mmc1.ppu_write(0x1000, 0x43, &mut ciram);
+    assert_eq!(mmc1.ppu_read(0x1000, &ciram), 0x43);
+
+    write_register(&mut mmc1, 0xA000, 0b1_0000);
+    assert_eq!(
+        mmc1.cpu_read(0x6000, 0xA5),
+        0xA5,
+        "CHR bit 4 disables PRG RAM"
+    );
+    assert_eq!(prg_banks(&mmc1), (0, 15));
+
+    write_register(&mut mmc1, 0xA000, 0);
+    assert_eq!(mmc1.cpu_read(0x6000, 0xA5), 0x42);
+}
+
+#[test]
+fn sorom() {
+    // 8 KiB of PRG RAM and 8 KiB of PRG NVRAM
+    let mut mmc1 = mmc1([16, 0, 0x10, 0x08, 0, 0, 0x77, 0x07, 0, 0, 0, 0]);
+
+    mmc1.cpu_write(0x6000, 0x42);
+    write_register(&mut mmc1, 0xA000, 0b0_1000);
+    assert_eq!(mmc1.cpu_read(0x6000, 0xA5), 0);
+
+    mmc1.cpu_write(0x6000, 0x43);
+    write_register(&mut mmc1, 0xA000, 0b1_0000);
+    assert_eq!(
+        mmc1.cpu_read(0x6000, 0xA5),
+        0x42,
+        "bit 4 must not disable PRG RAM"
+    );
+}
+
+#[test]
+fn surom() {
+    // 512 KiB of PRG ROM, 8 KiB of CHR RAM and 8 KiB of PRG RAM
+    let mut mmc1 = mmc1([32, 0, 0x10, 0x08, 0, 0, 0x07, 0x07, 0, 0, 0, 0]);
+    mmc1.cpu_write(0x6000, 0x42);
+
+    write_register(&mut mmc1, 0xE000, 3);
+    assert_eq!(prg_banks(&mmc1), (3, 15));
+
+    write_register(&mut mmc1, 0xA000, 0b1_0000);
+    assert_eq!(
+        prg_banks(&mmc1),
+        (19, 31),
+        "CHR bit 4 selects the upper 256 KiB"
+    );
+    assert_eq!(
+        mmc1.cpu_read(0x6000, 0xA5),
+        0x42,
+        "bit 4 must not disable PRG RAM"
+    );
+}
+
+#[test]
+fn sxrom() {
+    // 512 KiB of PRG ROM, 8 KiB of CHR RAM and 32 KiB of PRG RAM
+    let mut mmc1 = mmc1([32, 0, 0x10, 0x08, 0, 0, 0x09, 0x07, 0, 0, 0, 0]);
+
+    for bank in 0..4 {
+        write_register(&mut mmc1, 0xA000, bank << 2);
+        mmc1.cpu_write(0x7FFF, bank);
+    }
+
+    for bank in 0..4 {
+        write_register(&mut mmc1, 0xA000, (bank << 2) | 0b1_0000);
+        assert_eq!(mmc1.cpu_read(0x7FFF, 0xA5), bank);
+        assert_eq!(prg_banks(&mmc1), (16, 31));
+    }
+}
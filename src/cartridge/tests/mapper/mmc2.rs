@@ -0,0 +1,145 @@
+use super::{ciram, rom};
+use crate::cartridge::{
+    Mapper,
+    mapper::{self, Chip, Mmc2},
+};
+
+/// 128 KiB of PRG ROM and 128 KiB of CHR ROM
+fn mmc2(chip: Chip) -> Mmc2 {
+    let mapper = match chip {
+        Chip::Mmc2 => 0x90,
+        Chip::Mmc4 => 0xA0,
+    };
+    Mmc2::new(rom([8, 16, mapper, 0, 0, 0, 0, 0, 0, 0, 0, 0]), chip)
+}
+
+/// Set the CHR banks to 1 KiB blocks 4, 8, 12 and 16, in register order
+fn set_chr_banks(mmc2: &mut Mmc2) {
+    for (address, bank) in [(0xB000, 1), (0xC000, 2), (0xD000, 3), (0xE000, 4)] {
+        mmc2.cpu_write(address, bank);
+    }
+}
+
+/// The 1 KiB CHR block numbers at `$0000` and `$1000`, without setting the latches
+fn chr_blocks(mmc2: &mut Mmc2) -> (u8, u8) {
+    let ciram = ciram();
+    (mmc2.ppu_read(0x0000, &ciram), mmc2.ppu_read(0x1000, &ciram))
+}
+
+#[test]
+fn from_rom() {
+    for (mapper, chip) in [(0x90, Chip::Mmc2), (0xA0, Chip::Mmc4)] {
+        let mut mmc2 = mapper::from_rom(rom([8, 16, mapper, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+        mmc2.cpu_write(0xA000, 1);
+
+        let expected = match chip {
+            Chip::Mmc2 => 8,
+            Chip::Mmc4 => 16,
+        };
+        assert_eq!(mmc2.cpu_peek(0x8000), Some(expected), "{chip:?}");
+    }
+}
+
+#[test]
+fn mmc2_prg_banks() {
+    let mut mmc2 = mmc2(Chip::Mmc2);
+
+    mmc2.cpu_write(0xA000, 0xF5);
+
+    let banks = [0x8000, 0xA000, 0xC000, 0xE000].map(|address| mmc2.cpu_peek(address).unwrap() / 8);
+    assert_eq!(banks, [5, 13, 14, 15]);
+}
+
+#[test]
+fn mmc4_prg_banks() {
+    let mut mmc4 = mmc2(Chip::Mmc4);
+
+    mmc4.cpu_write(0xA000, 0xF5);
+
+    let banks = [0x8000, 0xC000].map(|address| mmc4.cpu_peek(address).unwrap() / 16);
+    assert_eq!(banks, [5, 7]);
+}
+
+#[test]
+fn mmc4_prg_ram() {
+    let mut mmc4 = mmc2(Chip::Mmc4);
+
+    mmc4.cpu_write(0x6000, 0x42);
+
+    assert_eq!(mmc4.cpu_read(0x6000, 0xA5), 0x42);
+}
+
+#[test]
+fn latches_start_at_fe() {
+    let mut mmc2 = mmc2(Chip::Mmc2);
+    set_chr_banks(&mut mmc2);
+
+    assert_eq!(chr_blocks(&mut mmc2), (8, 16));
+}
+
+#[test]
+fn tile_fetches_set_latches() {
+    for chip in [Chip::Mmc2, Chip::Mmc4] {
+        let mut mmc2 = mmc2(chip);
+        let ciram = ciram();
+        set_chr_banks(&mut mmc2);
+
+        assert_eq!(
+            mmc2.ppu_read(0x0FD8, &ciram),
+            11,
+            "the fetch setting the latch must use the previous bank"
+        );
+        assert_eq!(chr_blocks(&mut mmc2), (4, 16), "{chip:?}");
+
+        mmc2.ppu_read(0x1FD8, &ciram);
+        assert_eq!(chr_blocks(&mut mmc2), (4, 12), "{chip:?}");
+
+        mmc2.ppu_read(0x0FE8, &ciram);
+        mmc2.ppu_read(0x1FEF, &ciram);
+        assert_eq!(chr_blocks(&mut mmc2), (8, 16), "{chip:?}");
+    }
+}
+
+#[test]
+fn mmc2_first_latch_only_watches_the_last_byte() {
+    let mut mmc2 = mmc2(Chip::Mmc2);
+    let ciram = ciram();
+    set_chr_banks(&mut mmc2);
+
+    mmc2.ppu_read(0x0FD0, &ciram);
+    mmc2.ppu_read(0x0FDF, &ciram);
+    mmc2.ppu_read(0x1FDF, &ciram);
+
+    assert_eq!(chr_blocks(&mut mmc2), (8, 12));
+}
+
+#[test]
+fn mmc4_first_latch_watches_the_whole_tile() {
+    let mut mmc4 = mmc2(Chip::Mmc4);
+    let ciram = ciram();
+    set_chr_banks(&mut mmc4);
+
+    mmc4.ppu_read(0x0FD0, &ciram);
+    assert_eq!(
+        chr_blocks(&mut mmc4),
+        (8, 16),
+        "the first plane isn't watched"
+    );
+
+    mmc4.ppu_read(0x0FDF, &ciram);
+    assert_eq!(chr_blocks(&mut mmc4), (4, 16));
+}
+
+#[test]
+fn mirroring() {
+    let mut mmc2 = mmc2(Chip::Mmc2);
+    let mut ciram = ciram();
+
+    mmc2.cpu_write(0xF000, 1);
+    mmc2.ppu_write(0x2400, 0x42, &mut ciram);
+    assert_eq!(mmc2.ppu_read(0x2000, &ciram), 0x42, "horizontal");
+
+    mmc2.cpu_write(0xF000, 0);
+    assert_eq!(mmc2.ppu_read(0x2800, &ciram), 0x42, "vertical");
+}
@@ -0,0 +1,305 @@
+use super::{Unmapped, ciram, rom};
+use crate::{
+    cartridge::{
+        Mapper,
+        mapper::{Mmc3, mmc3::IrqBehavior},
+    },
+    memory::{Memory, bus::Bus},
+};
+
+/// 128 KiB of PRG ROM, 128 KiB of CHR ROM and 8 KiB of PRG RAM
+fn mmc3() -> Mmc3 {
+    Mmc3::new(rom([8, 16, 0x40, 0, 0, 0, 0, 0, 0, 0, 0, 0]))
+}
+
+/// The 8 KiB PRG banks mapped at `$8000`, `$A000`, `$C000` and `$E000`
+fn prg_banks(mmc3: &Mmc3) -> [u8; 4] {
+    [0x8000, 0xA000, 0xC000, 0xE000].map(|address| mmc3.cpu_peek(address).unwrap() / 8)
+}
+
+/// The 1 KiB CHR banks mapped at `$0000-$1FFF`
+fn chr_banks(mmc3: &mut Mmc3) -> [u8; 8] {
+    let ciram = ciram();
+    [0, 1, 2, 3, 4, 5, 6, 7].map(|slot| mmc3.ppu_read(slot * 0x0400, &ciram))
+}
+
+fn set_bank(mmc3: &mut Mmc3, bank_select: u8, bank: u8) {
+    mmc3.cpu_write(0x8000, bank_select);
+    mmc3.cpu_write(0x8001, bank);
+}
+
+/// Make PPU A12 rise after staying low long enough, like it does once per scanline
+fn scanline(mmc3: &mut Mmc3) {
+    let ciram = ciram();
+
+    mmc3.ppu_read(0x0000, &ciram);
+    for _ in 0..3 {
+        mmc3.cpu_cycle();
+    }
+    mmc3.ppu_read(0x1000, &ciram);
+}
+
+/// Set up the IRQ counter to reload from `latch` on the next scanline and enable IRQs
+fn enable_irq(mmc3: &mut Mmc3, latch: u8) {
+    mmc3.cpu_write(0xC000, latch);
+    mmc3.cpu_write(0xC001, 0);
+    mmc3.cpu_write(0xE001, 0);
+}
+
+/// Which of the next scanlines trigger an IRQ, acknowledging every IRQ
+fn irq_scanlines(mmc3: &mut Mmc3, count: usize) -> Vec<usize> {
+    (0..count)
+        .filter(|_| {
+            scanline(mmc3);
+            let irq = mmc3.irq();
+            mmc3.cpu_write(0xE000, 0);
+            mmc3.cpu_write(0xE001, 0);
+            irq
+        })
+        .collect()
+}
+
+#[test]
+fn prg_bank_modes() {
+    let mut mmc3 = mmc3();
+    set_bank(&mut mmc3, 6, 3);
+    set_bank(&mut mmc3, 7, 5);
+
+    assert_eq!(prg_banks(&mmc3), [3, 5, 14, 15]);
+
+    mmc3.cpu_write(0x8000, 0x40);
+    assert_eq!(
+        prg_banks(&mmc3),
+        [14, 5, 3, 15],
+        "PRG mode 1 must swap $8000 and $C000"
+    );
+}
+
+#[test]
+fn prg_banks_wrap() {
+    let mut mmc3 = mmc3();
+
+    set_bank(&mut mmc3, 6, 0xC0 | 17);
+
+    assert_eq!(prg_banks(&mmc3)[0], 1);
+}
+
+#[test]
+fn chr_bank_modes() {
+    let mut mmc3 = mmc3();
+    for (register, bank) in [(0, 9), (1, 20), (2, 100), (3, 101), (4, 102), (5, 103)] {
+        set_bank(&mut mmc3, register, bank);
+    }
+
+    assert_eq!(
+        chr_banks(&mut mmc3),
+        [8, 9, 20, 21, 100, 101, 102, 103],
+        "2 KiB banks must ignore the low bit"
+    );
+
+    mmc3.cpu_write(0x8000, 0x80);
+    assert_eq!(
+        chr_banks(&mut mmc3),
+        [100, 101, 102, 103, 8, 9, 20, 21],
+        "CHR inversion must swap the halves"
+    );
+}
+
+#[test]
+fn mirroring() {
+    let mut mmc3 = mmc3();
+    let mut ciram = ciram();
+
+    mmc3.cpu_write(0xA000, 1);
+    mmc3.ppu_write(0x2400, 0x42, &mut ciram);
+    assert_eq!(mmc3.ppu_read(0x2000, &ciram), 0x42, "horizontal");
+
+    mmc3.cpu_write(0xA000, 0);
+    assert_eq!(mmc3.ppu_read(0x2800, &ciram), 0x42, "vertical");
+}
+
+#[test]
+fn four_screen_ignores_mirroring() {
+    let mut mmc3 = Mmc3::new(rom([8, 16, 0x48, 0, 0, 0, 0, 0, 0, 0, 0, 0]));
+    let mut ciram = ciram();
+
+    mmc3.cpu_write(0xA000, 1);
+    for table in 0..4 {
+        mmc3.ppu_write(0x2000 + table * 0x0400, table as u8, &mut ciram);
+    }
+
+    let tables = [0, 1, 2, 3].map(|table| mmc3.ppu_read(0x2000 + table * 0x0400, &ciram));
+    assert_eq!(tables, [0, 1, 2, 3]);
+}
+
+#[test]
+fn prg_ram_protect() {
+    let mut mmc3 = mmc3();
+    mmc3.cpu_write(0x6000, 0x42);
+    assert_eq!(mmc3.cpu_read(0x6000, 0xA5), 0x42);
+
+    mmc3.cpu_write(0xA001, 0xC0);
+    mmc3.cpu_write(0x6000, 0x43);
+    assert_eq!(
+        mmc3.cpu_read(0x6000, 0xA5),
+        0x42,
+        "writes must be ignored when write protected"
+    );
+
+    mmc3.cpu_write(0xA001, 0x00);
+    assert_eq!(
+        mmc3.cpu_read(0x6000, 0xA5),
+        0xA5,
+        "reads must be open bus when disabled"
+    );
+    assert_eq!(mmc3.cpu_peek(0x6000), None);
+}
+
+#[test]
+fn irq_counter() {
+    let mut mmc3 = mmc3();
+    enable_irq(&mut mmc3, 3);
+
+    assert_eq!(
+        irq_scanlines(&mut mmc3, 9),
+        [3, 7],
+        "an IRQ must happen every latch + 1 scanlines"
+    );
+}
+
+#[test]
+fn irq_disable_acknowledges() {
+    let mut mmc3 = mmc3();
+    enable_irq(&mut mmc3, 0);
+
+    scanline(&mut mmc3);
+    assert!(mmc3.irq());
+
+    mmc3.cpu_write(0xE000, 0);
+    assert!(!mmc3.irq());
+
+    scanline(&mut mmc3);
+    assert!(!mmc3.irq(), "no IRQ must happen while disabled");
+}
+
+#[test]
+fn irq_reload_mid_count() {
+    let mut mmc3 = mmc3();
+    enable_irq(&mut mmc3, 3);
+    scanline(&mut mmc3);
+    scanline(&mut mmc3);
+
+    mmc3.cpu_write(0xC000, 1);
+    mmc3.cpu_write(0xC001, 0);
+
+    assert_eq!(irq_scanlines(&mut mmc3, 4), [1, 3]);
+}
+
+#[test]
+fn a12_filter() {
+    let mut mmc3 = mmc3();
+    let ciram = ciram();
+    enable_irq(&mut mmc3, 0);
+
+    for _ in 0..8 {
+        mmc3.ppu_read(0x0000, &ciram);
+        mmc3.cpu_cycle();
+        mmc3.cpu_cycle();
+        mmc3.ppu_read(0x1FFF, &ciram);
+    }
+    assert!(
+        !mmc3.irq(),
+        "rises after A12 was low for too short must be ignored"
+    );
+
+    mmc3.cpu_cycle();
+    mmc3.ppu_read(0x2000, &ciram);
+    for _ in 0..3 {
+        mmc3.cpu_cycle();
+    }
+    mmc3.ppu_read(0x1000, &ciram);
+    assert!(mmc3.irq(), "nametable fetches keep A12 low");
+}
+
+#[test]
+fn sharp_latch_0() {
+    let mut mmc3 = mmc3();
+    assert_eq!(mmc3.irq_behavior, IrqBehavior::Sharp);
+    enable_irq(&mut mmc3, 0);
+
+    assert_eq!(irq_scanlines(&mut mmc3, 4), [0, 1, 2, 3]);
+}
+
+#[test]
+fn nec_latch_0() {
+    // NES 2.0 submapper 4
+    let mut mmc3 = Mmc3::new(rom([8, 16, 0x40, 0x08, 0x40, 0, 0x07, 0, 0, 0, 0, 0]));
+    assert_eq!(mmc3.irq_behavior, IrqBehavior::Nec);
+    enable_irq(&mut mmc3, 0);
+
+    assert_eq!(
+        irq_scanlines(&mut mmc3, 4),
+        [0],
+        "a latch of 0 must only trigger once after a reload"
+    );
+}
+
+#[test]
+fn nec_counts_down_to_0() {
+    let mut mmc3 = mmc3();
+    mmc3.irq_behavior = IrqBehavior::Nec;
+    enable_irq(&mut mmc3, 2);
+
+    assert_eq!(irq_scanlines(&mut mmc3, 6), [2, 5]);
+}
+
+#[test]
+fn irq_clocked_through_ppu_address() {
+    let mut mmc3 = mmc3();
+    enable_irq(&mut mmc3, 0);
+
+    // like setting the VRAM address through $2006 while not rendering
+    mmc3.ppu_address_changed(0x0000);
+    mmc3.ppu_address_changed(0x1000);
+    assert!(!mmc3.irq(), "the A12 filter must apply to address changes");
+
+    mmc3.ppu_address_changed(0x0000);
+    for _ in 0..3 {
+        mmc3.cpu_cycle();
+    }
+    mmc3.ppu_address_changed(0x1000);
+    assert!(
+        mmc3.irq(),
+        "A12 rising without a fetch must clock the counter"
+    );
+}
+
+#[test]
+fn irq_clocked_through_ppu_data() {
+    let mut mmc3 = mmc3();
+    enable_irq(&mut mmc3, 0);
+
+    // a $2007 read of $0FE0 that increments the address by 32
+    mmc3.ppu_address_changed(0x0FE0);
+    for _ in 0..3 {
+        mmc3.cpu_cycle();
+    }
+    mmc3.ppu_read(0x0FE0, &ciram());
+    assert!(!mmc3.irq());
+
+    mmc3.ppu_address_changed(0x1000);
+    assert!(
+        mmc3.irq(),
+        "incrementing the address into $1000 must clock the counter"
+    );
+}
+
+#[test]
+fn irq_reaches_the_bus() {
+    let mut bus = Bus::new(Unmapped, Unmapped, mmc3());
+    enable_irq(&mut bus.cartridge, 0);
+    assert!(!bus.irq());
+
+    scanline(&mut bus.cartridge);
+    assert!(bus.irq());
+}
@@ -0,0 +1,444 @@
+use super::{ciram, rom};
+use crate::cartridge::{Ciram, Mapper, mapper::Mmc5};
+
+/// 256 KiB of PRG ROM, 256 KiB of CHR ROM and 64 KiB of PRG RAM
+fn mmc5() -> Mmc5 {
+    Mmc5::new(rom([16, 32, 0x50, 0, 8, 0, 0, 0, 0, 0, 0, 0]))
+}
+
+/// The 1 KiB PRG block numbers at `$8000`, `$A000`, `$C000` and `$E000`
+fn prg_blocks(mmc5: &Mmc5) -> [u8; 4] {
+    [0x8000, 0xA000, 0xC000, 0xE000].map(|address| mmc5.cpu_peek(address).unwrap())
+}
+
+/// The 1 KiB CHR block numbers at `$0000-$1FFF`
+fn chr_blocks(mmc5: &mut Mmc5) -> [u8; 8] {
+    let ciram = ciram();
+    [0, 1, 2, 3, 4, 5, 6, 7].map(|slot| mmc5.ppu_read(slot * 0x0400, &ciram))
+}
+
+/// Turn rendering on and make the dummy nametable fetches at the end of the pre-render scanline
+fn start_frame(mmc5: &mut Mmc5, ciram: &Ciram) {
+    mmc5.ppu_register_write(0x2001, 0x18);
+    mmc5.ppu_read(0x2002, ciram);
+    mmc5.ppu_read(0x2002, ciram);
+}
+
+/// Make the PPU reads of a visible scanline from its first background fetch,
+/// returning the address and value of every read
+///
+/// Background tiles read the nametable byte at their column, the attribute byte at `$23C0`
+/// and their patterns at `$0000`, sprites read the nametable twice and their patterns at `$1000`
+fn scanline(mmc5: &mut Mmc5, ciram: &Ciram) -> Vec<(u16, u8)> {
+    let tile = |column: u16| [0x2000 + column, 0x23C0, 0x0000, 0x0008];
+    let sprite = [0x2000, 0x2000, 0x1000, 0x1008];
+
+    let addresses = (2..34)
+        .flat_map(tile)
+        .chain((0..8).flat_map(|_| sprite))
+        .chain((0..2).flat_map(tile))
+        .chain([0x2002, 0x2002]);
+
+    addresses
+        .map(|address| (address, mmc5.ppu_read(address, ciram)))
+        .collect()
+}
+
+/// Values read by the fetches of the background tile at a column of a scanline,
+/// nametable, attribute, and the 2 pattern bytes
+fn background(reads: &[(u16, u8)], column: usize) -> [u8; 4] {
+    let start = (column - 2) * 4;
+    [0, 1, 2, 3].map(|fetch| reads[start + fetch].1)
+}
+
+#[test]
+fn prg_bank_modes() {
+    let mut mmc5 = mmc5();
+    assert_eq!(
+        prg_blocks(&mmc5)[3],
+        248,
+        "the last bank must be mapped at $E000 on power on"
+    );
+
+    mmc5.cpu_write(0x5100, 0);
+    mmc5.cpu_write(0x5117, 0x07);
+    assert_eq!(prg_blocks(&mmc5), [32, 40, 48, 56], "mode 0");
+
+    mmc5.cpu_write(0x5100, 1);
+    mmc5.cpu_write(0x5115, 0x83);
+    mmc5.cpu_write(0x5117, 0x05);
+    assert_eq!(prg_blocks(&mmc5), [16, 24, 32, 40], "mode 1");
+
+    mmc5.cpu_write(0x5100, 2);
+    mmc5.cpu_write(0x5116, 0x89);
+    mmc5.cpu_write(0x5117, 0x0A);
+    assert_eq!(prg_blocks(&mmc5), [16, 24, 72, 80], "mode 2");
+
+    mmc5.cpu_write(0x5100, 3);
+    for (register, bank) in [
+        (0x5114, 0x81),
+        (0x5115, 0x82),
+        (0x5116, 0x83),
+        (0x5117, 0x04),
+    ] {
+        mmc5.cpu_write(register, bank);
+    }
+    assert_eq!(prg_blocks(&mmc5), [8, 16, 24, 32], "mode 3");
+}
+
+#[test]
+fn prg_ram_banks() {
+    let mut mmc5 = mmc5();
+    mmc5.cpu_write(0x5113, 2);
+
+    mmc5.cpu_write(0x6000, 0x42);
+    assert_eq!(
+        mmc5.cpu_peek(0x6000),
+        Some(0),
+        "writes must be ignored until PRG RAM is unprotected"
+    );
+
+    mmc5.cpu_write(0x5102, 0x02);
+    mmc5.cpu_write(0x5103, 0x01);
+    mmc5.cpu_write(0x6000, 0x42);
+
+    mmc5.cpu_write(0x5114, 0x02);
+    assert_eq!(
+        mmc5.cpu_peek(0x8000),
+        Some(0x42),
+        "PRG RAM must be mappable in $8000-$DFFF"
+    );
+
+    mmc5.cpu_write(0x8001, 0x43);
+    mmc5.cpu_write(0x5113, 0x05);
+    mmc5.cpu_write(0x5114, 0x05);
+    mmc5.cpu_write(0xE001, 0x44);
+    assert_eq!(
+        [mmc5.cpu_peek(0x6001), mmc5.cpu_peek(0x8001)],
+        [Some(0), Some(0)]
+    );
+    mmc5.cpu_write(0x5113, 0x02);
+    assert_eq!(mmc5.cpu_peek(0x6001), Some(0x43));
+    assert_eq!(
+        mmc5.cpu_peek(0xE001),
+        Some(248),
+        "$E000 must always be PRG ROM"
+    );
+}
+
+#[test]
+fn chr_bank_modes() {
+    let mut mmc5 = mmc5();
+    for register in 0..8 {
+        mmc5.cpu_write(0x5120 + register, register as u8 + 1);
+    }
+
+    mmc5.cpu_write(0x5101, 0);
+    assert_eq!(
+        chr_blocks(&mut mmc5),
+        [64, 65, 66, 67, 68, 69, 70, 71],
+        "mode 0"
+    );
+
+    mmc5.cpu_write(0x5101, 1);
+    assert_eq!(
+        chr_blocks(&mut mmc5),
+        [16, 17, 18, 19, 32, 33, 34, 35],
+        "mode 1"
+    );
+
+    mmc5.cpu_write(0x5101, 2);
+    assert_eq!(
+        chr_blocks(&mut mmc5),
+        [4, 5, 8, 9, 12, 13, 16, 17],
+        "mode 2"
+    );
+
+    mmc5.cpu_write(0x5101, 3);
+    assert_eq!(chr_blocks(&mut mmc5), [1, 2, 3, 4, 5, 6, 7, 8], "mode 3");
+}
+
+#[test]
+fn chr_set_b() {
+    let mut mmc5 = mmc5();
+    mmc5.cpu_write(0x5101, 3);
+    for register in 0..12 {
+        mmc5.cpu_write(0x5120 + register, register as u8 + 1);
+    }
+
+    assert_eq!(
+        chr_blocks(&mut mmc5),
+        [9, 10, 11, 12, 9, 10, 11, 12],
+        "set B must be used after being written, in both pattern tables"
+    );
+
+    mmc5.cpu_write(0x5127, 0x20);
+    assert_eq!(chr_blocks(&mut mmc5)[7], 0x20);
+}
+
+#[test]
+fn chr_upper_bits() {
+    // 512 KiB of CHR ROM, where block numbers wrap at 256 KiB
+    let mut rom = rom([16, 64, 0x50, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+    rom.chr_rom[0x0102 * 1024] = 0xEE;
+    let mut mmc5 = Mmc5::new(rom);
+    mmc5.cpu_write(0x5101, 3);
+
+    mmc5.cpu_write(0x5130, 1);
+    mmc5.cpu_write(0x5120, 0x02);
+    mmc5.cpu_write(0x5130, 0);
+
+    assert_eq!(
+        mmc5.ppu_read(0x0000, &ciram()),
+        0xEE,
+        "the upper bits must be latched when the bank is written"
+    );
+}
+
+#[test]
+fn large_sprites_use_both_sets() {
+    let mut mmc5 = mmc5();
+    let ciram = ciram();
+    mmc5.cpu_write(0x5127, 1);
+    mmc5.cpu_write(0x512B, 2);
+    mmc5.ppu_register_write(0x2000, 0x20);
+
+    assert_eq!(
+        [mmc5.ppu_read(0x0000, &ciram), mmc5.ppu_read(0x1000, &ciram)],
+        [16, 20],
+        "the last written set must be used outside of frames"
+    );
+
+    start_frame(&mut mmc5, &ciram);
+    let reads = scanline(&mut mmc5, &ciram);
+
+    assert_eq!(background(&reads, 10)[2], 16, "backgrounds must use set B");
+    assert_eq!(reads[130].1, 12, "sprites must use set A");
+}
+
+#[test]
+fn nametable_mapping() {
+    let mut mmc5 = mmc5();
+    let mut ciram = ciram();
+    ciram[0x0400] = 0x11;
+    mmc5.cpu_write(0x5104, 0);
+
+    mmc5.cpu_write(0x5105, 0b11_10_01_00);
+    mmc5.cpu_write(0x5106, 0x42);
+    mmc5.cpu_write(0x5107, 0x02);
+    mmc5.ppu_write(0x2800, 0x12, &mut ciram);
+
+    let tables = [0, 1, 2, 3].map(|table| mmc5.ppu_read(0x2000 + table * 0x0400, &ciram));
+    assert_eq!(tables, [0, 0x11, 0x12, 0x42]);
+    assert_eq!(
+        mmc5.ppu_read(0x2FC0, &ciram),
+        0xAA,
+        "fill mode must return the fill attribute in attribute tables"
+    );
+
+    mmc5.cpu_write(0x5104, 2);
+    assert_eq!(
+        mmc5.ppu_read(0x2800, &ciram),
+        0,
+        "ExRAM must read as 0 when used as RAM"
+    );
+}
+
+#[test]
+fn exram_modes() {
+    let mut mmc5 = mmc5();
+
+    mmc5.cpu_write(0x5104, 2);
+    mmc5.cpu_write(0x5C00, 0x42);
+    assert_eq!(mmc5.cpu_read(0x5C00, 0xA5), 0x42);
+
+    mmc5.cpu_write(0x5104, 3);
+    mmc5.cpu_write(0x5C00, 0x43);
+    assert_eq!(
+        mmc5.cpu_read(0x5C00, 0xA5),
+        0x42,
+        "mode 3 must be read only"
+    );
+
+    mmc5.cpu_write(0x5104, 0);
+    assert_eq!(
+        mmc5.cpu_read(0x5C00, 0xA5),
+        0xA5,
+        "ExRAM must not be readable as a nametable"
+    );
+
+    mmc5.cpu_write(0x5C00, 0x44);
+    mmc5.cpu_write(0x5104, 2);
+    assert_eq!(
+        mmc5.cpu_read(0x5C00, 0xA5),
+        0,
+        "0 must be written outside of frames"
+    );
+}
+
+#[test]
+fn multiplier() {
+    let mut mmc5 = mmc5();
+    assert_eq!(
+        [mmc5.cpu_peek(0x5205), mmc5.cpu_peek(0x5206)],
+        [Some(0x01), Some(0xFE)]
+    );
+
+    mmc5.cpu_write(0x5205, 12);
+    mmc5.cpu_write(0x5206, 34);
+
+    assert_eq!(
+        [mmc5.cpu_peek(0x5205), mmc5.cpu_peek(0x5206)],
+        [Some(0x98), Some(0x01)]
+    );
+}
+
+#[test]
+fn audio_registers() {
+    let mut mmc5 = mmc5();
+
+    mmc5.cpu_write(0x5015, 0x03);
+    mmc5.cpu_write(0x5000, 0x3F);
+
+    assert_eq!(mmc5.cpu_peek(0x5015), Some(0), "channels must be silent");
+    assert_eq!(
+        mmc5.cpu_read(0x5000, 0xA5),
+        0xA5,
+        "registers must be write only"
+    );
+}
+
+#[test]
+fn scanline_irq() {
+    let mut mmc5 = mmc5();
+    let ciram = ciram();
+    mmc5.cpu_write(0x5203, 3);
+    mmc5.cpu_write(0x5204, 0x80);
+
+    start_frame(&mut mmc5, &ciram);
+    let irq_scanlines: Vec<_> = (0..6)
+        .filter(|_| {
+            scanline(&mut mmc5, &ciram);
+            mmc5.irq()
+        })
+        .collect();
+
+    assert_eq!(irq_scanlines, [3, 4, 5]);
+    assert_eq!(mmc5.cpu_peek(0x5204), Some(0xC0));
+    assert_eq!(mmc5.cpu_read(0x5204, 0), 0xC0);
+    assert!(!mmc5.irq(), "reading $5204 must acknowledge the IRQ");
+    assert_eq!(mmc5.cpu_read(0x5204, 0), 0x40);
+}
+
+#[test]
+fn irq_disabled() {
+    let mut mmc5 = mmc5();
+    let ciram = ciram();
+    mmc5.cpu_write(0x5203, 1);
+
+    start_frame(&mut mmc5, &ciram);
+    scanline(&mut mmc5, &ciram);
+    scanline(&mut mmc5, &ciram);
+
+    assert!(!mmc5.irq());
+    assert_eq!(
+        mmc5.cpu_peek(0x5204),
+        Some(0xC0),
+        "the IRQ must still be pending"
+    );
+
+    mmc5.cpu_write(0x5204, 0x80);
+    assert!(mmc5.irq());
+}
+
+/// Start a frame and render a scanline
+fn in_frame() -> Mmc5 {
+    let mut mmc5 = mmc5();
+    let ciram = ciram();
+    start_frame(&mut mmc5, &ciram);
+    scanline(&mut mmc5, &ciram);
+    assert_eq!(mmc5.cpu_peek(0x5204), Some(0x40));
+
+    mmc5
+}
+
+#[test]
+fn frame_end() {
+    let mut mmc5 = in_frame();
+    mmc5.cpu_read(0xFFFA, 0);
+    assert_eq!(
+        mmc5.cpu_peek(0x5204),
+        Some(0),
+        "reading the NMI vector must end the frame"
+    );
+
+    let mut mmc5 = in_frame();
+    for _ in 0..3 {
+        mmc5.cpu_cycle();
+    }
+    assert_eq!(
+        mmc5.cpu_peek(0x5204),
+        Some(0),
+        "the PPU not reading must end the frame"
+    );
+
+    let mut mmc5 = in_frame();
+    mmc5.ppu_register_write(0x2001, 0);
+    assert_eq!(
+        mmc5.cpu_peek(0x5204),
+        Some(0),
+        "disabling rendering must end the frame"
+    );
+}
+
+#[test]
+fn extended_attributes() {
+    let mut mmc5 = mmc5();
+    let mut ciram = ciram();
+    // ExRAM is written through the second nametable
+    mmc5.cpu_write(0x5104, 1);
+    mmc5.cpu_write(0x5105, 0b10_00);
+    mmc5.ppu_write(0x2405, 0b10_000011, &mut ciram);
+    mmc5.cpu_write(0x5130, 1);
+
+    start_frame(&mut mmc5, &ciram);
+    let reads = scanline(&mut mmc5, &ciram);
+
+    assert_eq!(background(&reads, 5), [0, 0xAA, 12, 12]);
+    assert_eq!(
+        background(&reads, 6),
+        [0, 0x00, 0, 0],
+        "tiles must use their own ExRAM byte"
+    );
+}
+
+#[test]
+fn vertical_split() {
+    let mut mmc5 = mmc5();
+    let mut ciram = ciram();
+    mmc5.cpu_write(0x5104, 0);
+    mmc5.cpu_write(0x5105, 0b10_00);
+    // tile $41 at column 25 of the second tile row, palette 3
+    mmc5.ppu_write(0x2400 + 32 + 25, 0x41, &mut ciram);
+    mmc5.ppu_write(0x27C6, 0x03, &mut ciram);
+    ciram[25] = 0x99;
+
+    mmc5.cpu_write(0x5200, 0xC0 | 20);
+    mmc5.cpu_write(0x5201, 8);
+    mmc5.cpu_write(0x5202, 1);
+
+    start_frame(&mut mmc5, &ciram);
+    let reads = scanline(&mut mmc5, &ciram);
+
+    assert_eq!(background(&reads, 25), [0x41, 0xFF, 5, 5]);
+    assert_eq!(
+        background(&reads, 10)[0],
+        ciram[10],
+        "tiles left of the split must come from the nametables"
+    );
+    assert_eq!(
+        reads[4 * 23].0,
+        0x2019,
+        "the PPU must still fetch its own nametable"
+    );
+}
@@ -0,0 +1,98 @@
+use super::{ciram, rom};
+use crate::cartridge::{Mapper, mapper};
+
+#[test]
+fn nrom_128_is_mirrored() {
+    let mut nrom = mapper::from_rom(rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(nrom.cpu_read(0x8000, 0xFF), 0);
+    assert_eq!(nrom.cpu_read(0xBFFF, 0xFF), 15);
+    assert_eq!(nrom.cpu_read(0xC000, 0xFF), 0);
+    assert_eq!(nrom.cpu_read(0xFFFF, 0xFF), 15);
+    assert_eq!(nrom.cpu_peek(0xC400), Some(1));
+}
+
+#[test]
+fn nrom_256() {
+    let mut nrom = mapper::from_rom(rom([2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(nrom.cpu_read(0x8000, 0xFF), 0);
+    assert_eq!(nrom.cpu_read(0xC000, 0xFF), 16);
+    assert_eq!(nrom.cpu_read(0xFFFF, 0xFF), 31);
+}
+
+#[test]
+fn prg_rom_is_read_only() {
+    let mut nrom = mapper::from_rom(rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    nrom.cpu_write(0x8400, 0x42);
+
+    assert_eq!(nrom.cpu_read(0x8400, 0xFF), 1);
+}
+
+#[test]
+fn prg_ram() {
+    let mut nrom = mapper::from_rom(rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    nrom.cpu_write(0x6000, 0x42);
+    nrom.cpu_write(0x7FFF, 0x43);
+
+    assert_eq!(nrom.cpu_read(0x6000, 0xFF), 0x42);
+    assert_eq!(nrom.cpu_peek(0x7FFF), Some(0x43));
+}
+
+#[test]
+fn missing_prg_ram_is_open_bus() {
+    // NES 2.0 header without PRG RAM
+    let mut nrom = mapper::from_rom(rom([1, 1, 0, 0x08, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    nrom.cpu_write(0x6000, 0x42);
+
+    assert_eq!(nrom.cpu_read(0x6000, 0xA5), 0xA5);
+    assert_eq!(nrom.cpu_read(0x5000, 0xA5), 0xA5);
+    assert_eq!(nrom.cpu_peek(0x6000), None);
+}
+
+#[test]
+fn chr_rom() {
+    let mut nrom = mapper::from_rom(rom([1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+    let mut ciram = ciram();
+
+    nrom.ppu_write(0x0400, 0x42, &mut ciram);
+
+    assert_eq!(nrom.ppu_read(0x0000, &ciram), 0);
+    assert_eq!(nrom.ppu_read(0x0400, &ciram), 1);
+    assert_eq!(nrom.ppu_read(0x1FFF, &ciram), 7);
+}
+
+#[test]
+fn chr_ram() {
+    let mut nrom = mapper::from_rom(rom([1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+    let mut ciram = ciram();
+
+    nrom.ppu_write(0x0000, 0x42, &mut ciram);
+    nrom.ppu_write(0x1FFF, 0x43, &mut ciram);
+
+    assert_eq!(nrom.ppu_read(0x0000, &ciram), 0x42);
+    assert_eq!(nrom.ppu_read(0x1FFF, &ciram), 0x43);
+}
+
+#[test]
+fn nametables_use_header_mirroring() {
+    let mut nrom = mapper::from_rom(rom([1, 1, 0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+    let mut ciram = ciram();
+
+    nrom.ppu_write(0x2400, 0x42, &mut ciram);
+
+    assert_eq!(ciram[0x0400], 0x42);
+    assert_eq!(
+        nrom.ppu_read(0x2C00, &ciram),
+        0x42,
+        "mirroring must be vertical"
+    );
+    assert_eq!(
+        nrom.ppu_read(0x3400, &ciram),
+        0x42,
+        "$3000-$3EFF mirrors $2000"
+    );
+}
@@ -0,0 +1,280 @@
+use super::{ciram, rom};
+use crate::cartridge::{
+    Mapper,
+    mapper::{self, Vrc4, vrc4::Revision},
+};
+
+/// NES 2.0 header with 128 KiB of PRG ROM, 256 KiB of CHR ROM and 8 KiB of PRG RAM
+fn header(mapper: u8, submapper: u8) -> [u8; 12] {
+    let flags_7 = (mapper & 0xF0) | 0x08;
+    [
+        8,
+        32,
+        mapper << 4,
+        flags_7,
+        submapper << 4,
+        0,
+        0x07,
+        0,
+        0,
+        0,
+        0,
+        0,
+    ]
+}
+
+fn vrc(mapper: u8, submapper: u8) -> Vrc4 {
+    Vrc4::new(rom(header(mapper, submapper)))
+}
+
+/// The 1 KiB PRG block numbers at `$8000`, `$A000`, `$C000` and `$E000`
+fn prg_blocks(vrc4: &Vrc4) -> [u8; 4] {
+    [0x8000, 0xA000, 0xC000, 0xE000].map(|address| vrc4.cpu_peek(address).unwrap())
+}
+
+/// CPU cycles until the next IRQ, acknowledging it
+fn cycles_to_irq(vrc4: &mut Vrc4) -> usize {
+    let cycles = (1..1000)
+        .find(|_| {
+            vrc4.cpu_cycle();
+            vrc4.irq()
+        })
+        .unwrap();
+    vrc4.cpu_write(0xF003, 0);
+
+    cycles
+}
+
+#[test]
+fn wiring() {
+    // mapper, submapper, address lines of the register select inputs and revision
+    for (mapper, submapper, a0, a1, revision) in [
+        (21, 1, 0x02, 0x04, Revision::Vrc4),
+        (21, 2, 0x40, 0x80, Revision::Vrc4),
+        (22, 0, 0x02, 0x01, Revision::Vrc2),
+        (23, 1, 0x01, 0x02, Revision::Vrc4),
+        (23, 2, 0x04, 0x08, Revision::Vrc4),
+        (23, 3, 0x01, 0x02, Revision::Vrc2),
+        (25, 1, 0x02, 0x01, Revision::Vrc4),
+        (25, 2, 0x08, 0x04, Revision::Vrc4),
+        (25, 3, 0x02, 0x01, Revision::Vrc2),
+    ] {
+        let mut vrc4 = vrc(mapper, submapper);
+        assert_eq!(vrc4.revision, revision, "mapper {mapper}.{submapper}");
+
+        // low and high bits of the first bank, low bits of the second one
+        vrc4.cpu_write(0xB000, 0x06);
+        vrc4.cpu_write(0xB000 | a0, 0x02);
+        vrc4.cpu_write(0xB000 | a1, 0x05);
+
+        // VRC2a ignores the low bit of CHR banks
+        let shift = (mapper == 22) as u8;
+        let ciram = ciram();
+        assert_eq!(
+            [vrc4.ppu_read(0x0000, &ciram), vrc4.ppu_read(0x0400, &ciram)],
+            [0x26 >> shift, 0x05 >> shift],
+            "mapper {mapper}.{submapper}"
+        );
+    }
+}
+
+#[test]
+fn ines_wiring() {
+    // iNES mappers 21, 23 and 25
+    for (flags_6, a0, a1) in [(0x50, 0x40, 0x80), (0x70, 0x04, 0x08), (0x90, 0x08, 0x04)] {
+        let mut vrc4 = Vrc4::new(rom([8, 32, flags_6, 0x10, 0, 0, 0, 0, 0, 0, 0, 0]));
+        assert_eq!(vrc4.revision, Revision::Vrc4);
+
+        vrc4.cpu_write(0xB000 | a0, 0x01);
+        vrc4.cpu_write(0xB000 | a1, 0x05);
+
+        let ciram = ciram();
+        assert_eq!(
+            [vrc4.ppu_read(0x0000, &ciram), vrc4.ppu_read(0x0400, &ciram)],
+            [0x10, 0x05],
+            "flags 6 {flags_6:02X}"
+        );
+    }
+}
+
+#[test]
+fn from_rom() {
+    for mapper in [21, 22, 23, 25] {
+        let mut vrc4 = mapper::from_rom(rom(header(mapper, 0))).unwrap();
+
+        vrc4.cpu_write(0xA000, 3);
+
+        assert_eq!(vrc4.cpu_peek(0xA000), Some(24), "mapper {mapper}");
+    }
+}
+
+#[test]
+fn prg_swap_mode() {
+    let mut vrc4 = vrc(23, 1);
+    vrc4.cpu_write(0x8000, 3);
+    vrc4.cpu_write(0xA000, 5);
+    assert_eq!(prg_blocks(&vrc4), [24, 40, 112, 120]);
+
+    vrc4.cpu_write(0x9002, 0x02);
+    assert_eq!(prg_blocks(&vrc4), [112, 40, 24, 120]);
+
+    let mut vrc2 = vrc(23, 3);
+    vrc2.cpu_write(0x9002, 0x02);
+    assert_eq!(
+        prg_blocks(&vrc2)[0],
+        0,
+        "the VRC2 must not have a PRG swap mode"
+    );
+}
+
+#[test]
+fn chr_bank_size() {
+    // 512 KiB of CHR ROM, where block numbers wrap at 256 KiB
+    let mut header = header(23, 1);
+    header[1] = 64;
+    let mut rom = rom(header);
+    rom.chr_rom[0x01F0 * 1024] = 0xEE;
+
+    let mut vrc4 = Vrc4::new(rom.clone());
+    vrc4.cpu_write(0xB001, 0x1F);
+    assert_eq!(vrc4.ppu_read(0x0000, &ciram()), 0xEE);
+
+    rom.header.submapper = 3;
+    let mut vrc2 = Vrc4::new(rom);
+    vrc2.cpu_write(0xB001, 0x1F);
+    assert_eq!(
+        vrc2.ppu_read(0x0000, &ciram()),
+        0xF0,
+        "VRC2 CHR banks must have 8 bits"
+    );
+}
+
+#[test]
+fn mirroring() {
+    let mut vrc4 = vrc(23, 1);
+    let mut ciram = ciram();
+
+    vrc4.cpu_write(0x9000, 3);
+    vrc4.ppu_write(0x2000, 0x42, &mut ciram);
+    assert_eq!([ciram[0], ciram[0x0400]], [0, 0x42], "single screen upper");
+
+    vrc4.cpu_write(0x9000, 1);
+    assert_eq!(vrc4.ppu_read(0x2400, &ciram), 0, "horizontal");
+
+    let mut vrc2 = vrc(23, 3);
+    vrc2.cpu_write(0x9000, 3);
+    vrc2.ppu_write(0x2000, 0x43, &mut ciram);
+    assert_eq!(
+        vrc2.ppu_read(0x2400, &ciram),
+        0x43,
+        "the VRC2 must only have horizontal and vertical mirroring"
+    );
+}
+
+#[test]
+fn vrc2_latch() {
+    // no PRG RAM
+    let mut header = header(23, 3);
+    header[6] = 0;
+    let mut vrc2 = Vrc4::new(rom(header));
+
+    vrc2.cpu_write(0x6000, 0xFF);
+
+    assert_eq!(vrc2.cpu_read(0x6000, 0xA4), 0xA5);
+    assert_eq!(
+        vrc2.cpu_read(0x7000, 0xA4),
+        0xA4,
+        "the latch must only be in $6000-$6FFF"
+    );
+    assert_eq!(vrc2.cpu_peek(0x6000), None);
+}
+
+#[test]
+fn prg_ram() {
+    let mut vrc4 = vrc(25, 1);
+
+    vrc4.cpu_write(0x6000, 0x42);
+
+    assert_eq!(vrc4.cpu_peek(0x6000), Some(0x42));
+}
+
+#[test]
+fn irq_cycle_mode() {
+    let mut vrc4 = vrc(23, 1);
+    vrc4.cpu_write(0xF000, 0x0D);
+    vrc4.cpu_write(0xF001, 0x0F);
+    vrc4.cpu_write(0xF002, 0x07);
+
+    assert_eq!(cycles_to_irq(&mut vrc4), 3);
+    assert_eq!(
+        cycles_to_irq(&mut vrc4),
+        3,
+        "the counter must be reloaded when it overflows"
+    );
+
+    vrc4.cpu_write(0xF002, 0x06);
+    cycles_to_irq(&mut vrc4);
+    for _ in 0..10 {
+        vrc4.cpu_cycle();
+    }
+    assert!(
+        !vrc4.irq(),
+        "acknowledging must disable the counter unless the control register asked otherwise"
+    );
+}
+
+#[test]
+fn irq_scanline_mode() {
+    let mut vrc4 = vrc(23, 1);
+    vrc4.cpu_write(0xF000, 0x0F);
+    vrc4.cpu_write(0xF001, 0x0F);
+    vrc4.cpu_write(0xF002, 0x03);
+
+    let periods = [0; 3].map(|_| cycles_to_irq(&mut vrc4));
+
+    assert_eq!(
+        periods,
+        [114, 114, 113],
+        "the counter must be clocked every 341 PPU dots"
+    );
+}
+
+#[test]
+fn irq_control_acknowledges() {
+    let mut vrc4 = vrc(23, 1);
+    vrc4.cpu_write(0xF000, 0x0F);
+    vrc4.cpu_write(0xF001, 0x0F);
+    vrc4.cpu_write(0xF002, 0x06);
+    vrc4.cpu_cycle();
+    assert!(vrc4.irq());
+
+    vrc4.cpu_write(0xF002, 0x00);
+
+    assert!(!vrc4.irq());
+}
+
+#[test]
+fn vrc2_has_no_irq() {
+    let mut vrc2 = vrc(23, 3);
+    vrc2.cpu_write(0xF000, 0x0F);
+    vrc2.cpu_write(0xF001, 0x0F);
+    vrc2.cpu_write(0xF002, 0x06);
+
+    vrc2.cpu_cycle();
+
+    assert!(!vrc2.irq());
+}
+
+#[test]
+fn header_mirroring() {
+    let mut vrc4 = Vrc4::new(rom([8, 32, 0x71, 0x10, 0, 0, 0, 0, 0, 0, 0, 0]));
+    let mut ciram = ciram();
+
+    vrc4.ppu_write(0x2000, 0x42, &mut ciram);
+
+    assert_eq!(
+        vrc4.ppu_read(0x2800, &ciram),
+        0x42,
+        "the header's mirroring must be used until $9000 is written"
+    );
+}
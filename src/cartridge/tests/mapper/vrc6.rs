@@ -0,0 +1,218 @@
+use super::{ciram, rom};
+use crate::{
+    cartridge::{
+        Mapper,
+        mapper::{self, Vrc6},
+    },
+    mixer::{self, APU_PULSE_MAX},
+};
+
+/// 128 KiB of PRG ROM, 256 KiB of CHR ROM and 8 KiB of PRG RAM
+fn vrc() -> Vrc6 {
+    Vrc6::new(rom([8, 32, 0x80, 0x10, 0, 0, 0, 0, 0, 0, 0, 0]))
+}
+
+/// The 1 KiB PRG block numbers at `$8000`, `$A000`, `$C000` and `$E000`
+fn prg_blocks(vrc6: &Vrc6) -> [u8; 4] {
+    [0x8000, 0xA000, 0xC000, 0xE000].map(|address| vrc6.cpu_peek(address).unwrap())
+}
+
+/// The 1 KiB CHR block numbers at `$0000-$1FFF`
+fn chr_blocks(vrc6: &mut Vrc6) -> [u8; 8] {
+    let ciram = ciram();
+    [0, 1, 2, 3, 4, 5, 6, 7].map(|slot| vrc6.ppu_read(slot * 0x0400, &ciram))
+}
+
+/// The expansion audio output of the next CPU cycles, in DAC steps
+fn audio_steps(vrc6: &mut Vrc6, cycles: usize) -> Vec<u8> {
+    (0..cycles)
+        .map(|_| {
+            vrc6.cpu_cycle();
+            (vrc6.audio() * 15.0 / APU_PULSE_MAX).round() as u8
+        })
+        .collect()
+}
+
+#[test]
+fn prg_banks() {
+    let mut vrc6 = vrc();
+
+    vrc6.cpu_write(0x8000, 3);
+    vrc6.cpu_write(0xC000, 5);
+
+    assert_eq!(prg_blocks(&vrc6), [48, 56, 40, 120]);
+}
+
+#[test]
+fn wiring() {
+    // mapper 24 and 26, the address of R1
+    for (flags_6, address) in [(0x80, 0xD001), (0xA0, 0xD002)] {
+        let mut vrc6 =
+            mapper::from_rom(rom([8, 32, flags_6, 0x10, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+        vrc6.cpu_write(address, 7);
+
+        assert_eq!(vrc6.ppu_read(0x0400, &ciram()), 7, "flags 6 {flags_6:02X}");
+    }
+}
+
+#[test]
+fn chr_bank_modes() {
+    let mut vrc6 = vrc();
+    for (register, address) in [
+        0xD000, 0xD001, 0xD002, 0xD003, 0xE000, 0xE001, 0xE002, 0xE003,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        vrc6.cpu_write(address, register as u8 * 2 + 10);
+    }
+
+    for (control, blocks) in [
+        (0x20, [10, 12, 14, 16, 18, 20, 22, 24]),
+        (0x21, [10, 11, 12, 13, 14, 15, 16, 17]),
+        (0x01, [10, 10, 12, 12, 14, 14, 16, 16]),
+        (0x22, [10, 12, 14, 16, 18, 19, 20, 21]),
+        (0x23, [10, 12, 14, 16, 18, 19, 20, 21]),
+    ] {
+        vrc6.cpu_write(0xB003, control);
+        assert_eq!(chr_blocks(&mut vrc6), blocks, "$B003 = {control:02X}");
+    }
+}
+
+#[test]
+fn mirroring() {
+    let mut vrc6 = vrc();
+    let mut ciram = ciram();
+
+    vrc6.cpu_write(0xB003, 0x2C);
+    vrc6.ppu_write(0x2000, 0x42, &mut ciram);
+    assert_eq!([ciram[0], ciram[0x0400]], [0, 0x42], "single screen upper");
+
+    vrc6.cpu_write(0xB003, 0x24);
+    assert_eq!(vrc6.ppu_read(0x2C00, &ciram), 0x42, "horizontal");
+}
+
+#[test]
+fn prg_ram_enable() {
+    let mut vrc6 = vrc();
+    vrc6.cpu_write(0x6000, 0x42);
+    assert_eq!(
+        vrc6.cpu_read(0x6000, 0xA5),
+        0xA5,
+        "PRG RAM must be disabled on power on"
+    );
+
+    vrc6.cpu_write(0xB003, 0x80);
+    vrc6.cpu_write(0x6000, 0x42);
+    assert_eq!(vrc6.cpu_peek(0x6000), Some(0x42));
+}
+
+#[test]
+fn irq() {
+    let mut vrc6 = vrc();
+    vrc6.cpu_write(0xF000, 0xFE);
+    vrc6.cpu_write(0xF001, 0x06);
+
+    vrc6.cpu_cycle();
+    assert!(!vrc6.irq());
+    vrc6.cpu_cycle();
+    assert!(vrc6.irq());
+
+    vrc6.cpu_write(0xF002, 0);
+    assert!(!vrc6.irq());
+}
+
+#[test]
+fn pulse() {
+    let mut vrc6 = vrc();
+    // duty 3, volume 10, a step every cycle
+    vrc6.cpu_write(0xA000, 0x3A);
+    vrc6.cpu_write(0xA001, 0x00);
+    vrc6.cpu_write(0xA002, 0x00);
+    vrc6.cpu_write(0xA002, 0x80);
+
+    assert_eq!(
+        audio_steps(&mut vrc6, 16),
+        [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 10, 10, 10, 0]
+    );
+
+    vrc6.cpu_write(0xA000, 0xBA);
+    assert_eq!(
+        audio_steps(&mut vrc6, 4),
+        [10; 4],
+        "constant mode must ignore the duty"
+    );
+}
+
+#[test]
+fn pulse_period() {
+    let mut vrc6 = vrc();
+    // duty 7, volume 15, a step every 3 cycles
+    vrc6.cpu_write(0x9000, 0x7F);
+    vrc6.cpu_write(0x9001, 0x02);
+    vrc6.cpu_write(0x9002, 0x00);
+    vrc6.cpu_write(0x9002, 0x80);
+
+    assert_eq!(
+        audio_steps(&mut vrc6, 48),
+        [vec![0; 21], vec![15; 24], vec![0; 3]].concat()
+    );
+}
+
+#[test]
+fn sawtooth() {
+    let mut vrc6 = vrc();
+    vrc6.cpu_write(0xB000, 0x08);
+    vrc6.cpu_write(0xB001, 0x00);
+    vrc6.cpu_write(0xB002, 0x80);
+
+    assert_eq!(
+        audio_steps(&mut vrc6, 14),
+        [0, 1, 1, 2, 2, 3, 3, 4, 4, 5, 5, 6, 6, 0]
+    );
+}
+
+#[test]
+fn audio_control() {
+    let mut vrc6 = vrc();
+    vrc6.cpu_write(0xB000, 0x08);
+    vrc6.cpu_write(0xB001, 0x00);
+    vrc6.cpu_write(0xB002, 0x81);
+
+    vrc6.cpu_write(0x9003, 0x04);
+    assert_eq!(
+        audio_steps(&mut vrc6, 4),
+        [0, 0, 1, 1],
+        "the period must be shifted right 8 times"
+    );
+
+    vrc6.cpu_write(0x9003, 0x05);
+    assert_eq!(audio_steps(&mut vrc6, 4), [1; 4], "channels must be halted");
+}
+
+#[test]
+fn mixed_with_the_apu() {
+    let mut vrc6 = vrc();
+    // pulse 1 at volume 15 in constant mode
+    vrc6.cpu_write(0x9000, 0x8F);
+    vrc6.cpu_write(0x9002, 0x80);
+
+    assert!(
+        (vrc6.audio() - APU_PULSE_MAX).abs() < 1e-6,
+        "a pulse at full volume must be as loud as an APU pulse"
+    );
+    assert_eq!(mixer::mix(0.25, &vrc6), 0.25 + vrc6.audio());
+}
+
+#[test]
+fn other_mappers_are_silent() {
+    let mapper = mapper::from_rom(rom([2, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    assert_eq!(mapper.audio(), 0.0);
+    assert_eq!(
+        mixer::mix(0.25, &mapper),
+        0.25,
+        "the APU's output must be left as it is"
+    );
+}
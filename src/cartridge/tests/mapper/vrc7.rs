@@ -0,0 +1,110 @@
+use super::{ciram, rom};
+use crate::cartridge::{
+    Mapper,
+    mapper::{self, Vrc7},
+};
+
+/// NES 2.0 header with 128 KiB of PRG ROM, 256 KiB of CHR ROM and 8 KiB of PRG RAM
+fn vrc(submapper: u8) -> Vrc7 {
+    let submapper = submapper << 4;
+    Vrc7::new(rom([8, 32, 0x50, 0x58, submapper, 0, 0x07, 0, 0, 0, 0, 0]))
+}
+
+/// The 1 KiB PRG block numbers at `$8000`, `$A000`, `$C000` and `$E000`
+fn prg_blocks(vrc7: &Vrc7) -> [u8; 4] {
+    [0x8000, 0xA000, 0xC000, 0xE000].map(|address| vrc7.cpu_peek(address).unwrap())
+}
+
+#[test]
+fn prg_banks() {
+    // VRC7b, VRC7a and iNES
+    for (submapper, a0) in [(1, 0x08), (2, 0x10), (0, 0x10)] {
+        let mut vrc7 = vrc(submapper);
+
+        vrc7.cpu_write(0x8000, 1);
+        vrc7.cpu_write(0x8000 | a0, 2);
+        vrc7.cpu_write(0x9000, 3);
+
+        assert_eq!(prg_blocks(&vrc7), [8, 16, 24, 120], "submapper {submapper}");
+    }
+}
+
+#[test]
+fn from_rom() {
+    let mut vrc7 = mapper::from_rom(rom([8, 32, 0x50, 0x50, 0, 0, 0, 0, 0, 0, 0, 0])).unwrap();
+
+    vrc7.cpu_write(0x9000, 3);
+
+    assert_eq!(vrc7.cpu_peek(0xC000), Some(24));
+}
+
+#[test]
+fn chr_banks() {
+    let mut vrc7 = vrc(2);
+    for (bank, address) in [
+        0xA000, 0xA010, 0xB000, 0xB010, 0xC000, 0xC010, 0xD000, 0xD010,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        vrc7.cpu_write(address, bank as u8 + 100);
+    }
+
+    let ciram = ciram();
+    let blocks = [0, 1, 2, 3, 4, 5, 6, 7].map(|slot| vrc7.ppu_read(slot * 0x0400, &ciram));
+    assert_eq!(blocks, [100, 101, 102, 103, 104, 105, 106, 107]);
+}
+
+#[test]
+fn control() {
+    let mut vrc7 = vrc(2);
+    let mut ciram = ciram();
+
+    vrc7.cpu_write(0x6000, 0x42);
+    assert_eq!(
+        vrc7.cpu_read(0x6000, 0xA5),
+        0xA5,
+        "PRG RAM must be disabled on power on"
+    );
+
+    vrc7.cpu_write(0xE000, 0x81);
+    vrc7.cpu_write(0x6000, 0x42);
+    assert_eq!(vrc7.cpu_peek(0x6000), Some(0x42));
+
+    vrc7.ppu_write(0x2000, 0x43, &mut ciram);
+    assert_eq!(vrc7.ppu_read(0x2400, &ciram), 0x43, "horizontal mirroring");
+}
+
+#[test]
+fn irq() {
+    let mut vrc7 = vrc(2);
+    vrc7.cpu_write(0xE010, 0xFE);
+    vrc7.cpu_write(0xF000, 0x07);
+
+    vrc7.cpu_cycle();
+    assert!(!vrc7.irq());
+    vrc7.cpu_cycle();
+    assert!(vrc7.irq());
+
+    vrc7.cpu_write(0xF010, 0);
+    assert!(!vrc7.irq());
+    vrc7.cpu_cycle();
+    vrc7.cpu_cycle();
+    assert!(
+        vrc7.irq(),
+        "the counter must keep going after being acknowledged"
+    );
+}
+
+#[test]
+fn audio_ports() {
+    // VRC7b, where $9010 and $9030 would select the PRG bank register
+    let mut vrc7 = vrc(1);
+    vrc7.cpu_write(0x9000, 3);
+
+    vrc7.cpu_write(0x9010, 0x10);
+    vrc7.cpu_write(0x9030, 0x42);
+
+    assert_eq!(prg_blocks(&vrc7)[2], 24);
+    assert_eq!(vrc7.audio(), 0.0, "FM synthesis isn't emulated");
+}
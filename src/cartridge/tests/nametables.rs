@@ -0,0 +1,63 @@
+use crate::cartridge::{CIRAM_SIZE, Mirroring, Nametables};
+
+/// Write a different value to the start of each nametable and read them back
+fn pages(mirroring: Mirroring) -> ([u8; 4], [u8; CIRAM_SIZE]) {
+    let mut nametables = Nametables::new(mirroring);
+    let mut ciram = [0; CIRAM_SIZE];
+
+    for table in 0..4 {
+        nametables.write(0x2000 + table * 0x0400, table as u8 + 1, &mut ciram);
+    }
+
+    let values = [0, 1, 2, 3].map(|table| nametables.read(0x2000 + table * 0x0400, &ciram));
+    (values, ciram)
+}
+
+#[test]
+fn horizontal() {
+    let (values, ciram) = pages(Mirroring::Horizontal);
+
+    assert_eq!(values, [2, 2, 4, 4]);
+    assert_eq!([ciram[0], ciram[0x0400]], [2, 4]);
+}
+
+#[test]
+fn vertical() {
+    let (values, ciram) = pages(Mirroring::Vertical);
+
+    assert_eq!(values, [3, 4, 3, 4]);
+    assert_eq!([ciram[0], ciram[0x0400]], [3, 4]);
+}
+
+#[test]
+fn four_screen() {
+    let (values, ciram) = pages(Mirroring::FourScreen);
+
+    assert_eq!(values, [1, 2, 3, 4]);
+    assert_eq!(
+        [ciram[0], ciram[0x0400]],
+        [1, 2],
+        "the last 2 nametables must be on the cartridge"
+    );
+}
+
+#[test]
+fn single_screen() {
+    let (values, ciram) = pages(Mirroring::SingleScreenLower);
+    assert_eq!(values, [4, 4, 4, 4]);
+    assert_eq!([ciram[0], ciram[0x0400]], [4, 0]);
+
+    let (values, ciram) = pages(Mirroring::SingleScreenUpper);
+    assert_eq!(values, [4, 4, 4, 4]);
+    assert_eq!([ciram[0], ciram[0x0400]], [0, 4]);
+}
+
+#[test]
+fn mirrors_above_3000() {
+    let mut nametables = Nametables::new(Mirroring::Vertical);
+    let mut ciram = [0; CIRAM_SIZE];
+
+    nametables.write(0x3EFF, 0x42, &mut ciram);
+
+    assert_eq!(nametables.read(0x2EFF, &ciram), 0x42);
+}
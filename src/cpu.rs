@@ -2,17 +2,34 @@
 
 use bitflags::bitflags;
 
-use crate::{cpu::executor::Executor, memory::Memory};
+use crate::{
+    cpu::{cycle_log::CycleLog, executor::Executor},
+    memory::Memory,
+};
 
 mod addressing_modes;
 mod arithmetic;
+mod bus_observer;
+mod cmos_opcode;
+mod cycle_log;
 mod executor;
+mod illegal_opcode;
 mod instructions;
+mod interrupts;
+#[cfg(feature = "unstable-opcodes")]
+mod magic_constant;
 mod opcode;
+mod variant;
 
 #[cfg(all(test, not(tarpaulin_include)))]
 mod tests;
 
+pub use bus_observer::{BusCycle, BusDirection, BusObserver};
+pub use illegal_opcode::{IllegalOpcode, IllegalOpcodePolicy};
+#[cfg(feature = "unstable-opcodes")]
+pub use magic_constant::MagicConstant;
+pub use variant::Variant;
+
 bitflags! {
     /// Status Flags used by the Processor Status register
     ///
@@ -58,20 +75,185 @@ pub struct Cpu {
     pub pc: u16,
 
     /// SP (Stack Pointer) Register
+    ///
+    /// It's `$FF` on power-up, the reset sequence decrements it by 3
     pub sp: u8,
 
     /// Processor Status Register
     pub flags: StatusFlags,
+
+    /// The chip being emulated
+    pub variant: Variant,
+
+    /// What to do when an opcode that isn't implemented is encountered
+    pub illegal_opcode_policy: IllegalOpcodePolicy,
+
+    /// Constant used by the unstable XAA and LXA opcodes
+    #[cfg(feature = "unstable-opcodes")]
+    pub magic_constant: MagicConstant,
+
+    /// Cycles performed so far of an instruction started by `tick`
+    cycle_log: CycleLog,
+
+    /// Last value that was on the data bus, read back from addresses nothing responds to
+    data_bus: u8,
+
+    /// Set when the CPU has halted, only a reset brings it back
+    jammed: bool,
+
+    /// Set by the 65C02 WAI instruction, cleared when an interrupt line is asserted
+    waiting: bool,
+
+    /// Level of the NMI line, used to detect edges
+    nmi_line: bool,
+
+    /// Set when an edge on the NMI line has been detected and the NMI hasn't been serviced yet
+    nmi_pending: bool,
+
+    /// Level of the IRQ line
+    irq_line: bool,
+
+    /// Level of the IRQ line driven by memory, sampled before every cycle
+    memory_irq: bool,
+
+    /// Result of the most recent interrupt poll
+    ///
+    /// The CPU polls for interrupts before every cycle,
+    /// the value left after an instruction is what determines
+    /// whether an interrupt will be serviced instead of the next instruction
+    interrupt_poll: bool,
 }
 
 impl Cpu {
+    /// Create a CPU in its power-up state
+    ///
+    /// The power-up state doesn't load PC from the reset vector,
+    /// `reset` should be called before executing any instructions
     pub fn new() -> Self {
         Self::default()
     }
 
-    pub fn execute_next_instruction<M: Memory>(&mut self, memory: &mut M) {
-        let mut executor = Executor { cpu: self, memory };
-        executor.execute_next_instruction();
+    /// Execute the next instruction
+    ///
+    /// If an interrupt was detected while executing the previous instruction,
+    /// the interrupt sequence is executed instead.
+    /// If the CPU is jammed or waiting for an interrupt, a single dummy read cycle is performed.
+    /// If an instruction was partially executed by `tick`, only its remaining cycles are performed.
+    ///
+    /// # Errors
+    /// Returns an error if the opcode isn't implemented
+    /// and `illegal_opcode_policy` is `IllegalOpcodePolicy::Error`
+    pub fn execute_next_instruction<M: Memory>(
+        &mut self,
+        memory: &mut M,
+    ) -> Result<(), IllegalOpcode> {
+        self.execute_next_instruction_observed(memory, ())
+    }
+
+    /// Execute the next instruction like `execute_next_instruction`,
+    /// notifying the observer of every bus access
+    ///
+    /// # Errors
+    /// Returns an error if the opcode isn't implemented
+    /// and `illegal_opcode_policy` is `IllegalOpcodePolicy::Error`
+    pub fn execute_next_instruction_observed<M: Memory, O: BusObserver>(
+        &mut self,
+        memory: &mut M,
+        mut observer: O,
+    ) -> Result<(), IllegalOpcode> {
+        if self.is_mid_instruction() {
+            while self.is_mid_instruction() {
+                self.tick_observed(memory, &mut observer)?;
+            }
+
+            return Ok(());
+        }
+
+        let mut executor = Executor {
+            cpu: self,
+            memory,
+            observer,
+        };
+        executor.execute_next_instruction()
+    }
+
+    /// Perform the 7 cycle reset sequence
+    ///
+    /// Loads PC from the reset vector at `$FFFC`, decrements SP by 3 and sets the INTERRUPT_DISABLE flag,
+    /// other registers are left as they are. Also brings a jammed CPU back
+    pub fn reset<M: Memory>(&mut self, memory: &mut M) {
+        self.reset_observed(memory, ());
+    }
+
+    /// Perform the reset sequence like `reset`, notifying the observer of every bus access
+    pub fn reset_observed<M: Memory, O: BusObserver>(&mut self, memory: &mut M, observer: O) {
+        // an instruction that's partially executed is abandoned
+        self.cycle_log = CycleLog::default();
+
+        let mut executor = Executor {
+            cpu: self,
+            memory,
+            observer,
+        };
+        executor.reset();
+    }
+
+    /// The last value that was read from or written to the data bus
+    ///
+    /// Reads from addresses nothing responds to return it (open bus)
+    pub fn data_bus(&self) -> u8 {
+        self.data_bus
+    }
+
+    /// Whether the CPU has halted
+    ///
+    /// A jammed CPU doesn't execute instructions or respond to interrupts until it's reset
+    pub fn is_jammed(&self) -> bool {
+        self.jammed
+    }
+
+    /// Whether the CPU is stopped by the 65C02 WAI instruction
+    ///
+    /// It resumes when the NMI or IRQ line is asserted, even if IRQs are disabled
+    pub fn is_waiting(&self) -> bool {
+        self.waiting
+    }
+
+    /// Set the level of the NMI line, `true` meaning the line is asserted
+    ///
+    /// NMI is edge sensitive, an NMI is triggered when the line goes from deasserted to asserted
+    pub fn set_nmi(&mut self, asserted: bool) {
+        if asserted && !self.nmi_line {
+            self.nmi_pending = true;
+            self.cycle_log.nmi_edge();
+        }
+
+        self.nmi_line = asserted;
+    }
+
+    /// Set the level of the IRQ line, `true` meaning the line is asserted
+    ///
+    /// IRQ is level sensitive, interrupts are triggered as long as the line stays asserted
+    /// and the INTERRUPT_DISABLE flag is clear.
+    /// Devices on the bus can also assert it through `Memory::irq`
+    pub fn set_irq(&mut self, asserted: bool) {
+        self.irq_line = asserted;
+    }
+
+    /// Poll the interrupt lines, should be done before every cycle
+    fn poll_interrupts(&mut self) {
+        let irq = self.irq_asserted() && !self.flags.contains(StatusFlags::INTERRUPT_DISABLE);
+        self.interrupt_poll = self.nmi_pending || irq;
+    }
+
+    /// Whether the IRQ line is asserted by anything
+    fn irq_asserted(&self) -> bool {
+        self.irq_line || self.memory_irq
+    }
+
+    /// Whether ADC and SBC should do BCD arithmetic
+    fn decimal_mode(&self) -> bool {
+        self.variant.has_decimal_mode() && self.flags.contains(StatusFlags::DECIMAL)
     }
 
     fn set_register_with_flags(
@@ -88,6 +270,17 @@ impl Cpu {
         self.flags.set(StatusFlags::NEGATIVE, (value as i8) < 0);
         self.flags.set(StatusFlags::ZERO, value == 0);
     }
+
+    /// Set the flags to a value pulled from the stack
+    ///
+    /// BREAK and IGNORED don't physically exist in the status register,
+    /// so they are left as they were
+    fn restore_flags(&mut self, value: u8) {
+        const UNCHANGED: StatusFlags = StatusFlags::BREAK.union(StatusFlags::IGNORED);
+
+        let pulled = StatusFlags::from_bits_retain(value).difference(UNCHANGED);
+        self.flags = pulled | self.flags.intersection(UNCHANGED);
+    }
 }
 
 impl Default for Cpu {
@@ -100,6 +293,19 @@ impl Default for Cpu {
             sp: 0xFF,
             flags: StatusFlags::default(),
             clock_cycle_count: 0,
+            variant: Variant::default(),
+            illegal_opcode_policy: IllegalOpcodePolicy::default(),
+            #[cfg(feature = "unstable-opcodes")]
+            magic_constant: MagicConstant::default(),
+            cycle_log: CycleLog::default(),
+            data_bus: 0,
+            jammed: false,
+            waiting: false,
+            nmi_line: false,
+            nmi_pending: false,
+            irq_line: false,
+            memory_irq: false,
+            interrupt_poll: false,
         }
     }
 }
@@ -1,5 +1,9 @@
 pub mod implied;
+pub mod jump;
 pub mod read;
+pub mod relative;
 pub mod rmw;
 pub mod write;
 pub mod stack;
+#[cfg(feature = "unstable-opcodes")]
+pub mod unstable_store;
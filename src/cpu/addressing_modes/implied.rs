@@ -1,5 +1,5 @@
 use crate::{
-    cpu::{Cpu, executor::Executor},
+    cpu::{BusObserver, Cpu, executor::Executor},
     memory::Memory,
 };
 
@@ -8,9 +8,9 @@ pub trait ImpliedInstruction {
 }
 
 pub trait Implied: ImpliedInstruction {
-    fn implied<M: Memory>(executor: &mut Executor<M>) {
+    fn implied<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         // dummy read at PC
-        let _ = executor.read_cycle(executor.cpu.pc);
+        executor.dummy_read_cycle(executor.cpu.pc);
         Self::instruction(executor.cpu);
     }
 }
@@ -0,0 +1,63 @@
+use crate::{
+    cpu::{BusObserver, Cpu, executor::Executor},
+    memory::Memory,
+};
+
+pub trait JumpInstruction {
+    fn instruction(cpu: &mut Cpu, addr: u16);
+}
+
+pub trait JumpAbsolute: JumpInstruction {
+    fn absolute<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let addr_low = executor.fetch_from_pc_cycle();
+        // PC is overwritten right away, so no need to increment it
+        let addr_high = executor.read_cycle(executor.cpu.pc);
+
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+        Self::instruction(executor.cpu, addr);
+    }
+}
+
+pub trait JumpIndirect: JumpInstruction {
+    fn indirect<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let ptr_low = executor.fetch_from_pc_cycle();
+        let ptr_high = executor.read_cycle(executor.cpu.pc);
+
+        let ptr = (ptr_high as u16) << 8 | ptr_low as u16;
+
+        let addr_high_ptr = if executor.cpu.variant.is_cmos() {
+            // the 65C02 takes an extra cycle to propagate the carry
+            executor.dummy_read_cycle(executor.cpu.pc);
+            ptr.wrapping_add(1)
+        } else {
+            // note: the carry from incrementing the low byte of the pointer is not propagated,
+            // so JMP ($xxFF) fetches the high byte from $xx00
+            (ptr_high as u16) << 8 | ptr_low.wrapping_add(1) as u16
+        };
+
+        let addr_low = executor.read_cycle(ptr);
+        let addr_high = executor.read_cycle(addr_high_ptr);
+
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+        Self::instruction(executor.cpu, addr);
+    }
+}
+
+/// 65C02 only
+pub trait JumpAbsoluteXIndirect: JumpInstruction {
+    fn absolute_x_indirect<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let base_ptr_low = executor.fetch_from_pc_cycle();
+        let base_ptr_high = executor.read_cycle(executor.cpu.pc);
+        // dummy read while X is added
+        executor.dummy_read_cycle(executor.cpu.pc);
+
+        let base_ptr = (base_ptr_high as u16) << 8 | base_ptr_low as u16;
+        let ptr = base_ptr.wrapping_add(executor.cpu.x as u16);
+
+        let addr_low = executor.read_cycle(ptr);
+        let addr_high = executor.read_cycle(ptr.wrapping_add(1));
+
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+        Self::instruction(executor.cpu, addr);
+    }
+}
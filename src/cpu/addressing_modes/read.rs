@@ -1,35 +1,38 @@
 use crate::{
-    cpu::{Cpu, executor::Executor},
+    cpu::{BusObserver, Cpu, StatusFlags, executor::Executor},
     memory::Memory,
 };
 
 pub trait ReadInstruction {
+    /// Whether the 65C02 takes an extra cycle when the DECIMAL flag is set
+    const DECIMAL_EXTRA_CYCLE: bool = false;
+
     fn instruction(cpu: &mut Cpu, value: u8);
 }
 
 pub trait ReadImmediate: ReadInstruction {
-    fn immediate<M: Memory>(executor: &mut Executor<M>) {
+    fn immediate<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let value = executor.fetch_from_pc_cycle();
-        Self::instruction(executor.cpu, value);
+        execute::<Self>(executor, value);
     }
 }
 
 pub trait ReadZeropage: ReadInstruction {
-    fn zeropage<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr = executor.fetch_from_pc_cycle() as u16;
         let value = executor.read_cycle(addr);
-        Self::instruction(executor.cpu, value);
+        execute::<Self>(executor, value);
     }
 }
 
 pub trait ReadZeropageX: ReadInstruction {
-    fn zeropage_x<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         zeropage_indexed::<Self>(executor, |cpu| cpu.x);
     }
 }
 
 pub trait ReadZeropageY: ReadInstruction {
-    fn zeropage_y<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         zeropage_indexed::<Self>(executor, |cpu| cpu.y);
     }
 }
@@ -39,42 +42,42 @@ pub trait ReadZeropageY: ReadInstruction {
 // instead of zeropage_indexed::<Self, _, _>
 
 fn zeropage_indexed<I: ReadInstruction + ?Sized>(
-    executor: &mut Executor<impl Memory>,
+    executor: &mut Executor<impl Memory, impl BusObserver>,
     get_index: impl FnOnce(&Cpu) -> u8,
 ) {
     let base_addr = executor.fetch_from_pc_cycle();
     // dummy read from addr
-    let _ = executor.read_cycle(base_addr as u16);
+    executor.dummy_read_cycle(base_addr as u16);
     let addr = base_addr.wrapping_add(get_index(executor.cpu)) as u16;
     let value = executor.read_cycle(addr);
-    I::instruction(executor.cpu, value);
+    execute::<I>(executor, value);
 }
 
 pub trait ReadAbsolute: ReadInstruction {
-    fn absolute<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr_low = executor.fetch_from_pc_cycle();
         let addr_high = executor.fetch_from_pc_cycle();
 
         let addr = (addr_high as u16) << 8 | addr_low as u16;
         let value = executor.read_cycle(addr);
-        Self::instruction(executor.cpu, value);
+        execute::<Self>(executor, value);
     }
 }
 
 pub trait ReadAbsoluteX: ReadInstruction {
-    fn absolute_x<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         absolute_indexed::<Self>(executor, |cpu| cpu.x);
     }
 }
 
 pub trait ReadAbsoluteY: ReadInstruction {
-    fn absolute_y<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         absolute_indexed::<Self>(executor, |cpu| cpu.y);
     }
 }
 
 fn absolute_indexed<I: ReadInstruction + ?Sized>(
-    executor: &mut Executor<impl Memory>,
+    executor: &mut Executor<impl Memory, impl BusObserver>,
     get_index: impl FnOnce(&Cpu) -> u8,
 ) {
     let base_addr_low = executor.fetch_from_pc_cycle();
@@ -83,24 +86,26 @@ fn absolute_indexed<I: ReadInstruction + ?Sized>(
     let (addr_low, carry) = base_addr_low.overflowing_add(get_index(executor.cpu));
 
     let addr = (base_addr_high as u16) << 8 | addr_low as u16;
-    // if carry is true, addr is wrong, this will be a dummy read
-    let value = executor.read_cycle(addr);
 
     if carry {
+        // addr is wrong, this is a dummy read
+        executor.dummy_read_cycle(first_read_addr(executor.cpu, addr, carry));
+
         // redo the read
         let corrected_addr = addr.wrapping_add(1 << 8);
         let value = executor.read_cycle(corrected_addr);
-        I::instruction(executor.cpu, value);
+        execute::<I>(executor, value);
     } else {
-        I::instruction(executor.cpu, value);
+        let value = executor.read_cycle(addr);
+        execute::<I>(executor, value);
     }
 }
 
 pub trait ReadIndirectX: ReadInstruction {
-    fn indirect_x<M: Memory>(executor: &mut Executor<M>) {
+    fn indirect_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let base_ptr = executor.fetch_from_pc_cycle();
         // dummy read
-        let _ = executor.read_cycle(base_ptr as u16);
+        executor.dummy_read_cycle(base_ptr as u16);
         let ptr = base_ptr.wrapping_add(executor.cpu.x);
 
         // note: intentionally first adding and then extending to 16 bit
@@ -110,12 +115,12 @@ pub trait ReadIndirectX: ReadInstruction {
         let addr = (addr_high as u16) << 8 | addr_low as u16;
 
         let value = executor.read_cycle(addr);
-        Self::instruction(executor.cpu, value);
+        execute::<Self>(executor, value);
     }
 }
 
 pub trait ReadIndirectY: ReadInstruction {
-    fn indirect_y<M: Memory>(executor: &mut Executor<M>) {
+    fn indirect_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let ptr = executor.fetch_from_pc_cycle();
 
         // note: zero page crossing is intentionally not handled
@@ -124,15 +129,57 @@ pub trait ReadIndirectY: ReadInstruction {
 
         let (addr_low, carry) = base_addr_low.overflowing_add(executor.cpu.y);
         let addr = (base_addr_high as u16) << 8 | addr_low as u16;
-        // might be a dummy read if carry is true and we need to fix up the high byte of the address
-        let value = executor.read_cycle(addr);
 
         if carry {
+            // dummy read, the high byte of the address needs to be fixed up
+            executor.dummy_read_cycle(first_read_addr(executor.cpu, addr, carry));
+
             let corrected_addr = addr.wrapping_add(1 << 8);
             let value = executor.read_cycle(corrected_addr);
-            Self::instruction(executor.cpu, value);
+            execute::<Self>(executor, value);
         } else {
-            Self::instruction(executor.cpu, value);
+            let value = executor.read_cycle(addr);
+            execute::<Self>(executor, value);
         }
     }
 }
+
+pub trait ReadZeropageIndirect: ReadInstruction {
+    fn zeropage_indirect<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let ptr = executor.fetch_from_pc_cycle();
+
+        let addr_low = executor.read_cycle(ptr as u16);
+        let addr_high = executor.read_cycle(ptr.wrapping_add(1) as u16);
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+
+        let value = executor.read_cycle(addr);
+        execute::<Self>(executor, value);
+    }
+}
+
+/// Address of the first read after indexing, which is a dummy read if a page was crossed
+///
+/// The 65C02 rereads the last operand byte instead of reading from the wrong address
+pub(super) fn first_read_addr(cpu: &Cpu, addr: u16, page_crossed: bool) -> u16 {
+    if page_crossed && cpu.variant.is_cmos() {
+        cpu.pc.wrapping_sub(1)
+    } else {
+        addr
+    }
+}
+
+fn execute<I: ReadInstruction + ?Sized>(
+    executor: &mut Executor<impl Memory, impl BusObserver>,
+    value: u8,
+) {
+    let decimal_extra_cycle = I::DECIMAL_EXTRA_CYCLE
+        && executor.cpu.variant.is_cmos()
+        && executor.cpu.flags.contains(StatusFlags::DECIMAL);
+
+    I::instruction(executor.cpu, value);
+
+    if decimal_extra_cycle {
+        // dummy read of the next opcode while the result is adjusted
+        executor.dummy_read_cycle(executor.cpu.pc);
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{
+    cpu::{BusObserver, Cpu, executor::Executor},
+    memory::Memory,
+};
+
+pub trait RelativeInstruction {
+    /// Whether the branch should be taken
+    fn condition(cpu: &Cpu) -> bool;
+}
+
+pub trait Relative: RelativeInstruction {
+    fn relative<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let offset = executor.fetch_from_pc_cycle() as i8;
+
+        if Self::condition(executor.cpu) {
+            branch(executor, offset);
+        }
+    }
+}
+
+impl<I: RelativeInstruction> Relative for I {}
+
+/// 65C02 BBR and BBS, which test a bit of a zero page value
+pub trait BitBranchInstruction {
+    /// Whether the branch should be taken
+    fn condition(value: u8) -> bool;
+}
+
+pub trait ZeropageRelative: BitBranchInstruction {
+    fn zeropage_relative<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let addr = executor.fetch_from_pc_cycle() as u16;
+        let value = executor.read_cycle(addr);
+        // dummy read while the bit is tested
+        executor.dummy_read_cycle(addr);
+        let offset = executor.fetch_from_pc_cycle() as i8;
+
+        if Self::condition(value) {
+            branch(executor, offset);
+        }
+    }
+}
+
+impl<I: BitBranchInstruction> ZeropageRelative for I {}
+
+/// The cycles of a taken branch, after the offset has been fetched
+fn branch(executor: &mut Executor<impl Memory, impl BusObserver>, offset: i8) {
+    // a taken branch doesn't poll for interrupts before its last cycle,
+    // unless the page is crossed
+    let interrupt_poll = executor.cpu.interrupt_poll;
+
+    // dummy read of the next opcode while the offset is added to PCL
+    executor.dummy_read_cycle(executor.cpu.pc);
+
+    let [pc_low, pc_high] = executor.cpu.pc.to_le_bytes();
+    let (target_low, page_crossed) = pc_low.overflowing_add_signed(offset);
+    let target = executor.cpu.pc.wrapping_add_signed(offset as i16);
+
+    if page_crossed {
+        // PCH hasn't been fixed up yet, dummy read from the wrong page
+        executor.dummy_read_cycle((pc_high as u16) << 8 | target_low as u16);
+    } else {
+        executor.cpu.interrupt_poll = interrupt_poll;
+    }
+
+    executor.cpu.pc = target;
+}
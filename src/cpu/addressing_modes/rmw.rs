@@ -1,5 +1,6 @@
+use super::read::first_read_addr;
 use crate::{
-    cpu::{Cpu, executor::Executor},
+    cpu::{BusObserver, Cpu, executor::Executor},
     memory::Memory,
 };
 
@@ -8,73 +9,137 @@ pub trait RmwInstruction {
 }
 
 pub trait RmwAccumulator: RmwInstruction {
-    fn accumulator<M: Memory>(executor: &mut Executor<M>) {
+    fn accumulator<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         // dummy read at PC
-        let _ = executor.read_cycle(executor.cpu.pc);
+        executor.dummy_read_cycle(executor.cpu.pc);
         let value = executor.cpu.a;
         executor.cpu.a = Self::instruction(executor.cpu, value);
     }
 }
 
 pub trait RmwZeropage: RmwInstruction {
-    fn zeropage<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr = executor.fetch_from_pc_cycle() as u16;
         let value = executor.read_cycle(addr);
 
-        // dummy write back to effective addr
-        executor.write_cycle(addr, value);
-        let output = Self::instruction(executor.cpu, value);
-
-        executor.write_cycle(addr, output);
+        modify_write::<Self>(executor, addr, value);
     }
 }
 
 pub trait RmwZeropageX: RmwInstruction {
-    fn zeropage_x<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let base_addr = executor.fetch_from_pc_cycle();
         // dummy read from addr
-        let _ = executor.read_cycle(base_addr as u16);
+        executor.dummy_read_cycle(base_addr as u16);
         // overflow is intentionally not carried into the high byte
         let addr = base_addr.wrapping_add(executor.cpu.x) as u16;
         let value = executor.read_cycle(addr);
 
-        executor.write_cycle(addr, value);
-        let output = Self::instruction(executor.cpu, value);
-
-        executor.write_cycle(addr, output);
+        modify_write::<Self>(executor, addr, value);
     }
 }
 
 pub trait RmwAbsolute: RmwInstruction {
-    fn absolute<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr_low = executor.fetch_from_pc_cycle();
         let addr_high = executor.fetch_from_pc_cycle();
 
         let addr = (addr_high as u16) << 8 | addr_low as u16;
         let value = executor.read_cycle(addr);
 
-        executor.write_cycle(addr, value);
-        let output = Self::instruction(executor.cpu, value);
-
-        executor.write_cycle(addr, output);
+        modify_write::<Self>(executor, addr, value);
     }
 }
 
 pub trait RmwAbsoluteX: RmwInstruction {
-    fn absolute_x<M: Memory>(executor: &mut Executor<M>) {
-        let base_addr_low = executor.fetch_from_pc_cycle();
-        let base_addr_high = executor.fetch_from_pc_cycle();
+    /// Whether the 65C02 skips the dummy read when no page is crossed
+    ///
+    /// It does for shifts and rotates, but not for INC and DEC
+    const CMOS_SKIPS_DUMMY_READ: bool = true;
 
-        let (addr_low, carry) = base_addr_low.overflowing_add(executor.cpu.x);
-        let addr = (base_addr_high as u16) << 8 | addr_low as u16;
+    fn absolute_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        absolute_indexed::<Self>(executor, |cpu| cpu.x, Self::CMOS_SKIPS_DUMMY_READ);
+    }
+}
+
+pub trait RmwAbsoluteY: RmwInstruction {
+    fn absolute_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        absolute_indexed::<Self>(executor, |cpu| cpu.y, false);
+    }
+}
+
+fn absolute_indexed<I: RmwInstruction + ?Sized>(
+    executor: &mut Executor<impl Memory, impl BusObserver>,
+    get_index: impl FnOnce(&Cpu) -> u8,
+    cmos_skips_dummy_read: bool,
+) {
+    let base_addr_low = executor.fetch_from_pc_cycle();
+    let base_addr_high = executor.fetch_from_pc_cycle();
+
+    let (addr_low, carry) = base_addr_low.overflowing_add(get_index(executor.cpu));
+    let addr = (base_addr_high as u16) << 8 | addr_low as u16;
 
-        let _ = executor.read_cycle(addr);
+    if carry || !(cmos_skips_dummy_read && executor.cpu.variant.is_cmos()) {
+        executor.dummy_read_cycle(first_read_addr(executor.cpu, addr, carry));
+    }
+    let addr = addr.wrapping_add((carry as u16) << 8);
+
+    let value = executor.read_cycle(addr);
+    modify_write::<I>(executor, addr, value);
+}
+
+pub trait RmwIndirectX: RmwInstruction {
+    fn indirect_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let base_ptr = executor.fetch_from_pc_cycle();
+        // dummy read
+        executor.dummy_read_cycle(base_ptr as u16);
+        let ptr = base_ptr.wrapping_add(executor.cpu.x);
+
+        // note: intentionally first adding and then extending to 16 bit
+        // overflowing page zero wraps to the beginning of page zero, not to page 1
+        let addr_low = executor.read_cycle(ptr as u16);
+        let addr_high = executor.read_cycle(ptr.wrapping_add(1) as u16);
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+
+        let value = executor.read_cycle(addr);
+        modify_write::<Self>(executor, addr, value);
+    }
+}
+
+pub trait RmwIndirectY: RmwInstruction {
+    fn indirect_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let ptr = executor.fetch_from_pc_cycle();
+
+        // note: zero page crossing is intentionally not handled
+        let base_addr_low = executor.read_cycle(ptr as u16);
+        let base_addr_high = executor.read_cycle(ptr.wrapping_add(1) as u16);
+
+        let (addr_low, carry) = base_addr_low.overflowing_add(executor.cpu.y);
+        let addr = (base_addr_high as u16) << 8 | addr_low as u16;
+        // dummy read from an address that might be wrong
+        executor.dummy_read_cycle(addr);
         let addr = addr.wrapping_add((carry as u16) << 8);
 
         let value = executor.read_cycle(addr);
-        executor.write_cycle(addr, value);
+        modify_write::<Self>(executor, addr, value);
+    }
+}
 
-        let output = Self::instruction(executor.cpu, value);
-        executor.write_cycle(addr, output);
+/// The cycles after the value has been read
+///
+/// The NMOS 6502 writes the unmodified value back while it's being modified,
+/// the 65C02 rereads it instead
+fn modify_write<I: RmwInstruction + ?Sized>(
+    executor: &mut Executor<impl Memory, impl BusObserver>,
+    addr: u16,
+    value: u8,
+) {
+    if executor.cpu.variant.is_cmos() {
+        executor.dummy_read_cycle(addr);
+    } else {
+        executor.dummy_write_cycle(addr, value);
     }
+
+    let output = I::instruction(executor.cpu, value);
+    executor.write_cycle(addr, output);
 }
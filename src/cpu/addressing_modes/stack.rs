@@ -1,5 +1,5 @@
 use crate::{
-    cpu::{Cpu, executor::Executor},
+    cpu::{BusObserver, Cpu, executor::Executor},
     memory::Memory,
 };
 
@@ -8,8 +8,8 @@ pub trait StackPushInstruction {
 }
 
 pub trait StackPush: StackPushInstruction {
-    fn stack_push<M: Memory>(executor: &mut Executor<M>) {
-        let _ = executor.read_cycle(executor.cpu.pc);
+    fn stack_push<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        executor.dummy_read_cycle(executor.cpu.pc);
         let value = Self::instruction(executor.cpu);
         executor.stack_write(value);
         executor.cpu.sp = executor.cpu.sp.wrapping_sub(1);
@@ -17,3 +17,20 @@ pub trait StackPush: StackPushInstruction {
 }
 
 impl<I: StackPushInstruction> StackPush for I {}
+
+pub trait StackPullInstruction {
+    fn instruction(cpu: &mut Cpu, value: u8);
+}
+
+pub trait StackPull: StackPullInstruction {
+    fn stack_pull<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        executor.dummy_read_cycle(executor.cpu.pc);
+        // dummy read from the top of the stack while SP is incremented
+        executor.dummy_stack_read();
+        executor.cpu.sp = executor.cpu.sp.wrapping_add(1);
+        let value = executor.stack_read();
+        Self::instruction(executor.cpu, value);
+    }
+}
+
+impl<I: StackPullInstruction> StackPull for I {}
@@ -0,0 +1,74 @@
+use crate::{
+    cpu::{BusObserver, Cpu, executor::Executor},
+    memory::Memory,
+};
+
+/// Unstable unofficial stores (SHA, SHX, SHY, TAS)
+///
+/// The stored value gets ANDed with the high byte of the base address + 1.
+/// If adding the index crosses a page, the high byte of the target address
+/// gets replaced with the stored value
+pub trait UnstableStoreInstruction {
+    /// The value before it's ANDed with the high byte of the address
+    fn instruction(cpu: &mut Cpu) -> u8;
+}
+
+pub trait UnstableStoreAbsoluteX: UnstableStoreInstruction {
+    fn absolute_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        absolute_indexed::<Self>(executor, |cpu| cpu.x);
+    }
+}
+
+pub trait UnstableStoreAbsoluteY: UnstableStoreInstruction {
+    fn absolute_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        absolute_indexed::<Self>(executor, |cpu| cpu.y);
+    }
+}
+
+fn absolute_indexed<I: UnstableStoreInstruction + ?Sized>(
+    executor: &mut Executor<impl Memory, impl BusObserver>,
+    get_index: impl FnOnce(&Cpu) -> u8,
+) {
+    let base_addr_low = executor.fetch_from_pc_cycle();
+    let base_addr_high = executor.fetch_from_pc_cycle();
+
+    let (addr_low, carry) = base_addr_low.overflowing_add(get_index(executor.cpu));
+    let addr = (base_addr_high as u16) << 8 | addr_low as u16;
+    // dummy read from an address that might be wrong
+    executor.dummy_read_cycle(addr);
+
+    store::<I>(executor, base_addr_high, addr_low, carry);
+}
+
+pub trait UnstableStoreIndirectY: UnstableStoreInstruction {
+    fn indirect_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let ptr = executor.fetch_from_pc_cycle();
+
+        // note: zero page crossing is intentionally not handled
+        let base_addr_low = executor.read_cycle(ptr as u16);
+        let base_addr_high = executor.read_cycle(ptr.wrapping_add(1) as u16);
+
+        let (addr_low, carry) = base_addr_low.overflowing_add(executor.cpu.y);
+        let addr = (base_addr_high as u16) << 8 | addr_low as u16;
+        // dummy read from an address that might be wrong
+        executor.dummy_read_cycle(addr);
+
+        store::<Self>(executor, base_addr_high, addr_low, carry);
+    }
+}
+
+fn store<I: UnstableStoreInstruction + ?Sized>(
+    executor: &mut Executor<impl Memory, impl BusObserver>,
+    base_addr_high: u8,
+    addr_low: u8,
+    carry: bool,
+) {
+    let value = I::instruction(executor.cpu) & base_addr_high.wrapping_add(1);
+
+    // the value is put on the bus while the high byte of the address is being fixed up,
+    // corrupting it
+    let addr_high = if carry { value } else { base_addr_high };
+    let addr = (addr_high as u16) << 8 | addr_low as u16;
+
+    executor.write_cycle(addr, value);
+}
@@ -1,5 +1,6 @@
+use super::read::first_read_addr;
 use crate::{
-    cpu::{Cpu, executor::Executor},
+    cpu::{BusObserver, Cpu, executor::Executor},
     memory::Memory,
 };
 
@@ -8,7 +9,7 @@ pub trait WriteInstruction {
 }
 
 pub trait WriteZeropage: WriteInstruction {
-    fn zeropage<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr = executor.fetch_from_pc_cycle() as u16;
         let value = Self::instruction(executor.cpu);
         executor.write_cycle(addr, value);
@@ -16,31 +17,31 @@ pub trait WriteZeropage: WriteInstruction {
 }
 
 pub trait WriteZeropageX: WriteInstruction {
-    fn zeropage_x<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         zeropage_indexed::<Self>(executor, |cpu| cpu.x);
     }
 }
 
 pub trait WriteZeropageY: WriteInstruction {
-    fn zeropage_y<M: Memory>(executor: &mut Executor<M>) {
+    fn zeropage_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         zeropage_indexed::<Self>(executor, |cpu| cpu.y);
     }
 }
 
 fn zeropage_indexed<I: WriteInstruction + ?Sized>(
-    executor: &mut Executor<impl Memory>,
+    executor: &mut Executor<impl Memory, impl BusObserver>,
     get_index: impl FnOnce(&Cpu) -> u8,
 ) {
     let base_addr = executor.fetch_from_pc_cycle();
     // dummy read from addr
-    let _ = executor.read_cycle(base_addr as u16);
+    executor.dummy_read_cycle(base_addr as u16);
     let addr = base_addr.wrapping_add(get_index(executor.cpu)) as u16;
     let value = I::instruction(executor.cpu);
     executor.write_cycle(addr, value);
 }
 
 pub trait WriteAbsolute: WriteInstruction {
-    fn absolute<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let addr_low = executor.fetch_from_pc_cycle();
         let addr_high = executor.fetch_from_pc_cycle();
 
@@ -51,19 +52,19 @@ pub trait WriteAbsolute: WriteInstruction {
 }
 
 pub trait WriteAbsoluteX: WriteInstruction {
-    fn absolute_x<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         absolute_indexed::<Self>(executor, |cpu| cpu.x);
     }
 }
 
 pub trait WriteAbsoluteY: WriteInstruction {
-    fn absolute_y<M: Memory>(executor: &mut Executor<M>) {
+    fn absolute_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         absolute_indexed::<Self>(executor, |cpu| cpu.y);
     }
 }
 
 fn absolute_indexed<I: WriteInstruction + ?Sized>(
-    executor: &mut Executor<impl Memory>,
+    executor: &mut Executor<impl Memory, impl BusObserver>,
     get_index: impl FnOnce(&Cpu) -> u8,
 ) {
     let base_addr_low = executor.fetch_from_pc_cycle();
@@ -73,7 +74,7 @@ fn absolute_indexed<I: WriteInstruction + ?Sized>(
 
     let addr = (base_addr_high as u16) << 8 | addr_low as u16;
     // dummy read from an address that might be wrong
-    let _ = executor.read_cycle(addr);
+    executor.dummy_read_cycle(first_read_addr(executor.cpu, addr, carry));
 
     let addr = addr.wrapping_add((carry as u16) << 8);
 
@@ -82,10 +83,10 @@ fn absolute_indexed<I: WriteInstruction + ?Sized>(
 }
 
 pub trait WriteIndirectX: WriteInstruction {
-    fn indirect_x<M: Memory>(executor: &mut Executor<M>) {
+    fn indirect_x<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let base_ptr = executor.fetch_from_pc_cycle();
         // dummy read
-        let _ = executor.read_cycle(base_ptr as u16);
+        executor.dummy_read_cycle(base_ptr as u16);
         let ptr = base_ptr.wrapping_add(executor.cpu.x);
 
         // note: intentionally first adding and then extending to 16 bit
@@ -100,7 +101,7 @@ pub trait WriteIndirectX: WriteInstruction {
 }
 
 pub trait WriteIndirectY: WriteInstruction {
-    fn indirect_y<M: Memory>(executor: &mut Executor<M>) {
+    fn indirect_y<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
         let ptr = executor.fetch_from_pc_cycle();
 
         // note: zero page crossing is intentionally not handled
@@ -110,7 +111,7 @@ pub trait WriteIndirectY: WriteInstruction {
         let (addr_low, carry) = base_addr_low.overflowing_add(executor.cpu.y);
         let addr = (base_addr_high as u16) << 8 | addr_low as u16;
         // might be a dummy read if carry is true and we need to fix up the high byte of the address
-        let _ = executor.read_cycle(addr);
+        executor.dummy_read_cycle(first_read_addr(executor.cpu, addr, carry));
 
         let addr = if carry {
             addr.wrapping_add(1 << 8)
@@ -122,3 +123,16 @@ pub trait WriteIndirectY: WriteInstruction {
         executor.write_cycle(addr, value);
     }
 }
+
+pub trait WriteZeropageIndirect: WriteInstruction {
+    fn zeropage_indirect<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let ptr = executor.fetch_from_pc_cycle();
+
+        let addr_low = executor.read_cycle(ptr as u16);
+        let addr_high = executor.read_cycle(ptr.wrapping_add(1) as u16);
+
+        let addr = (addr_high as u16) << 8 | addr_low as u16;
+        let value = Self::instruction(executor.cpu);
+        executor.write_cycle(addr, value);
+    }
+}
@@ -27,3 +27,94 @@ pub fn sub_with_carry(a: u8, b: u8, carry: bool) -> (u8, bool) {
 pub fn sub_overflows(a: i8, b: i8, carry: bool) -> bool {
     add_overflows(a, !b, carry)
 }
+
+/// Add two BCD numbers and a carry bit the way an NMOS 6502 does
+///
+/// Invalid BCD digits produce the same garbage as on the original chip, see
+/// http://www.6502.org/tutorials/decimal_mode.html#A
+pub fn decimal_add_with_carry(a: u8, b: u8, carry: bool) -> (u8, bool) {
+    let mut sum = decimal_add_unadjusted(a, b, carry);
+    if sum >= 0xA0 {
+        sum = sum.wrapping_add(0x60);
+    }
+
+    (sum as u8, sum >= 0x100)
+}
+
+/// NEGATIVE and OVERFLOW flags of an NMOS 6502 decimal addition
+///
+/// They are based on the sum before the high digit is adjusted,
+/// see http://www.6502.org/tutorials/decimal_mode.html#B
+pub fn decimal_add_negative_overflow(a: u8, b: u8, carry: bool) -> (bool, bool) {
+    let negative = decimal_add_unadjusted(a, b, carry) & 0x80 != 0;
+
+    let signed_sum = ((a & 0xF0) as i8 as i16)
+        .wrapping_add((b & 0xF0) as i8 as i16)
+        .wrapping_add(decimal_add_low_digit(a, b, carry) as i16);
+    let overflow = !(i8::MIN as i16..=i8::MAX as i16).contains(&signed_sum);
+
+    (negative, overflow)
+}
+
+/// Sum with the low digit adjusted, but not the high one
+fn decimal_add_unadjusted(a: u8, b: u8, carry: bool) -> u16 {
+    ((a & 0xF0) as u16)
+        .wrapping_add((b & 0xF0) as u16)
+        .wrapping_add(decimal_add_low_digit(a, b, carry))
+}
+
+/// Sum of the low digits, adjusted to carry into the high digit
+fn decimal_add_low_digit(a: u8, b: u8, carry: bool) -> u16 {
+    let low = ((a & 0x0F) as u16)
+        .wrapping_add((b & 0x0F) as u16)
+        .wrapping_add(carry as u16);
+
+    if low >= 0x0A {
+        (low.wrapping_add(0x06) & 0x0F).wrapping_add(0x10)
+    } else {
+        low
+    }
+}
+
+/// Subtract two BCD numbers and a carry bit the way an NMOS 6502 does
+///
+/// Only the result differs from a binary subtraction, all the flags are the same
+pub fn decimal_sub_with_carry(a: u8, b: u8, carry: bool) -> u8 {
+    let mut low = ((a & 0x0F) as i16)
+        .wrapping_sub((b & 0x0F) as i16)
+        .wrapping_add(carry as i16)
+        .wrapping_sub(1);
+    if low < 0 {
+        low = (low.wrapping_sub(0x06) & 0x0F).wrapping_sub(0x10);
+    }
+
+    let mut difference = ((a & 0xF0) as i16)
+        .wrapping_sub((b & 0xF0) as i16)
+        .wrapping_add(low);
+    if difference < 0 {
+        difference = difference.wrapping_sub(0x60);
+    }
+
+    difference as u8
+}
+
+/// Subtract two BCD numbers and a carry bit the way a 65C02 does
+///
+/// The result only differs from the NMOS one for invalid BCD digits, see
+/// http://www.6502.org/tutorials/decimal_mode.html#A
+pub fn cmos_decimal_sub_with_carry(a: u8, b: u8, carry: bool) -> u8 {
+    let borrow = !carry as i16;
+    let low = ((a & 0x0F) as i16)
+        .wrapping_sub((b & 0x0F) as i16)
+        .wrapping_sub(borrow);
+
+    let mut difference = (a as i16).wrapping_sub(b as i16).wrapping_sub(borrow);
+    if difference < 0 {
+        difference = difference.wrapping_sub(0x60);
+    }
+    if low < 0 {
+        difference = difference.wrapping_sub(0x06);
+    }
+
+    difference as u8
+}
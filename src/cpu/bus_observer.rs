@@ -0,0 +1,50 @@
+use crate::memory::AccessKind;
+
+/// Whether the CPU reads from or writes to the bus
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BusDirection {
+    Read,
+    Write,
+}
+
+/// A single bus access made by the CPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusCycle {
+    /// Value of `clock_cycle_count` when the cycle started
+    pub cycle: u64,
+
+    pub address: u16,
+
+    /// The value that was read or written
+    pub value: u8,
+
+    pub direction: BusDirection,
+
+    pub kind: AccessKind,
+}
+
+/// Receives every bus access the CPU makes, in order
+///
+/// `()` is the observer that ignores everything,
+/// executing without an observer compiles to the same code as having none at all
+pub trait BusObserver {
+    fn observe(&mut self, cycle: BusCycle);
+}
+
+impl BusObserver for () {
+    #[inline(always)]
+    fn observe(&mut self, _cycle: BusCycle) {}
+}
+
+/// Collects a trace of the bus accesses
+impl BusObserver for Vec<BusCycle> {
+    fn observe(&mut self, cycle: BusCycle) {
+        self.push(cycle);
+    }
+}
+
+impl<O: BusObserver + ?Sized> BusObserver for &mut O {
+    fn observe(&mut self, cycle: BusCycle) {
+        (**self).observe(cycle);
+    }
+}
@@ -0,0 +1,382 @@
+use num_enum::{FromPrimitive, IntoPrimitive};
+
+/// Opcodes of the 65C02
+///
+/// All 256 opcodes are defined, the ones that aren't instructions are NOPs
+#[derive(Debug, Clone, Copy, IntoPrimitive, FromPrimitive)]
+#[repr(u8)]
+pub enum CmosOpcode {
+    // ADC
+    AdcImmediate = 0x69,
+    AdcZeropage = 0x65,
+    AdcZeropageX = 0x75,
+    AdcAbsolute = 0x6D,
+    AdcAbsoluteX = 0x7D,
+    AdcAbsoluteY = 0x79,
+    AdcIndirectX = 0x61,
+    AdcIndirectY = 0x71,
+
+    // AND
+    AndImmediate = 0x29,
+    AndZeropage = 0x25,
+    AndZeropageX = 0x35,
+    AndAbsolute = 0x2D,
+    AndAbsoluteX = 0x3D,
+    AndAbsoluteY = 0x39,
+    AndIndirectX = 0x21,
+    AndIndirectY = 0x31,
+
+    // ASL
+    AslAccumulator = 0x0A,
+    AslZeropage = 0x06,
+    AslZeropageX = 0x16,
+    AslAbsolute = 0x0E,
+    AslAbsoluteX = 0x1E,
+
+    // B**
+    Bcc = 0x90,
+    Bcs = 0xB0,
+    Beq = 0xF0,
+    Bmi = 0x30,
+    Bne = 0xD0,
+    Bpl = 0x10,
+    Brk = 0x00,
+    Bvc = 0x50,
+    Bvs = 0x70,
+
+    // BIT
+    BitZeropage = 0x24,
+    BitAbsolute = 0x2C,
+
+    // CL*
+    Clc = 0x18,
+    Cld = 0xD8,
+    Cli = 0x58,
+    Clv = 0xB8,
+
+    // CMP
+    CmpImmediate = 0xC9,
+    CmpZeropage = 0xC5,
+    CmpZeropageX = 0xD5,
+    CmpAbsolute = 0xCD,
+    CmpAbsoluteX = 0xDD,
+    CmpAbsoluteY = 0xD9,
+    CmpIndirectX = 0xC1,
+    CmpIndirectY = 0xD1,
+
+    // CPX
+    CpxImmediate = 0xE0,
+    CpxZeropage = 0xE4,
+    CpxAbsolute = 0xEC,
+
+    // CPY
+    CpyImmediate = 0xC0,
+    CpyZeropage = 0xC4,
+    CpyAbsolute = 0xCC,
+
+    // DEC
+    DecZeropage = 0xC6,
+    DecZeropageX = 0xD6,
+    DecAbsolute = 0xCE,
+    DecAbsoluteX = 0xDE,
+
+    // DEX/Y
+    Dex = 0xCA,
+    Dey = 0x88,
+
+    // EOR
+    EorImmediate = 0x49,
+    EorZeropage = 0x45,
+    EorZeropageX = 0x55,
+    EorAbsolute = 0x4D,
+    EorAbsoluteX = 0x5D,
+    EorAbsoluteY = 0x59,
+    EorIndirectX = 0x41,
+    EorIndirectY = 0x51,
+
+    // INC
+    IncZeropage = 0xE6,
+    IncZeropageX = 0xF6,
+    IncAbsolute = 0xEE,
+    IncAbsoluteX = 0xFE,
+
+    // IN*
+    Inx = 0xE8,
+    Iny = 0xC8,
+
+    // JMP
+    JmpAbsolute = 0x4C,
+    JmpIndirect = 0x6C,
+
+    Jsr = 0x20,
+
+    // LDA
+    LdaImmediate = 0xA9,
+    LdaZeropage = 0xA5,
+    LdaZeropageX = 0xB5,
+    LdaAbsolute = 0xAD,
+    LdaAbsoluteX = 0xBD,
+    LdaAbsoluteY = 0xB9,
+    LdaIndirectX = 0xA1,
+    LdaIndirectY = 0xB1,
+
+    // LDX
+    LdxImmediate = 0xA2,
+    LdxZeropage = 0xA6,
+    LdxZeropageY = 0xB6,
+    LdxAbsolute = 0xAE,
+    LdxAbsoluteY = 0xBE,
+
+    // LDY
+    LdyImmediate = 0xA0,
+    LdyZeropage = 0xA4,
+    LdyZeropageX = 0xB4,
+    LdyAbsolute = 0xAC,
+    LdyAbsoluteX = 0xBC,
+
+    // LSR
+    LsrAccumulator = 0x4A,
+    LsrZeropage = 0x46,
+    LsrZeropageX = 0x56,
+    LsrAbsolute = 0x4E,
+    LsrAbsoluteX = 0x5E,
+
+    Nop = 0xEA,
+
+    // ORA
+    OraImmediate = 0x09,
+    OraZeropage = 0x05,
+    OraZeropageX = 0x15,
+    OraAbsolute = 0x0D,
+    OraAbsoluteX = 0x1D,
+    OraAbsoluteY = 0x19,
+    OraIndirectX = 0x01,
+    OraIndirectY = 0x11,
+
+    // P**
+    Pha = 0x48,
+    Php = 0x08,
+    Pla = 0x68,
+    Plp = 0x28,
+
+    // ROL
+    RolAccumulator = 0x2A,
+    RolZeropage = 0x26,
+    RolZeropageX = 0x36,
+    RolAbsolute = 0x2E,
+    RolAbsoluteX = 0x3E,
+
+    // ROR
+    RorAccumulator = 0x6A,
+    RorZeropage = 0x66,
+    RorZeropageX = 0x76,
+    RorAbsolute = 0x6E,
+    RorAbsoluteX = 0x7E,
+
+    // RT*
+    Rti = 0x40,
+    Rts = 0x60,
+
+    // SBC
+    SbcImmediate = 0xE9,
+    SbcZeropage = 0xE5,
+    SbcZeropageX = 0xF5,
+    SbcAbsolute = 0xED,
+    SbcAbsoluteX = 0xFD,
+    SbcAbsoluteY = 0xF9,
+    SbcIndirectX = 0xE1,
+    SbcIndirectY = 0xF1,
+
+    // SE*
+    Sec = 0x38,
+    Sed = 0xF8,
+    Sei = 0x78,
+
+    // STA
+    StaZeropage = 0x85,
+    StaZeropageX = 0x95,
+    StaAbsolute = 0x8D,
+    StaAbsoluteX = 0x9D,
+    StaAbsoluteY = 0x99,
+    StaIndirectX = 0x81,
+    StaIndirectY = 0x91,
+
+    // STX
+    StxZeropage = 0x86,
+    StxZeropageY = 0x96,
+    StxAbsolute = 0x8E,
+
+    // STY
+    StyZeropage = 0x84,
+    StyZeropageX = 0x94,
+    StyAbsolute = 0x8C,
+
+    // T**
+    Tax = 0xAA,
+    Tay = 0xA8,
+    Tsx = 0xBA,
+    Txa = 0x8A,
+    Txs = 0x9A,
+    Tya = 0x98,
+
+    // 65C02 opcodes
+
+    // ADC
+    AdcZeropageIndirect = 0x72,
+
+    // AND
+    AndZeropageIndirect = 0x32,
+
+    // BBR
+    Bbr0 = 0x0F,
+    Bbr1 = 0x1F,
+    Bbr2 = 0x2F,
+    Bbr3 = 0x3F,
+    Bbr4 = 0x4F,
+    Bbr5 = 0x5F,
+    Bbr6 = 0x6F,
+    Bbr7 = 0x7F,
+
+    // BBS
+    Bbs0 = 0x8F,
+    Bbs1 = 0x9F,
+    Bbs2 = 0xAF,
+    Bbs3 = 0xBF,
+    Bbs4 = 0xCF,
+    Bbs5 = 0xDF,
+    Bbs6 = 0xEF,
+    Bbs7 = 0xFF,
+
+    // BIT
+    BitImmediate = 0x89,
+    BitZeropageX = 0x34,
+    BitAbsoluteX = 0x3C,
+
+    // BRA
+    Bra = 0x80,
+
+    // CMP
+    CmpZeropageIndirect = 0xD2,
+
+    // DEC
+    DecAccumulator = 0x3A,
+
+    // EOR
+    EorZeropageIndirect = 0x52,
+
+    // INC
+    IncAccumulator = 0x1A,
+
+    // JMP
+    JmpAbsoluteXIndirect = 0x7C,
+
+    // LDA
+    LdaZeropageIndirect = 0xB2,
+
+    // ORA
+    OraZeropageIndirect = 0x12,
+
+    // P**
+    Phx = 0xDA,
+    Phy = 0x5A,
+    Plx = 0xFA,
+    Ply = 0x7A,
+
+    // RMB
+    Rmb0 = 0x07,
+    Rmb1 = 0x17,
+    Rmb2 = 0x27,
+    Rmb3 = 0x37,
+    Rmb4 = 0x47,
+    Rmb5 = 0x57,
+    Rmb6 = 0x67,
+    Rmb7 = 0x77,
+
+    // SBC
+    SbcZeropageIndirect = 0xF2,
+
+    // SMB
+    Smb0 = 0x87,
+    Smb1 = 0x97,
+    Smb2 = 0xA7,
+    Smb3 = 0xB7,
+    Smb4 = 0xC7,
+    Smb5 = 0xD7,
+    Smb6 = 0xE7,
+    Smb7 = 0xF7,
+
+    // STA
+    StaZeropageIndirect = 0x92,
+
+    // STP
+    Stp = 0xDB,
+
+    // STZ
+    StzZeropage = 0x64,
+    StzZeropageX = 0x74,
+    StzAbsolute = 0x9C,
+    StzAbsoluteX = 0x9E,
+
+    // TRB
+    TrbZeropage = 0x14,
+    TrbAbsolute = 0x1C,
+
+    // TSB
+    TsbZeropage = 0x04,
+    TsbAbsolute = 0x0C,
+
+    // WAI
+    Wai = 0xCB,
+
+    // NOP
+    // 1 byte, 1 cycle
+    Nop03 = 0x03,
+    Nop0B = 0x0B,
+    Nop13 = 0x13,
+    Nop1B = 0x1B,
+    Nop23 = 0x23,
+    Nop2B = 0x2B,
+    Nop33 = 0x33,
+    Nop3B = 0x3B,
+    Nop43 = 0x43,
+    Nop4B = 0x4B,
+    Nop53 = 0x53,
+    Nop5B = 0x5B,
+    Nop63 = 0x63,
+    Nop6B = 0x6B,
+    Nop73 = 0x73,
+    Nop7B = 0x7B,
+    Nop83 = 0x83,
+    Nop8B = 0x8B,
+    Nop93 = 0x93,
+    Nop9B = 0x9B,
+    NopA3 = 0xA3,
+    NopAb = 0xAB,
+    NopB3 = 0xB3,
+    NopBb = 0xBB,
+    NopC3 = 0xC3,
+    NopD3 = 0xD3,
+    NopE3 = 0xE3,
+    NopEb = 0xEB,
+    NopF3 = 0xF3,
+    NopFb = 0xFB,
+    // immediate
+    Nop02Immediate = 0x02,
+    Nop22Immediate = 0x22,
+    Nop42Immediate = 0x42,
+    Nop62Immediate = 0x62,
+    Nop82Immediate = 0x82,
+    NopC2Immediate = 0xC2,
+    NopE2Immediate = 0xE2,
+    // zero page
+    Nop44Zeropage = 0x44,
+    // zero page,X
+    Nop54ZeropageX = 0x54,
+    NopD4ZeropageX = 0xD4,
+    NopF4ZeropageX = 0xF4,
+    // absolute
+    NopDcAbsolute = 0xDC,
+    NopFcAbsolute = 0xFC,
+    // absolute, 8 cycles
+    Nop5C = 0x5C,
+}
@@ -0,0 +1,238 @@
+use std::cmp::Ordering;
+
+use super::{BusObserver, Cpu, IllegalOpcode, executor::Executor};
+use crate::memory::Memory;
+
+/// The most cycles a single instruction or interrupt sequence can take
+const MAX_CYCLES: usize = 8;
+
+/// Record of the cycles of an instruction that's been partially executed by `Cpu::tick`
+///
+/// The instruction is executed from its start every tick,
+/// the cycles that have already been performed are replayed from the log without touching memory,
+/// then the next cycle is performed for real and the rest of the instruction is skipped.
+/// Because execution only depends on the values read and the levels of the interrupt lines,
+/// replaying them always leads to the same result
+///
+/// # Why replay instead of a state machine
+///
+/// The addressing modes and instructions are written as straight line code
+/// shared by `execute_next_instruction` and `tick`,
+/// making every one of them resumable would mean splitting each of them at every bus access.
+/// Replaying keeps a single implementation, and so a single source of truth for cycle counts.
+///
+/// The cost is bounded, an instruction takes at most `MAX_CYCLES` cycles,
+/// so a tick replays at most 7 accesses, none of which reach memory.
+/// Every bus access and interrupt poll still happens on its exact cycle,
+/// which is all the PPU, APU, mappers and DMA can see of the CPU.
+/// What replay doesn't provide is registers partway through an instruction,
+/// they are only written back once its last cycle has been performed
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CycleLog {
+    cycles: [Cycle; MAX_CYCLES],
+
+    /// How many cycles of the current instruction have been performed
+    len: u8,
+
+    /// Index of the next bus access while executing
+    position: u8,
+
+    /// Set while the instruction is being replayed by `Cpu::tick`
+    stepping: bool,
+
+    /// Set when the log is full, the cycles past it are then performed instead of skipped
+    finishing: bool,
+
+    /// NMI_pending as it was when the instruction started
+    start_nmi_pending: bool,
+
+    /// Set when an NMI edge was detected since the last tick
+    nmi_edge: bool,
+}
+
+/// The levels of the interrupt lines when a cycle started, along with the value on the data bus
+#[derive(Debug, Clone, Copy, Default)]
+struct Cycle {
+    value: u8,
+    nmi_line: bool,
+    nmi_edge: bool,
+    irq_line: bool,
+    memory_irq: bool,
+}
+
+/// What a bus access should do
+pub enum Access {
+    /// Access memory
+    Live,
+
+    /// The cycle has already been performed, the value read back then should be used
+    Replayed(u8),
+
+    /// The cycle is past the one being performed, memory shouldn't be touched
+    Skipped,
+}
+
+impl CycleLog {
+    /// Whether an instruction has been started by `Cpu::tick` but not finished yet
+    pub fn in_progress(&self) -> bool {
+        self.len != 0
+    }
+
+    /// Whether the instruction is being executed by `Cpu::tick`,
+    /// the interrupt lines then come from the log
+    pub fn is_replaying(&self) -> bool {
+        self.stepping
+    }
+
+    /// Record an edge on the NMI line
+    pub fn nmi_edge(&mut self) {
+        self.nmi_edge = true;
+    }
+
+    /// Determine what the next bus access should do
+    pub fn next_access(&mut self) -> Access {
+        if !self.stepping {
+            return Access::Live;
+        }
+
+        let position = self.position;
+        self.position = self.position.saturating_add(1);
+
+        match position.cmp(&self.len) {
+            Ordering::Less => Access::Replayed(self.cycles[position as usize].value),
+            Ordering::Equal => Access::Live,
+            Ordering::Greater if self.finishing => Access::Live,
+            Ordering::Greater => Access::Skipped,
+        }
+    }
+}
+
+impl Cpu {
+    /// Perform exactly one cycle of the current instruction, starting the next instruction if needed
+    ///
+    /// Registers and flags are only updated once the last cycle of an instruction has been performed,
+    /// `clock_cycle_count` is incremented every tick.
+    /// Interrupt lines can be changed between ticks, they are polled before every cycle
+    /// just like they are with `execute_next_instruction`
+    ///
+    /// # Errors
+    /// Returns an error if the opcode isn't implemented
+    /// and `illegal_opcode_policy` is `IllegalOpcodePolicy::Error`
+    pub fn tick<M: Memory>(&mut self, memory: &mut M) -> Result<(), IllegalOpcode> {
+        self.tick_observed(memory, ())
+    }
+
+    /// Perform one cycle like `tick`, notifying the observer of the bus access
+    ///
+    /// # Errors
+    /// Returns an error if the opcode isn't implemented
+    /// and `illegal_opcode_policy` is `IllegalOpcodePolicy::Error`
+    pub fn tick_observed<M: Memory, O: BusObserver>(
+        &mut self,
+        memory: &mut M,
+        observer: O,
+    ) -> Result<(), IllegalOpcode> {
+        let cycle = self.cycle_log.len;
+        if cycle == 0 {
+            self.cycle_log.start_nmi_pending = self.nmi_pending;
+            self.cycle_log.nmi_edge = false;
+        }
+
+        self.cycle_log.cycles[cycle as usize] = Cycle {
+            value: 0,
+            nmi_line: self.nmi_line,
+            nmi_edge: self.cycle_log.nmi_edge,
+            irq_line: self.irq_line,
+            memory_irq: memory.irq(),
+        };
+        self.cycle_log.nmi_edge = false;
+
+        let mut start = *self;
+        start.clock_cycle_count = self.clock_cycle_count.wrapping_sub(cycle as u64);
+        start.nmi_pending = self.cycle_log.start_nmi_pending;
+        start.cycle_log.position = 0;
+        start.cycle_log.stepping = true;
+        start.apply_interrupt_lines(0);
+
+        let mut replay = start;
+        let mut executor = Executor {
+            cpu: &mut replay,
+            memory,
+            observer,
+        };
+        let mut result = executor.execute_next_instruction();
+
+        let performed = cycle.wrapping_add(1);
+        if executor.cpu.cycle_log.position > performed {
+            debug_assert!(
+                (performed as usize) < MAX_CYCLES,
+                "an instruction can't take more than {MAX_CYCLES} cycles"
+            );
+
+            if (performed as usize) < MAX_CYCLES {
+                self.cycle_log.cycles = executor.cpu.cycle_log.cycles;
+                self.cycle_log.len = performed;
+                self.clock_cycle_count = self
+                    .clock_cycle_count
+                    .checked_add(1)
+                    .expect("clock_cycle can't overflow");
+
+                return result;
+            }
+
+            // the log is full, the rest of the instruction is performed right away
+            let cycles = executor.cpu.cycle_log.cycles;
+            *executor.cpu = start;
+            executor.cpu.cycle_log.cycles = cycles;
+            executor.cpu.cycle_log.len = performed;
+            executor.cpu.cycle_log.finishing = true;
+            result = executor.execute_next_instruction();
+        }
+
+        replay.cycle_log = CycleLog::default();
+        *self = replay;
+
+        result
+    }
+
+    /// Whether an instruction has been started by `tick` but not finished yet
+    pub fn is_mid_instruction(&self) -> bool {
+        self.cycle_log.in_progress()
+    }
+
+    /// Update the log after a bus access, `value` being the value that was on the data bus
+    pub(super) fn end_access(&mut self, value: u8) {
+        if !self.cycle_log.stepping {
+            return;
+        }
+
+        let position = self.cycle_log.position;
+        let len = self.cycle_log.len;
+
+        if position == len.wrapping_add(1)
+            && let Some(cycle) = self.cycle_log.cycles.get_mut(len as usize)
+        {
+            cycle.value = value;
+        }
+
+        if position <= len {
+            self.apply_interrupt_lines(position);
+        }
+    }
+
+    /// Restore the interrupt lines to what they were when the cycle started
+    ///
+    /// Cycles past the end of a full log keep the lines of its last cycle
+    fn apply_interrupt_lines(&mut self, cycle: u8) {
+        let Some(&cycle) = self.cycle_log.cycles.get(cycle as usize) else {
+            return;
+        };
+
+        self.nmi_line = cycle.nmi_line;
+        self.irq_line = cycle.irq_line;
+        self.memory_irq = cycle.memory_irq;
+        if cycle.nmi_edge {
+            self.nmi_pending = true;
+        }
+    }
+}
@@ -1,22 +1,65 @@
-use num_enum::FromPrimitive;
+use num_enum::{FromPrimitive, TryFromPrimitive};
 
-use super::{Cpu, instructions, opcode::Opcode};
-use crate::memory::Memory;
+use super::{
+    BusCycle, BusDirection, BusObserver, Cpu, IllegalOpcode, cmos_opcode::CmosOpcode,
+    cycle_log::Access, instructions, opcode::Opcode,
+};
+use crate::memory::{AccessKind, Memory};
 
 /// CPU bundled together with memory
 ///
 /// Used as a convenience wrapper for executing instructions
-pub struct Executor<'a, M> {
+pub struct Executor<'a, M, O = ()> {
     pub cpu: &'a mut Cpu,
     pub memory: &'a mut M,
+
+    /// Notified of every bus access
+    pub observer: O,
 }
 
-impl<'a, M: Memory> Executor<'a, M> {
-    pub fn execute_next_instruction(&mut self) {
-        let opcode = self.fetch_from_pc_cycle();
-        let opcode = Opcode::from_primitive(opcode);
+impl<'a, M: Memory, O: BusObserver> Executor<'a, M, O> {
+    pub fn execute_next_instruction(&mut self) -> Result<(), IllegalOpcode> {
+        if self.cpu.jammed {
+            // the CPU is stuck, but the bus keeps getting clocked
+            self.dummy_read_cycle(0xFFFF);
+            return Ok(());
+        }
+
+        if self.cpu.waiting {
+            self.sample_memory_irq();
+            if !(self.cpu.nmi_pending || self.cpu.irq_asserted()) {
+                self.dummy_read_cycle(self.cpu.pc);
+                return Ok(());
+            }
+
+            // a masked IRQ still ends the wait, but execution continues after WAI
+            self.cpu.waiting = false;
+            self.cpu.poll_interrupts();
+        }
+
+        if self.cpu.interrupt_poll {
+            self.hardware_interrupt();
+            return Ok(());
+        }
 
-        instructions::execute_opcode(self, opcode);
+        let pc = self.cpu.pc;
+        let opcode_byte = self.fetch_from_pc_cycle();
+
+        if self.cpu.variant.is_cmos() {
+            instructions::execute_cmos_opcode(self, CmosOpcode::from_primitive(opcode_byte));
+            return Ok(());
+        }
+
+        match Opcode::try_from_primitive(opcode_byte) {
+            Ok(opcode) => {
+                instructions::execute_opcode(self, opcode);
+                Ok(())
+            }
+            Err(_) => self.illegal_opcode(IllegalOpcode {
+                opcode: opcode_byte,
+                pc,
+            }),
+        }
     }
 
     /// Fetch a value from the address PC points to and increment it
@@ -31,33 +74,88 @@ impl<'a, M: Memory> Executor<'a, M> {
 
     /// Perform a read cycle
     ///
-    /// Reads from the address and increments the `clock_cycle_count`
+    /// Polls for interrupts, reads from the address and increments the `clock_cycle_count`
     ///
     /// Every clock cycle a 6502 CPU either reads or writes to memory,
     /// as such this function or `write_cycle` should be called every time the clock cycle would be incremented
     /// in order to create the expected side effects of interacting with memory mapped hardware
     pub fn read_cycle(&mut self, addr: u16) -> u8 {
-        let result = self.memory.load(addr);
-
-        self.cpu.clock_cycle_count = self
-            .cpu
-            .clock_cycle_count
-            .checked_add(1)
-            .expect("clock_cycle can't overflow");
+        self.bus_read(addr, AccessKind::Real)
+    }
 
-        result
+    /// Perform a read cycle whose value is discarded
+    pub fn dummy_read_cycle(&mut self, addr: u16) {
+        let _ = self.bus_read(addr, AccessKind::Dummy);
     }
 
     /// Perform a write cycle
     ///
-    /// Writes a value to the address and increments the `clock_cycle_count`
+    /// Polls for interrupts, writes a value to the address and increments the `clock_cycle_count`
     ///
     /// Every clock cycle a 6502 CPU either reads or writes to memory,
     /// as such this function or `read_cycle` should be called every time the clock cycle would be incremented
     /// in order to create the expected side effects of interacting with memory mapped hardware
     pub fn write_cycle(&mut self, addr: u16, value: u8) {
-        self.memory.store(addr, value);
+        self.bus_write(addr, value, AccessKind::Real);
+    }
 
+    /// Perform a write cycle of a value that's about to be overwritten
+    pub fn dummy_write_cycle(&mut self, addr: u16, value: u8) {
+        self.bus_write(addr, value, AccessKind::Dummy);
+    }
+
+    fn bus_read(&mut self, addr: u16, kind: AccessKind) -> u8 {
+        self.sample_memory_irq();
+        self.cpu.poll_interrupts();
+        let result = match self.cpu.cycle_log.next_access() {
+            Access::Live => {
+                let value = self.memory.load_access(addr, kind, self.cpu.data_bus);
+                self.observe(addr, value, BusDirection::Read, kind);
+                value
+            }
+            Access::Replayed(value) => value,
+            Access::Skipped => 0,
+        };
+        self.cpu.end_access(result);
+        self.cpu.data_bus = result;
+
+        self.increment_clock_cycle_count();
+
+        result
+    }
+
+    fn bus_write(&mut self, addr: u16, value: u8, kind: AccessKind) {
+        self.sample_memory_irq();
+        self.cpu.poll_interrupts();
+        if let Access::Live = self.cpu.cycle_log.next_access() {
+            self.memory.store_access(addr, value, kind);
+            self.observe(addr, value, BusDirection::Write, kind);
+        }
+        self.cpu.end_access(value);
+        self.cpu.data_bus = value;
+
+        self.increment_clock_cycle_count();
+    }
+
+    /// Update the level of the IRQ line driven by memory,
+    /// replayed cycles keep the level that was logged
+    fn sample_memory_irq(&mut self) {
+        if !self.cpu.cycle_log.is_replaying() {
+            self.cpu.memory_irq = self.memory.irq();
+        }
+    }
+
+    fn observe(&mut self, address: u16, value: u8, direction: BusDirection, kind: AccessKind) {
+        self.observer.observe(BusCycle {
+            cycle: self.cpu.clock_cycle_count,
+            address,
+            value,
+            direction,
+            kind,
+        });
+    }
+
+    fn increment_clock_cycle_count(&mut self) {
         self.cpu.clock_cycle_count = self
             .cpu
             .clock_cycle_count
@@ -66,12 +164,17 @@ impl<'a, M: Memory> Executor<'a, M> {
     }
 
     pub fn stack_write(&mut self, value: u8) {
-        let addr = 0x0100 << 8 | self.cpu.sp as u16;
+        let addr = 0x0100 | self.cpu.sp as u16;
         self.write_cycle(addr, value);
     }
 
     pub fn stack_read(&mut self) -> u8 {
-        let addr = 0x0100 << 8 | self.cpu.sp as u16;
+        let addr = 0x0100 | self.cpu.sp as u16;
         self.read_cycle(addr)
     }
+
+    pub fn dummy_stack_read(&mut self) {
+        let addr = 0x0100 | self.cpu.sp as u16;
+        self.dummy_read_cycle(addr);
+    }
 }
@@ -0,0 +1,75 @@
+use std::{
+    error::Error,
+    fmt::{Display, Formatter},
+};
+
+use super::{BusObserver, executor::Executor, opcode};
+use crate::memory::Memory;
+
+/// What the CPU does when it encounters an opcode that isn't implemented
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum IllegalOpcodePolicy {
+    /// Return an error from `execute_next_instruction`
+    ///
+    /// PC is left pointing at the illegal opcode
+    #[default]
+    Error,
+
+    /// Halt the CPU, like the KIL/JAM opcodes do
+    ///
+    /// The CPU stays halted until it's reset
+    Halt,
+
+    /// Skip the opcode and its operands, as if it was a NOP of the same length
+    Nop,
+}
+
+/// Error returned when the CPU encounters an opcode that isn't implemented
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IllegalOpcode {
+    /// The opcode byte
+    pub opcode: u8,
+
+    /// The address the opcode was fetched from
+    pub pc: u16,
+}
+
+impl Display for IllegalOpcode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "illegal opcode ${:02X} at ${:04X}", self.opcode, self.pc)
+    }
+}
+
+impl Error for IllegalOpcode {}
+
+impl<M: Memory, O: BusObserver> Executor<'_, M, O> {
+    /// Handle an illegal opcode according to the CPU's `IllegalOpcodePolicy`
+    ///
+    /// Expects the opcode to have already been fetched
+    pub fn illegal_opcode(&mut self, illegal_opcode: IllegalOpcode) -> Result<(), IllegalOpcode> {
+        match self.cpu.illegal_opcode_policy {
+            IllegalOpcodePolicy::Error => {
+                self.cpu.pc = illegal_opcode.pc;
+                Err(illegal_opcode)
+            }
+            IllegalOpcodePolicy::Halt => {
+                self.cpu.jammed = true;
+                Ok(())
+            }
+            IllegalOpcodePolicy::Nop => {
+                match opcode::instruction_length(illegal_opcode.opcode) {
+                    1 => {
+                        // dummy read at PC
+                        self.dummy_read_cycle(self.cpu.pc);
+                    }
+                    length => {
+                        for _ in 1..length {
+                            let _ = self.fetch_from_pc_cycle();
+                        }
+                    }
+                }
+                Ok(())
+            }
+        }
+    }
+}
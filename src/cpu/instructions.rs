@@ -1,14 +1,36 @@
 use super::{
-    addressing_modes::{implied::*, read::*, rmw::*, write::*},
-    executor::Executor,
+    addressing_modes::{implied::*, jump::*, read::*, relative::*, rmw::*, write::*},
+    cmos_opcode::CmosOpcode,
+    BusObserver, executor::Executor,
     opcode::Opcode,
 };
-use crate::{cpu::addressing_modes::stack::StackPush, memory::Memory};
+use crate::{
+    cpu::addressing_modes::stack::{StackPull, StackPush},
+    memory::Memory,
+};
+#[cfg(feature = "unstable-opcodes")]
+use super::addressing_modes::unstable_store::*;
 
 mod adc;
+mod alr;
+mod anc;
 mod and;
+mod arr;
 mod asl;
+mod axs;
+mod bbr;
+mod bbs;
+mod bcc;
+mod bcs;
+mod beq;
 mod bit;
+mod bmi;
+mod bne;
+mod bpl;
+mod bra;
+mod brk;
+mod bvc;
+mod bvs;
 mod clc;
 mod cld;
 mod cli;
@@ -16,6 +38,7 @@ mod clv;
 mod cmp;
 mod cpx;
 mod cpy;
+mod dcp;
 mod dec;
 mod dex;
 mod dey;
@@ -23,42 +46,98 @@ mod eor;
 mod inc;
 mod inx;
 mod iny;
+mod isc;
+mod jmp;
+mod jsr;
+mod kil;
+#[cfg(feature = "unstable-opcodes")]
+mod las;
+mod lax;
 mod lda;
 mod ldx;
 mod ldy;
 mod lsr;
+#[cfg(feature = "unstable-opcodes")]
+mod lxa;
 mod nop;
 mod ora;
 mod pha;
 mod php;
+mod phx;
+mod phy;
+mod pla;
+mod plp;
+mod plx;
+mod ply;
+mod rla;
+mod rmb;
 mod rol;
 mod ror;
+mod rra;
+mod rti;
+mod rts;
+mod sax;
 mod sbc;
 mod sec;
 mod sed;
 mod sei;
+#[cfg(feature = "unstable-opcodes")]
+mod sha;
+#[cfg(feature = "unstable-opcodes")]
+mod shx;
+#[cfg(feature = "unstable-opcodes")]
+mod shy;
+mod slo;
+mod smb;
+mod sre;
 mod sta;
+mod stp;
 mod stx;
 mod sty;
+mod stz;
+#[cfg(feature = "unstable-opcodes")]
+mod tas;
 mod tax;
 mod tay;
+mod trb;
+mod tsb;
 mod tsx;
 mod txa;
 mod txs;
 mod tya;
+mod usbc;
+mod wai;
+#[cfg(feature = "unstable-opcodes")]
+mod xaa;
 
 pub use adc::*;
+pub use alr::*;
+pub use anc::*;
 pub use and::*;
+pub use arr::*;
 pub use asl::*;
+pub use axs::*;
+pub use bbr::*;
+pub use bbs::*;
+pub use bcc::*;
+pub use bcs::*;
+pub use beq::*;
 pub use bit::*;
+pub use bmi::*;
+pub use bne::*;
+pub use bpl::*;
+pub use bra::*;
+pub use brk::*;
+pub use bvc::*;
+pub use bvs::*;
 pub use clc::*;
 pub use cld::*;
 pub use cli::*;
 pub use clv::*;
-pub use php::*;
 pub use cmp::*;
 pub use cpx::*;
 pub use cpy::*;
+pub use dcp::*;
 pub use dec::*;
 pub use dex::*;
 pub use dey::*;
@@ -66,30 +145,71 @@ pub use eor::*;
 pub use inc::*;
 pub use inx::*;
 pub use iny::*;
+pub use isc::*;
+pub use jmp::*;
+pub use jsr::*;
+pub use kil::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use las::*;
+pub use lax::*;
 pub use lda::*;
 pub use ldx::*;
 pub use ldy::*;
 pub use lsr::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use lxa::*;
 pub use nop::*;
 pub use ora::*;
 pub use pha::*;
+pub use php::*;
+pub use phx::*;
+pub use phy::*;
+pub use pla::*;
+pub use plp::*;
+pub use plx::*;
+pub use ply::*;
+pub use rla::*;
+pub use rmb::*;
 pub use rol::*;
 pub use ror::*;
+pub use rra::*;
+pub use rti::*;
+pub use rts::*;
+pub use sax::*;
 pub use sbc::*;
 pub use sec::*;
 pub use sed::*;
 pub use sei::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use sha::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use shx::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use shy::*;
+pub use slo::*;
+pub use smb::*;
+pub use sre::*;
 pub use sta::*;
+pub use stp::*;
 pub use stx::*;
 pub use sty::*;
+pub use stz::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use tas::*;
 pub use tax::*;
 pub use tay::*;
+pub use trb::*;
+pub use tsb::*;
 pub use tsx::*;
 pub use txa::*;
 pub use txs::*;
 pub use tya::*;
+pub use usbc::*;
+pub use wai::*;
+#[cfg(feature = "unstable-opcodes")]
+pub use xaa::*;
 
-pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
+pub fn execute_opcode<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>, opcode: Opcode) {
     match opcode {
         // ADC
         Opcode::AdcImmediate => Adc::immediate(executor),
@@ -118,6 +238,17 @@ pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
         Opcode::AslAbsolute => Asl::absolute(executor),
         Opcode::AslAbsoluteX => Asl::absolute_x(executor),
 
+        // B**
+        Opcode::Bcc => Bcc::relative(executor),
+        Opcode::Bcs => Bcs::relative(executor),
+        Opcode::Beq => Beq::relative(executor),
+        Opcode::Bmi => Bmi::relative(executor),
+        Opcode::Bne => Bne::relative(executor),
+        Opcode::Bpl => Bpl::relative(executor),
+        Opcode::Brk => Brk::implied(executor),
+        Opcode::Bvc => Bvc::relative(executor),
+        Opcode::Bvs => Bvs::relative(executor),
+
         // BIT
         Opcode::BitZeropage => Bit::zeropage(executor),
         Opcode::BitAbsolute => Bit::absolute(executor),
@@ -178,6 +309,12 @@ pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
         Opcode::Inx => Inx::implied(executor),
         Opcode::Iny => Iny::implied(executor),
 
+        // JMP
+        Opcode::JmpAbsolute => Jmp::absolute(executor),
+        Opcode::JmpIndirect => Jmp::indirect(executor),
+
+        Opcode::Jsr => Jsr::absolute(executor),
+
         // LDA
         Opcode::LdaImmediate => Lda::immediate(executor),
         Opcode::LdaZeropage => Lda::zeropage(executor),
@@ -224,6 +361,8 @@ pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
         // P**
         Opcode::Pha => Pha::stack_push(executor),
         Opcode::Php => Php::stack_push(executor),
+        Opcode::Pla => Pla::stack_pull(executor),
+        Opcode::Plp => Plp::stack_pull(executor),
 
         // ROL
         Opcode::RolAccumulator => Rol::accumulator(executor),
@@ -239,6 +378,10 @@ pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
         Opcode::RorAbsolute => Ror::absolute(executor),
         Opcode::RorAbsoluteX => Ror::absolute_x(executor),
 
+        // RT*
+        Opcode::Rti => Rti::implied(executor),
+        Opcode::Rts => Rts::implied(executor),
+
         // SBC
         Opcode::SbcImmediate => Sbc::immediate(executor),
         Opcode::SbcZeropage => Sbc::zeropage(executor),
@@ -281,6 +424,508 @@ pub fn execute_opcode<M: Memory>(executor: &mut Executor<M>, opcode: Opcode) {
         Opcode::Txs => Txs::implied(executor),
         Opcode::Tya => Tya::implied(executor),
 
-        Opcode::Unimplemented => unimplemented!(),
+        // Unofficial opcodes
+
+        // ALR
+        Opcode::AlrImmediate => Alr::immediate(executor),
+
+        // ANC
+        Opcode::AncImmediate => Anc::immediate(executor),
+        Opcode::AncAltImmediate => Anc::immediate(executor),
+
+        // ARR
+        Opcode::ArrImmediate => Arr::immediate(executor),
+
+        // AXS
+        Opcode::AxsImmediate => Axs::immediate(executor),
+
+        // DCP
+        Opcode::DcpZeropage => Dcp::zeropage(executor),
+        Opcode::DcpZeropageX => Dcp::zeropage_x(executor),
+        Opcode::DcpAbsolute => Dcp::absolute(executor),
+        Opcode::DcpAbsoluteX => Dcp::absolute_x(executor),
+        Opcode::DcpAbsoluteY => Dcp::absolute_y(executor),
+        Opcode::DcpIndirectX => Dcp::indirect_x(executor),
+        Opcode::DcpIndirectY => Dcp::indirect_y(executor),
+
+        // ISC
+        Opcode::IscZeropage => Isc::zeropage(executor),
+        Opcode::IscZeropageX => Isc::zeropage_x(executor),
+        Opcode::IscAbsolute => Isc::absolute(executor),
+        Opcode::IscAbsoluteX => Isc::absolute_x(executor),
+        Opcode::IscAbsoluteY => Isc::absolute_y(executor),
+        Opcode::IscIndirectX => Isc::indirect_x(executor),
+        Opcode::IscIndirectY => Isc::indirect_y(executor),
+
+        // KIL
+        Opcode::Kil02
+        | Opcode::Kil12
+        | Opcode::Kil22
+        | Opcode::Kil32
+        | Opcode::Kil42
+        | Opcode::Kil52
+        | Opcode::Kil62
+        | Opcode::Kil72
+        | Opcode::Kil92
+        | Opcode::KilB2
+        | Opcode::KilD2
+        | Opcode::KilF2 => Kil::implied(executor),
+
+        // LAX
+        Opcode::LaxZeropage => Lax::zeropage(executor),
+        Opcode::LaxZeropageY => Lax::zeropage_y(executor),
+        Opcode::LaxAbsolute => Lax::absolute(executor),
+        Opcode::LaxAbsoluteY => Lax::absolute_y(executor),
+        Opcode::LaxIndirectX => Lax::indirect_x(executor),
+        Opcode::LaxIndirectY => Lax::indirect_y(executor),
+
+        // NOP
+        Opcode::Nop1A
+        | Opcode::Nop3A
+        | Opcode::Nop5A
+        | Opcode::Nop7A
+        | Opcode::NopDa
+        | Opcode::NopFa => Nop::implied(executor),
+        Opcode::Nop80Immediate
+        | Opcode::Nop82Immediate
+        | Opcode::Nop89Immediate
+        | Opcode::NopC2Immediate
+        | Opcode::NopE2Immediate => Nop::immediate(executor),
+        Opcode::Nop04Zeropage | Opcode::Nop44Zeropage | Opcode::Nop64Zeropage => {
+            Nop::zeropage(executor)
+        }
+        Opcode::Nop14ZeropageX
+        | Opcode::Nop34ZeropageX
+        | Opcode::Nop54ZeropageX
+        | Opcode::Nop74ZeropageX
+        | Opcode::NopD4ZeropageX
+        | Opcode::NopF4ZeropageX => Nop::zeropage_x(executor),
+        Opcode::Nop0CAbsolute => Nop::absolute(executor),
+        Opcode::Nop1CAbsoluteX
+        | Opcode::Nop3CAbsoluteX
+        | Opcode::Nop5CAbsoluteX
+        | Opcode::Nop7CAbsoluteX
+        | Opcode::NopDcAbsoluteX
+        | Opcode::NopFcAbsoluteX => Nop::absolute_x(executor),
+
+        // RLA
+        Opcode::RlaZeropage => Rla::zeropage(executor),
+        Opcode::RlaZeropageX => Rla::zeropage_x(executor),
+        Opcode::RlaAbsolute => Rla::absolute(executor),
+        Opcode::RlaAbsoluteX => Rla::absolute_x(executor),
+        Opcode::RlaAbsoluteY => Rla::absolute_y(executor),
+        Opcode::RlaIndirectX => Rla::indirect_x(executor),
+        Opcode::RlaIndirectY => Rla::indirect_y(executor),
+
+        // RRA
+        Opcode::RraZeropage => Rra::zeropage(executor),
+        Opcode::RraZeropageX => Rra::zeropage_x(executor),
+        Opcode::RraAbsolute => Rra::absolute(executor),
+        Opcode::RraAbsoluteX => Rra::absolute_x(executor),
+        Opcode::RraAbsoluteY => Rra::absolute_y(executor),
+        Opcode::RraIndirectX => Rra::indirect_x(executor),
+        Opcode::RraIndirectY => Rra::indirect_y(executor),
+
+        // SAX
+        Opcode::SaxZeropage => Sax::zeropage(executor),
+        Opcode::SaxZeropageY => Sax::zeropage_y(executor),
+        Opcode::SaxAbsolute => Sax::absolute(executor),
+        Opcode::SaxIndirectX => Sax::indirect_x(executor),
+
+        // SLO
+        Opcode::SloZeropage => Slo::zeropage(executor),
+        Opcode::SloZeropageX => Slo::zeropage_x(executor),
+        Opcode::SloAbsolute => Slo::absolute(executor),
+        Opcode::SloAbsoluteX => Slo::absolute_x(executor),
+        Opcode::SloAbsoluteY => Slo::absolute_y(executor),
+        Opcode::SloIndirectX => Slo::indirect_x(executor),
+        Opcode::SloIndirectY => Slo::indirect_y(executor),
+
+        // SRE
+        Opcode::SreZeropage => Sre::zeropage(executor),
+        Opcode::SreZeropageX => Sre::zeropage_x(executor),
+        Opcode::SreAbsolute => Sre::absolute(executor),
+        Opcode::SreAbsoluteX => Sre::absolute_x(executor),
+        Opcode::SreAbsoluteY => Sre::absolute_y(executor),
+        Opcode::SreIndirectX => Sre::indirect_x(executor),
+        Opcode::SreIndirectY => Sre::indirect_y(executor),
+
+        Opcode::UsbcImmediate => Usbc::immediate(executor),
+
+        // Unstable unofficial opcodes
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::LasAbsoluteY => Las::absolute_y(executor),
+
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::LxaImmediate => Lxa::immediate(executor),
+
+        // SHA
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::ShaAbsoluteY => Sha::absolute_y(executor),
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::ShaIndirectY => Sha::indirect_y(executor),
+
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::ShxAbsoluteY => Shx::absolute_y(executor),
+
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::ShyAbsoluteX => Shy::absolute_x(executor),
+
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::TasAbsoluteY => Tas::absolute_y(executor),
+
+        #[cfg(feature = "unstable-opcodes")]
+        Opcode::XaaImmediate => Xaa::immediate(executor),
+    }
+}
+
+pub fn execute_cmos_opcode<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>, opcode: CmosOpcode) {
+    match opcode {
+        // ADC
+        CmosOpcode::AdcImmediate => Adc::immediate(executor),
+        CmosOpcode::AdcZeropage => Adc::zeropage(executor),
+        CmosOpcode::AdcZeropageX => Adc::zeropage_x(executor),
+        CmosOpcode::AdcAbsolute => Adc::absolute(executor),
+        CmosOpcode::AdcAbsoluteX => Adc::absolute_x(executor),
+        CmosOpcode::AdcAbsoluteY => Adc::absolute_y(executor),
+        CmosOpcode::AdcIndirectX => Adc::indirect_x(executor),
+        CmosOpcode::AdcIndirectY => Adc::indirect_y(executor),
+
+        // AND
+        CmosOpcode::AndImmediate => And::immediate(executor),
+        CmosOpcode::AndZeropage => And::zeropage(executor),
+        CmosOpcode::AndZeropageX => And::zeropage_x(executor),
+        CmosOpcode::AndAbsolute => And::absolute(executor),
+        CmosOpcode::AndAbsoluteX => And::absolute_x(executor),
+        CmosOpcode::AndAbsoluteY => And::absolute_y(executor),
+        CmosOpcode::AndIndirectX => And::indirect_x(executor),
+        CmosOpcode::AndIndirectY => And::indirect_y(executor),
+
+        // ASL
+        CmosOpcode::AslAccumulator => Asl::accumulator(executor),
+        CmosOpcode::AslZeropage => Asl::zeropage(executor),
+        CmosOpcode::AslZeropageX => Asl::zeropage_x(executor),
+        CmosOpcode::AslAbsolute => Asl::absolute(executor),
+        CmosOpcode::AslAbsoluteX => Asl::absolute_x(executor),
+
+        // B**
+        CmosOpcode::Bcc => Bcc::relative(executor),
+        CmosOpcode::Bcs => Bcs::relative(executor),
+        CmosOpcode::Beq => Beq::relative(executor),
+        CmosOpcode::Bmi => Bmi::relative(executor),
+        CmosOpcode::Bne => Bne::relative(executor),
+        CmosOpcode::Bpl => Bpl::relative(executor),
+        CmosOpcode::Brk => Brk::implied(executor),
+        CmosOpcode::Bvc => Bvc::relative(executor),
+        CmosOpcode::Bvs => Bvs::relative(executor),
+        CmosOpcode::Bra => Bra::relative(executor),
+
+        // BIT
+        CmosOpcode::BitZeropage => Bit::zeropage(executor),
+        CmosOpcode::BitAbsolute => Bit::absolute(executor),
+
+        // CL*
+        CmosOpcode::Clc => Clc::implied(executor),
+        CmosOpcode::Cld => Cld::implied(executor),
+        CmosOpcode::Cli => Cli::implied(executor),
+        CmosOpcode::Clv => Clv::implied(executor),
+
+        // CMP
+        CmosOpcode::CmpImmediate => Cmp::immediate(executor),
+        CmosOpcode::CmpZeropage => Cmp::zeropage(executor),
+        CmosOpcode::CmpZeropageX => Cmp::zeropage_x(executor),
+        CmosOpcode::CmpAbsolute => Cmp::absolute(executor),
+        CmosOpcode::CmpAbsoluteX => Cmp::absolute_x(executor),
+        CmosOpcode::CmpAbsoluteY => Cmp::absolute_y(executor),
+        CmosOpcode::CmpIndirectX => Cmp::indirect_x(executor),
+        CmosOpcode::CmpIndirectY => Cmp::indirect_y(executor),
+
+        // CPX
+        CmosOpcode::CpxImmediate => Cpx::immediate(executor),
+        CmosOpcode::CpxZeropage => Cpx::zeropage(executor),
+        CmosOpcode::CpxAbsolute => Cpx::absolute(executor),
+
+        // CPY
+        CmosOpcode::CpyImmediate => Cpy::immediate(executor),
+        CmosOpcode::CpyZeropage => Cpy::zeropage(executor),
+        CmosOpcode::CpyAbsolute => Cpy::absolute(executor),
+
+        // DEC
+        CmosOpcode::DecZeropage => Dec::zeropage(executor),
+        CmosOpcode::DecZeropageX => Dec::zeropage_x(executor),
+        CmosOpcode::DecAbsolute => Dec::absolute(executor),
+        CmosOpcode::DecAbsoluteX => Dec::absolute_x(executor),
+
+        // DE*
+        CmosOpcode::Dex => Dex::implied(executor),
+        CmosOpcode::Dey => Dey::implied(executor),
+
+        // EOR
+        CmosOpcode::EorImmediate => Eor::immediate(executor),
+        CmosOpcode::EorZeropage => Eor::zeropage(executor),
+        CmosOpcode::EorZeropageX => Eor::zeropage_x(executor),
+        CmosOpcode::EorAbsolute => Eor::absolute(executor),
+        CmosOpcode::EorAbsoluteX => Eor::absolute_x(executor),
+        CmosOpcode::EorAbsoluteY => Eor::absolute_y(executor),
+        CmosOpcode::EorIndirectX => Eor::indirect_x(executor),
+        CmosOpcode::EorIndirectY => Eor::indirect_y(executor),
+
+        // INC
+        CmosOpcode::IncZeropage => Inc::zeropage(executor),
+        CmosOpcode::IncZeropageX => Inc::zeropage_x(executor),
+        CmosOpcode::IncAbsolute => Inc::absolute(executor),
+        CmosOpcode::IncAbsoluteX => Inc::absolute_x(executor),
+
+        // IN*
+        CmosOpcode::Inx => Inx::implied(executor),
+        CmosOpcode::Iny => Iny::implied(executor),
+
+        // JMP
+        CmosOpcode::JmpAbsolute => Jmp::absolute(executor),
+        CmosOpcode::JmpIndirect => Jmp::indirect(executor),
+
+        CmosOpcode::Jsr => Jsr::absolute(executor),
+
+        // LDA
+        CmosOpcode::LdaImmediate => Lda::immediate(executor),
+        CmosOpcode::LdaZeropage => Lda::zeropage(executor),
+        CmosOpcode::LdaZeropageX => Lda::zeropage_x(executor),
+        CmosOpcode::LdaAbsolute => Lda::absolute(executor),
+        CmosOpcode::LdaAbsoluteX => Lda::absolute_x(executor),
+        CmosOpcode::LdaAbsoluteY => Lda::absolute_y(executor),
+        CmosOpcode::LdaIndirectX => Lda::indirect_x(executor),
+        CmosOpcode::LdaIndirectY => Lda::indirect_y(executor),
+
+        // LDX
+        CmosOpcode::LdxImmediate => Ldx::immediate(executor),
+        CmosOpcode::LdxZeropage => Ldx::zeropage(executor),
+        CmosOpcode::LdxZeropageY => Ldx::zeropage_y(executor),
+        CmosOpcode::LdxAbsolute => Ldx::absolute(executor),
+        CmosOpcode::LdxAbsoluteY => Ldx::absolute_y(executor),
+
+        // LDY
+        CmosOpcode::LdyImmediate => Ldy::immediate(executor),
+        CmosOpcode::LdyZeropage => Ldy::zeropage(executor),
+        CmosOpcode::LdyZeropageX => Ldy::zeropage_x(executor),
+        CmosOpcode::LdyAbsolute => Ldy::absolute(executor),
+        CmosOpcode::LdyAbsoluteX => Ldy::absolute_x(executor),
+
+        // LSR
+        CmosOpcode::LsrAccumulator => Lsr::accumulator(executor),
+        CmosOpcode::LsrZeropage => Lsr::zeropage(executor),
+        CmosOpcode::LsrZeropageX => Lsr::zeropage_x(executor),
+        CmosOpcode::LsrAbsolute => Lsr::absolute(executor),
+        CmosOpcode::LsrAbsoluteX => Lsr::absolute_x(executor),
+
+        CmosOpcode::Nop => Nop::implied(executor),
+
+        // ORA
+        CmosOpcode::OraImmediate => Ora::immediate(executor),
+        CmosOpcode::OraZeropage => Ora::zeropage(executor),
+        CmosOpcode::OraZeropageX => Ora::zeropage_x(executor),
+        CmosOpcode::OraAbsolute => Ora::absolute(executor),
+        CmosOpcode::OraAbsoluteX => Ora::absolute_x(executor),
+        CmosOpcode::OraAbsoluteY => Ora::absolute_y(executor),
+        CmosOpcode::OraIndirectX => Ora::indirect_x(executor),
+        CmosOpcode::OraIndirectY => Ora::indirect_y(executor),
+
+        // P**
+        CmosOpcode::Pha => Pha::stack_push(executor),
+        CmosOpcode::Php => Php::stack_push(executor),
+        CmosOpcode::Pla => Pla::stack_pull(executor),
+        CmosOpcode::Plp => Plp::stack_pull(executor),
+
+        // ROL
+        CmosOpcode::RolAccumulator => Rol::accumulator(executor),
+        CmosOpcode::RolZeropage => Rol::zeropage(executor),
+        CmosOpcode::RolZeropageX => Rol::zeropage_x(executor),
+        CmosOpcode::RolAbsolute => Rol::absolute(executor),
+        CmosOpcode::RolAbsoluteX => Rol::absolute_x(executor),
+
+        // ROR
+        CmosOpcode::RorAccumulator => Ror::accumulator(executor),
+        CmosOpcode::RorZeropage => Ror::zeropage(executor),
+        CmosOpcode::RorZeropageX => Ror::zeropage_x(executor),
+        CmosOpcode::RorAbsolute => Ror::absolute(executor),
+        CmosOpcode::RorAbsoluteX => Ror::absolute_x(executor),
+
+        // RT*
+        CmosOpcode::Rti => Rti::implied(executor),
+        CmosOpcode::Rts => Rts::implied(executor),
+
+        // SBC
+        CmosOpcode::SbcImmediate => Sbc::immediate(executor),
+        CmosOpcode::SbcZeropage => Sbc::zeropage(executor),
+        CmosOpcode::SbcZeropageX => Sbc::zeropage_x(executor),
+        CmosOpcode::SbcAbsolute => Sbc::absolute(executor),
+        CmosOpcode::SbcAbsoluteX => Sbc::absolute_x(executor),
+        CmosOpcode::SbcAbsoluteY => Sbc::absolute_y(executor),
+        CmosOpcode::SbcIndirectX => Sbc::indirect_x(executor),
+        CmosOpcode::SbcIndirectY => Sbc::indirect_y(executor),
+
+        // SE*
+        CmosOpcode::Sec => Sec::implied(executor),
+        CmosOpcode::Sed => Sed::implied(executor),
+        CmosOpcode::Sei => Sei::implied(executor),
+
+        // STA
+        CmosOpcode::StaZeropage => Sta::zeropage(executor),
+        CmosOpcode::StaZeropageX => Sta::zeropage_x(executor),
+        CmosOpcode::StaAbsolute => Sta::absolute(executor),
+        CmosOpcode::StaAbsoluteX => Sta::absolute_x(executor),
+        CmosOpcode::StaAbsoluteY => Sta::absolute_y(executor),
+        CmosOpcode::StaIndirectX => Sta::indirect_x(executor),
+        CmosOpcode::StaIndirectY => Sta::indirect_y(executor),
+
+        // STX
+        CmosOpcode::StxZeropage => Stx::zeropage(executor),
+        CmosOpcode::StxZeropageY => Stx::zeropage_y(executor),
+        CmosOpcode::StxAbsolute => Stx::absolute(executor),
+
+        // Sty
+        CmosOpcode::StyZeropage => Sty::zeropage(executor),
+        CmosOpcode::StyZeropageX => Sty::zeropage_x(executor),
+        CmosOpcode::StyAbsolute => Sty::absolute(executor),
+
+        // T**
+        CmosOpcode::Tax => Tax::implied(executor),
+        CmosOpcode::Tay => Tay::implied(executor),
+        CmosOpcode::Tsx => Tsx::implied(executor),
+        CmosOpcode::Txa => Txa::implied(executor),
+        CmosOpcode::Txs => Txs::implied(executor),
+        CmosOpcode::Tya => Tya::implied(executor),
+
+        // 65C02 opcodes
+
+        // (zp)
+        CmosOpcode::AdcZeropageIndirect => Adc::zeropage_indirect(executor),
+        CmosOpcode::AndZeropageIndirect => And::zeropage_indirect(executor),
+        CmosOpcode::CmpZeropageIndirect => Cmp::zeropage_indirect(executor),
+        CmosOpcode::EorZeropageIndirect => Eor::zeropage_indirect(executor),
+        CmosOpcode::LdaZeropageIndirect => Lda::zeropage_indirect(executor),
+        CmosOpcode::OraZeropageIndirect => Ora::zeropage_indirect(executor),
+        CmosOpcode::SbcZeropageIndirect => Sbc::zeropage_indirect(executor),
+        CmosOpcode::StaZeropageIndirect => Sta::zeropage_indirect(executor),
+
+        // BBR
+        CmosOpcode::Bbr0 => Bbr::<0>::zeropage_relative(executor),
+        CmosOpcode::Bbr1 => Bbr::<1>::zeropage_relative(executor),
+        CmosOpcode::Bbr2 => Bbr::<2>::zeropage_relative(executor),
+        CmosOpcode::Bbr3 => Bbr::<3>::zeropage_relative(executor),
+        CmosOpcode::Bbr4 => Bbr::<4>::zeropage_relative(executor),
+        CmosOpcode::Bbr5 => Bbr::<5>::zeropage_relative(executor),
+        CmosOpcode::Bbr6 => Bbr::<6>::zeropage_relative(executor),
+        CmosOpcode::Bbr7 => Bbr::<7>::zeropage_relative(executor),
+
+        // BBS
+        CmosOpcode::Bbs0 => Bbs::<0>::zeropage_relative(executor),
+        CmosOpcode::Bbs1 => Bbs::<1>::zeropage_relative(executor),
+        CmosOpcode::Bbs2 => Bbs::<2>::zeropage_relative(executor),
+        CmosOpcode::Bbs3 => Bbs::<3>::zeropage_relative(executor),
+        CmosOpcode::Bbs4 => Bbs::<4>::zeropage_relative(executor),
+        CmosOpcode::Bbs5 => Bbs::<5>::zeropage_relative(executor),
+        CmosOpcode::Bbs6 => Bbs::<6>::zeropage_relative(executor),
+        CmosOpcode::Bbs7 => Bbs::<7>::zeropage_relative(executor),
+
+        // BIT
+        CmosOpcode::BitImmediate => BitImmediate::immediate(executor),
+        CmosOpcode::BitZeropageX => Bit::zeropage_x(executor),
+        CmosOpcode::BitAbsoluteX => Bit::absolute_x(executor),
+
+        // INC, DEC
+        CmosOpcode::IncAccumulator => Inc::accumulator(executor),
+        CmosOpcode::DecAccumulator => Dec::accumulator(executor),
+
+        CmosOpcode::JmpAbsoluteXIndirect => Jmp::absolute_x_indirect(executor),
+
+        // P**
+        CmosOpcode::Phx => Phx::stack_push(executor),
+        CmosOpcode::Phy => Phy::stack_push(executor),
+        CmosOpcode::Plx => Plx::stack_pull(executor),
+        CmosOpcode::Ply => Ply::stack_pull(executor),
+
+        // RMB
+        CmosOpcode::Rmb0 => Rmb::<0>::zeropage(executor),
+        CmosOpcode::Rmb1 => Rmb::<1>::zeropage(executor),
+        CmosOpcode::Rmb2 => Rmb::<2>::zeropage(executor),
+        CmosOpcode::Rmb3 => Rmb::<3>::zeropage(executor),
+        CmosOpcode::Rmb4 => Rmb::<4>::zeropage(executor),
+        CmosOpcode::Rmb5 => Rmb::<5>::zeropage(executor),
+        CmosOpcode::Rmb6 => Rmb::<6>::zeropage(executor),
+        CmosOpcode::Rmb7 => Rmb::<7>::zeropage(executor),
+
+        // SMB
+        CmosOpcode::Smb0 => Smb::<0>::zeropage(executor),
+        CmosOpcode::Smb1 => Smb::<1>::zeropage(executor),
+        CmosOpcode::Smb2 => Smb::<2>::zeropage(executor),
+        CmosOpcode::Smb3 => Smb::<3>::zeropage(executor),
+        CmosOpcode::Smb4 => Smb::<4>::zeropage(executor),
+        CmosOpcode::Smb5 => Smb::<5>::zeropage(executor),
+        CmosOpcode::Smb6 => Smb::<6>::zeropage(executor),
+        CmosOpcode::Smb7 => Smb::<7>::zeropage(executor),
+
+        // STZ
+        CmosOpcode::StzZeropage => Stz::zeropage(executor),
+        CmosOpcode::StzZeropageX => Stz::zeropage_x(executor),
+        CmosOpcode::StzAbsolute => Stz::absolute(executor),
+        CmosOpcode::StzAbsoluteX => Stz::absolute_x(executor),
+
+        // TRB
+        CmosOpcode::TrbZeropage => Trb::zeropage(executor),
+        CmosOpcode::TrbAbsolute => Trb::absolute(executor),
+
+        // TSB
+        CmosOpcode::TsbZeropage => Tsb::zeropage(executor),
+        CmosOpcode::TsbAbsolute => Tsb::absolute(executor),
+
+        CmosOpcode::Stp => Stp::implied(executor),
+        CmosOpcode::Wai => Wai::implied(executor),
+
+        // NOP
+        // these take a single cycle, the opcode fetch
+        CmosOpcode::Nop03
+        | CmosOpcode::Nop0B
+        | CmosOpcode::Nop13
+        | CmosOpcode::Nop1B
+        | CmosOpcode::Nop23
+        | CmosOpcode::Nop2B
+        | CmosOpcode::Nop33
+        | CmosOpcode::Nop3B
+        | CmosOpcode::Nop43
+        | CmosOpcode::Nop4B
+        | CmosOpcode::Nop53
+        | CmosOpcode::Nop5B
+        | CmosOpcode::Nop63
+        | CmosOpcode::Nop6B
+        | CmosOpcode::Nop73
+        | CmosOpcode::Nop7B
+        | CmosOpcode::Nop83
+        | CmosOpcode::Nop8B
+        | CmosOpcode::Nop93
+        | CmosOpcode::Nop9B
+        | CmosOpcode::NopA3
+        | CmosOpcode::NopAb
+        | CmosOpcode::NopB3
+        | CmosOpcode::NopBb
+        | CmosOpcode::NopC3
+        | CmosOpcode::NopD3
+        | CmosOpcode::NopE3
+        | CmosOpcode::NopEb
+        | CmosOpcode::NopF3
+        | CmosOpcode::NopFb => {}
+        CmosOpcode::Nop02Immediate
+        | CmosOpcode::Nop22Immediate
+        | CmosOpcode::Nop42Immediate
+        | CmosOpcode::Nop62Immediate
+        | CmosOpcode::Nop82Immediate
+        | CmosOpcode::NopC2Immediate
+        | CmosOpcode::NopE2Immediate => Nop::immediate(executor),
+        CmosOpcode::Nop44Zeropage => Nop::zeropage(executor),
+        CmosOpcode::Nop54ZeropageX
+        | CmosOpcode::NopD4ZeropageX
+        | CmosOpcode::NopF4ZeropageX => Nop::zeropage_x(executor),
+        CmosOpcode::NopDcAbsolute
+        | CmosOpcode::NopFcAbsolute => Nop::absolute(executor),
+        CmosOpcode::Nop5C => Nop::absolute_8_cycles(executor),
     }
 }
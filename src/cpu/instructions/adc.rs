@@ -5,11 +5,32 @@ use crate::cpu::{
 pub struct Adc;
 
 impl ReadInstruction for Adc {
+    const DECIMAL_EXTRA_CYCLE: bool = true;
+
     fn instruction(cpu: &mut Cpu, value: u8) {
         let carry = cpu.flags.contains(StatusFlags::CARRY);
         let (result, new_carry) = arithmetic::add_with_carry(cpu.a, value, carry);
         let overflow = arithmetic::add_overflows(cpu.a as i8, value as i8, carry);
 
+        if cpu.decimal_mode() {
+            let (decimal_result, decimal_carry) =
+                arithmetic::decimal_add_with_carry(cpu.a, value, carry);
+            let (negative, overflow) =
+                arithmetic::decimal_add_negative_overflow(cpu.a, value, carry);
+
+            if cpu.variant.is_cmos() {
+                cpu.set_register_with_flags(a_register, decimal_result);
+            } else {
+                // NMOS sets ZERO based on the binary result
+                cpu.a = decimal_result;
+                cpu.flags.set(StatusFlags::ZERO, result == 0);
+                cpu.flags.set(StatusFlags::NEGATIVE, negative);
+            }
+            cpu.flags.set(StatusFlags::CARRY, decimal_carry);
+            cpu.flags.set(StatusFlags::OVERFLOW, overflow);
+            return;
+        }
+
         cpu.set_register_with_flags(a_register, result);
         cpu.flags.set(StatusFlags::CARRY, new_carry);
         cpu.flags.set(StatusFlags::OVERFLOW, overflow);
@@ -24,3 +45,4 @@ impl ReadAbsoluteX for Adc {}
 impl ReadAbsoluteY for Adc {}
 impl ReadIndirectX for Adc {}
 impl ReadIndirectY for Adc {}
+impl ReadZeropageIndirect for Adc {}
@@ -0,0 +1,14 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::read::*, register_getters::a_register};
+
+/// Unofficial instruction, AND followed by LSR on the accumulator
+pub struct Alr;
+
+impl ReadInstruction for Alr {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        let and = cpu.a & value;
+        cpu.flags.set(StatusFlags::CARRY, and & 1 != 0);
+        cpu.set_register_with_flags(a_register, and >> 1);
+    }
+}
+
+impl ReadImmediate for Alr {}
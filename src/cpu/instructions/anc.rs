@@ -0,0 +1,16 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::read::*, register_getters::a_register};
+
+/// Unofficial instruction, AND that also copies NEGATIVE into CARRY
+pub struct Anc;
+
+impl ReadInstruction for Anc {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.set_register_with_flags(a_register, cpu.a & value);
+        cpu.flags.set(
+            StatusFlags::CARRY,
+            cpu.flags.contains(StatusFlags::NEGATIVE),
+        );
+    }
+}
+
+impl ReadImmediate for Anc {}
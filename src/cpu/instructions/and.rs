@@ -17,3 +17,4 @@ impl ReadAbsoluteX for And {}
 impl ReadAbsoluteY for And {}
 impl ReadIndirectX for And {}
 impl ReadIndirectY for And {}
+impl ReadZeropageIndirect for And {}
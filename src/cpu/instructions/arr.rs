@@ -0,0 +1,22 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::read::*, register_getters::a_register};
+
+/// Unofficial instruction, AND followed by ROR on the accumulator
+///
+/// CARRY and OVERFLOW are set in an unusual way,
+/// as a side effect of the adder being involved in the operation
+pub struct Arr;
+
+impl ReadInstruction for Arr {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        let carry = cpu.flags.contains(StatusFlags::CARRY);
+        let result = (cpu.a & value) >> 1 | (carry as u8) << 7;
+        cpu.set_register_with_flags(a_register, result);
+
+        let bit6 = result >> 6 & 1 != 0;
+        let bit5 = result >> 5 & 1 != 0;
+        cpu.flags.set(StatusFlags::CARRY, bit6);
+        cpu.flags.set(StatusFlags::OVERFLOW, bit6 != bit5);
+    }
+}
+
+impl ReadImmediate for Arr {}
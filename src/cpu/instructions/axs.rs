@@ -0,0 +1,19 @@
+use crate::cpu::{
+    Cpu, StatusFlags, addressing_modes::read::*, arithmetic, register_getters::x_register,
+};
+
+/// Unofficial instruction, sets X to (A AND X) - value
+///
+/// The subtraction is done like in CMP, ignoring the CARRY flag, which is set accordingly
+pub struct Axs;
+
+impl ReadInstruction for Axs {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        let (result, carry) = arithmetic::sub_with_carry(cpu.a & cpu.x, value, true);
+
+        cpu.set_register_with_flags(x_register, result);
+        cpu.flags.set(StatusFlags::CARRY, carry);
+    }
+}
+
+impl ReadImmediate for Axs {}
@@ -0,0 +1,10 @@
+use crate::cpu::addressing_modes::relative::*;
+
+/// 65C02 instruction, branch if bit `BIT` of a zero page value is clear
+pub struct Bbr<const BIT: u8>;
+
+impl<const BIT: u8> BitBranchInstruction for Bbr<BIT> {
+    fn condition(value: u8) -> bool {
+        value >> BIT & 1 == 0
+    }
+}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Bcc;
+
+impl RelativeInstruction for Bcc {
+    fn condition(cpu: &Cpu) -> bool {
+        !cpu.flags.contains(StatusFlags::CARRY)
+    }
+}
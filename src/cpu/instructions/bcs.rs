@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Bcs;
+
+impl RelativeInstruction for Bcs {
+    fn condition(cpu: &Cpu) -> bool {
+        cpu.flags.contains(StatusFlags::CARRY)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Beq;
+
+impl RelativeInstruction for Beq {
+    fn condition(cpu: &Cpu) -> bool {
+        cpu.flags.contains(StatusFlags::ZERO)
+    }
+}
@@ -15,4 +15,17 @@ impl ReadInstruction for Bit {
 }
 
 impl ReadZeropage for Bit {}
+impl ReadZeropageX for Bit {}
 impl ReadAbsolute for Bit {}
+impl ReadAbsoluteX for Bit {}
+
+/// 65C02 BIT with an immediate operand, which only sets ZERO
+pub struct BitImmediate;
+
+impl ReadInstruction for BitImmediate {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.flags.set(StatusFlags::ZERO, cpu.a & value == 0);
+    }
+}
+
+impl ReadImmediate for BitImmediate {}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Bmi;
+
+impl RelativeInstruction for Bmi {
+    fn condition(cpu: &Cpu) -> bool {
+        cpu.flags.contains(StatusFlags::NEGATIVE)
+    }
+}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Bne;
+
+impl RelativeInstruction for Bne {
+    fn condition(cpu: &Cpu) -> bool {
+        !cpu.flags.contains(StatusFlags::ZERO)
+    }
+}
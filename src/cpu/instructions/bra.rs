@@ -0,0 +1,10 @@
+use crate::cpu::{Cpu, addressing_modes::relative::*};
+
+/// 65C02 instruction, branch always
+pub struct Bra;
+
+impl RelativeInstruction for Bra {
+    fn condition(_cpu: &Cpu) -> bool {
+        true
+    }
+}
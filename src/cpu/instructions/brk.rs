@@ -0,0 +1,17 @@
+use crate::{
+    cpu::{BusObserver, StatusFlags, executor::Executor},
+    memory::Memory,
+};
+
+pub struct Brk;
+
+impl Brk {
+    pub fn implied<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        // the byte after BRK is read and skipped,
+        // so the return address is the address of BRK + 2
+        let _ = executor.fetch_from_pc_cycle();
+
+        let flags = executor.cpu.flags | StatusFlags::BREAK | StatusFlags::IGNORED;
+        executor.push_interrupt_frame(flags);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::relative::*};
+
+pub struct Bvs;
+
+impl RelativeInstruction for Bvs {
+    fn condition(cpu: &Cpu) -> bool {
+        cpu.flags.contains(StatusFlags::OVERFLOW)
+    }
+}
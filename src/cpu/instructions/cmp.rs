@@ -20,3 +20,4 @@ impl ReadAbsoluteX for Cmp {}
 impl ReadAbsoluteY for Cmp {}
 impl ReadIndirectX for Cmp {}
 impl ReadIndirectY for Cmp {}
+impl ReadZeropageIndirect for Cmp {}
@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{Cmp, Dec},
+};
+
+/// Unofficial instruction, DEC followed by CMP
+pub struct Dcp;
+
+impl RmwInstruction for Dcp {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Dec as RmwInstruction>::instruction(cpu, value);
+        <Cmp as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Dcp {}
+impl RmwZeropageX for Dcp {}
+impl RmwAbsolute for Dcp {}
+impl RmwAbsoluteX for Dcp {}
+impl RmwAbsoluteY for Dcp {}
+impl RmwIndirectX for Dcp {}
+impl RmwIndirectY for Dcp {}
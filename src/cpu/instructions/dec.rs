@@ -11,7 +11,10 @@ impl RmwInstruction for Dec {
     }
 }
 
+impl RmwAccumulator for Dec {}
 impl RmwZeropage for Dec {}
 impl RmwZeropageX for Dec {}
 impl RmwAbsolute for Dec {}
-impl RmwAbsoluteX for Dec {}
+impl RmwAbsoluteX for Dec {
+    const CMOS_SKIPS_DUMMY_READ: bool = false;
+}
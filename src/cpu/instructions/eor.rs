@@ -17,3 +17,4 @@ impl ReadAbsoluteX for Eor {}
 impl ReadAbsoluteY for Eor {}
 impl ReadIndirectX for Eor {}
 impl ReadIndirectY for Eor {}
+impl ReadZeropageIndirect for Eor {}
@@ -11,7 +11,10 @@ impl RmwInstruction for Inc {
     }
 }
 
+impl RmwAccumulator for Inc {}
 impl RmwZeropage for Inc {}
 impl RmwZeropageX for Inc {}
 impl RmwAbsolute for Inc {}
-impl RmwAbsoluteX for Inc {}
+impl RmwAbsoluteX for Inc {
+    const CMOS_SKIPS_DUMMY_READ: bool = false;
+}
@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{Inc, Sbc},
+};
+
+/// Unofficial instruction, INC followed by SBC
+pub struct Isc;
+
+impl RmwInstruction for Isc {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Inc as RmwInstruction>::instruction(cpu, value);
+        <Sbc as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Isc {}
+impl RmwZeropageX for Isc {}
+impl RmwAbsolute for Isc {}
+impl RmwAbsoluteX for Isc {}
+impl RmwAbsoluteY for Isc {}
+impl RmwIndirectX for Isc {}
+impl RmwIndirectY for Isc {}
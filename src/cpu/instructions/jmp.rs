@@ -0,0 +1,13 @@
+use crate::cpu::{Cpu, addressing_modes::jump::*};
+
+pub struct Jmp;
+
+impl JumpInstruction for Jmp {
+    fn instruction(cpu: &mut Cpu, addr: u16) {
+        cpu.pc = addr;
+    }
+}
+
+impl JumpAbsolute for Jmp {}
+impl JumpIndirect for Jmp {}
+impl JumpAbsoluteXIndirect for Jmp {}
@@ -0,0 +1,24 @@
+use crate::{
+    cpu::{BusObserver, executor::Executor},
+    memory::Memory,
+};
+
+pub struct Jsr;
+
+impl Jsr {
+    pub fn absolute<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let addr_low = executor.fetch_from_pc_cycle();
+        // internal operation, the CPU buffers addr_low and reads the top of the stack
+        executor.dummy_stack_read();
+
+        // PC points to the last byte of the instruction, which is what gets pushed
+        let [pc_low, pc_high] = executor.cpu.pc.to_le_bytes();
+        executor.stack_write(pc_high);
+        executor.cpu.sp = executor.cpu.sp.wrapping_sub(1);
+        executor.stack_write(pc_low);
+        executor.cpu.sp = executor.cpu.sp.wrapping_sub(1);
+
+        let addr_high = executor.read_cycle(executor.cpu.pc);
+        executor.cpu.pc = (addr_high as u16) << 8 | addr_low as u16;
+    }
+}
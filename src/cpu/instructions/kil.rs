@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::implied::*};
+
+/// Unofficial instruction, also known as JAM or HLT, halts the CPU
+///
+/// The CPU stays halted until it's reset
+pub struct Kil;
+
+impl ImpliedInstruction for Kil {
+    fn instruction(cpu: &mut Cpu) {
+        cpu.jammed = true;
+    }
+}
@@ -0,0 +1,16 @@
+use crate::cpu::{Cpu, addressing_modes::read::*, register_getters::a_register};
+
+/// Unstable unofficial instruction, sets A, X and SP to value AND SP
+pub struct Las;
+
+impl ReadInstruction for Las {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        let result = value & cpu.sp;
+
+        cpu.x = result;
+        cpu.sp = result;
+        cpu.set_register_with_flags(a_register, result);
+    }
+}
+
+impl ReadAbsoluteY for Las {}
@@ -0,0 +1,18 @@
+use crate::cpu::{Cpu, addressing_modes::read::*, register_getters::*};
+
+/// Unofficial instruction, LDA and LDX at the same time
+pub struct Lax;
+
+impl ReadInstruction for Lax {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.x = value;
+        cpu.set_register_with_flags(a_register, value);
+    }
+}
+
+impl ReadZeropage for Lax {}
+impl ReadZeropageY for Lax {}
+impl ReadAbsolute for Lax {}
+impl ReadAbsoluteY for Lax {}
+impl ReadIndirectX for Lax {}
+impl ReadIndirectY for Lax {}
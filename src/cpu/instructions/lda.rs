@@ -16,3 +16,4 @@ impl ReadAbsoluteX for Lda {}
 impl ReadAbsoluteY for Lda {}
 impl ReadIndirectX for Lda {}
 impl ReadIndirectY for Lda {}
+impl ReadZeropageIndirect for Lda {}
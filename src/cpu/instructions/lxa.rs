@@ -0,0 +1,15 @@
+use crate::cpu::{Cpu, addressing_modes::read::*, register_getters::a_register};
+
+/// Unstable unofficial instruction, sets A and X to (A OR magic constant) AND value
+pub struct Lxa;
+
+impl ReadInstruction for Lxa {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        let result = (cpu.a | cpu.magic_constant.0) & value;
+
+        cpu.x = result;
+        cpu.set_register_with_flags(a_register, result);
+    }
+}
+
+impl ReadImmediate for Lxa {}
@@ -1,7 +1,37 @@
-use crate::cpu::{Cpu, addressing_modes::implied::*};
+use crate::{
+    cpu::{
+        BusObserver, Cpu,
+        addressing_modes::{implied::*, read::*},
+        executor::Executor,
+    },
+    memory::Memory,
+};
 
 pub struct Nop;
 
 impl ImpliedInstruction for Nop {
     fn instruction(_cpu: &mut Cpu) {}
 }
+
+/// Unofficial NOPs that read memory, triggering any side effects of the read
+impl ReadInstruction for Nop {
+    fn instruction(_cpu: &mut Cpu, _value: u8) {}
+}
+
+impl ReadImmediate for Nop {}
+impl ReadZeropage for Nop {}
+impl ReadZeropageX for Nop {}
+impl ReadAbsolute for Nop {}
+impl ReadAbsoluteX for Nop {}
+
+impl Nop {
+    /// 65C02 opcode $5C, an 8 cycle NOP with a 2 byte operand
+    pub fn absolute_8_cycles<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        let _ = executor.fetch_from_pc_cycle();
+        let _ = executor.fetch_from_pc_cycle();
+
+        for _ in 0..5 {
+            executor.dummy_read_cycle(executor.cpu.pc);
+        }
+    }
+}
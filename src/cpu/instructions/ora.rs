@@ -17,3 +17,4 @@ impl ReadAbsoluteX for Ora {}
 impl ReadAbsoluteY for Ora {}
 impl ReadIndirectX for Ora {}
 impl ReadIndirectY for Ora {}
+impl ReadZeropageIndirect for Ora {}
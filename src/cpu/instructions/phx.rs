@@ -0,0 +1,10 @@
+use crate::cpu::{Cpu, addressing_modes::stack::StackPushInstruction};
+
+/// 65C02 instruction, push X
+pub struct Phx;
+
+impl StackPushInstruction for Phx {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.x
+    }
+}
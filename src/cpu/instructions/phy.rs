@@ -0,0 +1,10 @@
+use crate::cpu::{Cpu, addressing_modes::stack::StackPushInstruction};
+
+/// 65C02 instruction, push Y
+pub struct Phy;
+
+impl StackPushInstruction for Phy {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.y
+    }
+}
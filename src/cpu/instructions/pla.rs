@@ -0,0 +1,11 @@
+use crate::cpu::{
+    Cpu, addressing_modes::stack::StackPullInstruction, register_getters::a_register,
+};
+
+pub struct Pla;
+
+impl StackPullInstruction for Pla {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.set_register_with_flags(a_register, value);
+    }
+}
@@ -0,0 +1,9 @@
+use crate::cpu::{Cpu, addressing_modes::stack::StackPullInstruction};
+
+pub struct Plp;
+
+impl StackPullInstruction for Plp {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.restore_flags(value);
+    }
+}
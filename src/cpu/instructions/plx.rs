@@ -0,0 +1,12 @@
+use crate::cpu::{
+    Cpu, addressing_modes::stack::StackPullInstruction, register_getters::x_register,
+};
+
+/// 65C02 instruction, pull X
+pub struct Plx;
+
+impl StackPullInstruction for Plx {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.set_register_with_flags(x_register, value);
+    }
+}
@@ -0,0 +1,12 @@
+use crate::cpu::{
+    Cpu, addressing_modes::stack::StackPullInstruction, register_getters::y_register,
+};
+
+/// 65C02 instruction, pull Y
+pub struct Ply;
+
+impl StackPullInstruction for Ply {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.set_register_with_flags(y_register, value);
+    }
+}
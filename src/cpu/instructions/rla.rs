@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{And, Rol},
+};
+
+/// Unofficial instruction, ROL followed by AND
+pub struct Rla;
+
+impl RmwInstruction for Rla {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Rol as RmwInstruction>::instruction(cpu, value);
+        <And as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Rla {}
+impl RmwZeropageX for Rla {}
+impl RmwAbsolute for Rla {}
+impl RmwAbsoluteX for Rla {}
+impl RmwAbsoluteY for Rla {}
+impl RmwIndirectX for Rla {}
+impl RmwIndirectY for Rla {}
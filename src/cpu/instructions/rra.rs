@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{Adc, Ror},
+};
+
+/// Unofficial instruction, ROR followed by ADC
+pub struct Rra;
+
+impl RmwInstruction for Rra {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Ror as RmwInstruction>::instruction(cpu, value);
+        <Adc as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Rra {}
+impl RmwZeropageX for Rra {}
+impl RmwAbsolute for Rra {}
+impl RmwAbsoluteX for Rra {}
+impl RmwAbsoluteY for Rra {}
+impl RmwIndirectX for Rra {}
+impl RmwIndirectY for Rra {}
@@ -0,0 +1,25 @@
+use crate::{
+    cpu::{BusObserver, executor::Executor},
+    memory::Memory,
+};
+
+pub struct Rts;
+
+impl Rts {
+    pub fn implied<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        // dummy read at PC
+        executor.dummy_read_cycle(executor.cpu.pc);
+        // dummy read from the top of the stack while SP is incremented
+        executor.dummy_stack_read();
+        executor.cpu.sp = executor.cpu.sp.wrapping_add(1);
+
+        let pc_low = executor.stack_read();
+        executor.cpu.sp = executor.cpu.sp.wrapping_add(1);
+        let pc_high = executor.stack_read();
+        executor.cpu.pc = (pc_high as u16) << 8 | pc_low as u16;
+
+        // JSR pushes the address of its last byte, skip past it
+        executor.dummy_read_cycle(executor.cpu.pc);
+        executor.cpu.pc = executor.cpu.pc.wrapping_add(1);
+    }
+}
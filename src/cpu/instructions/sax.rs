@@ -0,0 +1,15 @@
+use crate::cpu::{Cpu, addressing_modes::write::*};
+
+/// Unofficial instruction, stores A AND X
+pub struct Sax;
+
+impl WriteInstruction for Sax {
+    fn instruction(cpu: &Cpu) -> u8 {
+        cpu.a & cpu.x
+    }
+}
+
+impl WriteZeropage for Sax {}
+impl WriteZeropageY for Sax {}
+impl WriteAbsolute for Sax {}
+impl WriteIndirectX for Sax {}
@@ -5,14 +5,32 @@ use crate::cpu::{
 pub struct Sbc;
 
 impl ReadInstruction for Sbc {
+    const DECIMAL_EXTRA_CYCLE: bool = true;
+
     fn instruction(cpu: &mut Cpu, value: u8) {
         let carry = cpu.flags.contains(StatusFlags::CARRY);
         let (result, new_carry) = arithmetic::sub_with_carry(cpu.a, value, carry);
         let overflow = arithmetic::sub_overflows(cpu.a as i8, value as i8, carry);
 
+        let decimal_result = cpu.decimal_mode().then(|| {
+            if cpu.variant.is_cmos() {
+                arithmetic::cmos_decimal_sub_with_carry(cpu.a, value, carry)
+            } else {
+                arithmetic::decimal_sub_with_carry(cpu.a, value, carry)
+            }
+        });
+
         cpu.set_register_with_flags(a_register, result);
         cpu.flags.set(StatusFlags::CARRY, new_carry);
         cpu.flags.set(StatusFlags::OVERFLOW, overflow);
+
+        if let Some(decimal_result) = decimal_result {
+            if cpu.variant.is_cmos() {
+                cpu.set_nz_flags(decimal_result);
+            }
+            // on NMOS all the flags are still based on the binary result
+            cpu.a = decimal_result;
+        }
     }
 }
 
@@ -24,3 +42,4 @@ impl ReadAbsoluteX for Sbc {}
 impl ReadAbsoluteY for Sbc {}
 impl ReadIndirectX for Sbc {}
 impl ReadIndirectY for Sbc {}
+impl ReadZeropageIndirect for Sbc {}
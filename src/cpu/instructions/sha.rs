@@ -0,0 +1,13 @@
+use crate::cpu::{Cpu, addressing_modes::unstable_store::*};
+
+/// Unstable unofficial instruction, stores A AND X AND (high byte of the address + 1)
+pub struct Sha;
+
+impl UnstableStoreInstruction for Sha {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.a & cpu.x
+    }
+}
+
+impl UnstableStoreAbsoluteY for Sha {}
+impl UnstableStoreIndirectY for Sha {}
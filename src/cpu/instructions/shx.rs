@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::unstable_store::*};
+
+/// Unstable unofficial instruction, stores X AND (high byte of the address + 1)
+pub struct Shx;
+
+impl UnstableStoreInstruction for Shx {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.x
+    }
+}
+
+impl UnstableStoreAbsoluteY for Shx {}
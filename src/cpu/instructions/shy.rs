@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::unstable_store::*};
+
+/// Unstable unofficial instruction, stores Y AND (high byte of the address + 1)
+pub struct Shy;
+
+impl UnstableStoreInstruction for Shy {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.y
+    }
+}
+
+impl UnstableStoreAbsoluteX for Shy {}
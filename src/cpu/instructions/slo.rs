@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{Asl, Ora},
+};
+
+/// Unofficial instruction, ASL followed by ORA
+pub struct Slo;
+
+impl RmwInstruction for Slo {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Asl as RmwInstruction>::instruction(cpu, value);
+        <Ora as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Slo {}
+impl RmwZeropageX for Slo {}
+impl RmwAbsolute for Slo {}
+impl RmwAbsoluteX for Slo {}
+impl RmwAbsoluteY for Slo {}
+impl RmwIndirectX for Slo {}
+impl RmwIndirectY for Slo {}
@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::rmw::*};
+
+/// 65C02 instruction, sets bit `BIT` of a zero page value
+pub struct Smb<const BIT: u8>;
+
+impl<const BIT: u8> RmwInstruction for Smb<BIT> {
+    fn instruction(_cpu: &mut Cpu, value: u8) -> u8 {
+        value | 1 << BIT
+    }
+}
+
+impl<const BIT: u8> RmwZeropage for Smb<BIT> {}
@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    addressing_modes::{read::ReadInstruction, rmw::*},
+    instructions::{Eor, Lsr},
+};
+
+/// Unofficial instruction, LSR followed by EOR
+pub struct Sre;
+
+impl RmwInstruction for Sre {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        let result = <Lsr as RmwInstruction>::instruction(cpu, value);
+        <Eor as ReadInstruction>::instruction(cpu, result);
+
+        result
+    }
+}
+
+impl RmwZeropage for Sre {}
+impl RmwZeropageX for Sre {}
+impl RmwAbsolute for Sre {}
+impl RmwAbsoluteX for Sre {}
+impl RmwAbsoluteY for Sre {}
+impl RmwIndirectX for Sre {}
+impl RmwIndirectY for Sre {}
@@ -15,3 +15,4 @@ impl WriteAbsoluteX for Sta {}
 impl WriteAbsoluteY for Sta {}
 impl WriteIndirectX for Sta {}
 impl WriteIndirectY for Sta {}
+impl WriteZeropageIndirect for Sta {}
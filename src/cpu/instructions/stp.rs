@@ -0,0 +1,15 @@
+use crate::{
+    cpu::{BusObserver, executor::Executor},
+    memory::Memory,
+};
+
+/// 65C02 instruction, stops the CPU until it's reset
+pub struct Stp;
+
+impl Stp {
+    pub fn implied<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        executor.dummy_read_cycle(executor.cpu.pc);
+        executor.dummy_read_cycle(executor.cpu.pc);
+        executor.cpu.jammed = true;
+    }
+}
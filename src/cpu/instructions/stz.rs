@@ -0,0 +1,15 @@
+use crate::cpu::{Cpu, addressing_modes::write::*};
+
+/// 65C02 instruction, store zero
+pub struct Stz;
+
+impl WriteInstruction for Stz {
+    fn instruction(_cpu: &Cpu) -> u8 {
+        0
+    }
+}
+
+impl WriteZeropage for Stz {}
+impl WriteZeropageX for Stz {}
+impl WriteAbsolute for Stz {}
+impl WriteAbsoluteX for Stz {}
@@ -0,0 +1,13 @@
+use crate::cpu::{Cpu, addressing_modes::unstable_store::*};
+
+/// Unstable unofficial instruction, sets SP to A AND X, then stores SP AND (high byte of the address + 1)
+pub struct Tas;
+
+impl UnstableStoreInstruction for Tas {
+    fn instruction(cpu: &mut Cpu) -> u8 {
+        cpu.sp = cpu.a & cpu.x;
+        cpu.sp
+    }
+}
+
+impl UnstableStoreAbsoluteY for Tas {}
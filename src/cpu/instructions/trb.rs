@@ -0,0 +1,17 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::rmw::*};
+
+/// 65C02 instruction, clears the bits that are set in A
+///
+/// ZERO is set like in BIT, based on A AND value
+pub struct Trb;
+
+impl RmwInstruction for Trb {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        cpu.flags.set(StatusFlags::ZERO, cpu.a & value == 0);
+
+        value & !cpu.a
+    }
+}
+
+impl RmwZeropage for Trb {}
+impl RmwAbsolute for Trb {}
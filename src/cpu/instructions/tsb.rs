@@ -0,0 +1,17 @@
+use crate::cpu::{Cpu, StatusFlags, addressing_modes::rmw::*};
+
+/// 65C02 instruction, sets the bits that are set in A
+///
+/// ZERO is set like in BIT, based on A AND value
+pub struct Tsb;
+
+impl RmwInstruction for Tsb {
+    fn instruction(cpu: &mut Cpu, value: u8) -> u8 {
+        cpu.flags.set(StatusFlags::ZERO, cpu.a & value == 0);
+
+        value | cpu.a
+    }
+}
+
+impl RmwZeropage for Tsb {}
+impl RmwAbsolute for Tsb {}
@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::read::*, instructions::Sbc};
+
+/// Unofficial opcode that behaves exactly like SBC immediate
+pub struct Usbc;
+
+impl ReadInstruction for Usbc {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        <Sbc as ReadInstruction>::instruction(cpu, value);
+    }
+}
+
+impl ReadImmediate for Usbc {}
@@ -0,0 +1,15 @@
+use crate::{
+    cpu::{BusObserver, executor::Executor},
+    memory::Memory,
+};
+
+/// 65C02 instruction, stops the CPU until an interrupt line is asserted
+pub struct Wai;
+
+impl Wai {
+    pub fn implied<M: Memory, O: BusObserver>(executor: &mut Executor<M, O>) {
+        executor.dummy_read_cycle(executor.cpu.pc);
+        executor.dummy_read_cycle(executor.cpu.pc);
+        executor.cpu.waiting = true;
+    }
+}
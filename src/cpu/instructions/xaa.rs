@@ -0,0 +1,12 @@
+use crate::cpu::{Cpu, addressing_modes::read::*, register_getters::a_register};
+
+/// Unstable unofficial instruction, sets A to (A OR magic constant) AND X AND value
+pub struct Xaa;
+
+impl ReadInstruction for Xaa {
+    fn instruction(cpu: &mut Cpu, value: u8) {
+        cpu.set_register_with_flags(a_register, (cpu.a | cpu.magic_constant.0) & cpu.x & value);
+    }
+}
+
+impl ReadImmediate for Xaa {}
@@ -0,0 +1,83 @@
+use super::{BusObserver, StatusFlags, executor::Executor};
+use crate::memory::Memory;
+
+pub const NMI_VECTOR: u16 = 0xFFFA;
+pub const RESET_VECTOR: u16 = 0xFFFC;
+pub const IRQ_VECTOR: u16 = 0xFFFE;
+
+impl<M: Memory, O: BusObserver> Executor<'_, M, O> {
+    /// Perform the 7 cycle reset sequence
+    ///
+    /// It's the same as the interrupt sequence,
+    /// except that the writes to the stack are turned into reads
+    pub fn reset(&mut self) {
+        self.cpu.jammed = false;
+        self.cpu.waiting = false;
+
+        // the opcode fetch and the operand fetch are turned into dummy reads
+        self.dummy_read_cycle(self.cpu.pc);
+        self.dummy_read_cycle(self.cpu.pc);
+
+        for _ in 0..3 {
+            self.dummy_stack_read();
+            self.cpu.sp = self.cpu.sp.wrapping_sub(1);
+        }
+
+        self.cpu.nmi_pending = false;
+        self.cpu.flags.insert(StatusFlags::INTERRUPT_DISABLE);
+        if self.cpu.variant.is_cmos() {
+            self.cpu.flags.remove(StatusFlags::DECIMAL);
+        }
+        self.cpu.pc = self.read_vector(RESET_VECTOR);
+        self.cpu.interrupt_poll = false;
+    }
+
+    /// Perform the 7 cycle sequence of servicing an NMI or an IRQ
+    pub fn hardware_interrupt(&mut self) {
+        // the opcode fetch and the operand fetch are turned into dummy reads,
+        // PC isn't incremented
+        self.dummy_read_cycle(self.cpu.pc);
+        self.dummy_read_cycle(self.cpu.pc);
+
+        let flags = self.cpu.flags.difference(StatusFlags::BREAK) | StatusFlags::IGNORED;
+        self.push_interrupt_frame(flags);
+    }
+
+    /// Push PC and flags, then jump to the interrupt vector, takes 5 cycles
+    ///
+    /// The vector is selected after the flags are pushed,
+    /// so an NMI that's pending by then will hijack an IRQ or a BRK
+    pub fn push_interrupt_frame(&mut self, flags: StatusFlags) {
+        let [pc_low, pc_high] = self.cpu.pc.to_le_bytes();
+        self.stack_write(pc_high);
+        self.cpu.sp = self.cpu.sp.wrapping_sub(1);
+        self.stack_write(pc_low);
+        self.cpu.sp = self.cpu.sp.wrapping_sub(1);
+        self.stack_write(flags.bits());
+        self.cpu.sp = self.cpu.sp.wrapping_sub(1);
+
+        let vector = if self.cpu.nmi_pending {
+            self.cpu.nmi_pending = false;
+            NMI_VECTOR
+        } else {
+            IRQ_VECTOR
+        };
+
+        self.cpu.flags.insert(StatusFlags::INTERRUPT_DISABLE);
+        if self.cpu.variant.is_cmos() {
+            // the 65C02 also makes sure the handler doesn't run in decimal mode
+            self.cpu.flags.remove(StatusFlags::DECIMAL);
+        }
+        self.cpu.pc = self.read_vector(vector);
+
+        // the first instruction of the handler is always executed before another interrupt
+        self.cpu.interrupt_poll = false;
+    }
+
+    fn read_vector(&mut self, vector: u16) -> u16 {
+        let addr_low = self.read_cycle(vector);
+        let addr_high = self.read_cycle(vector.wrapping_add(1));
+
+        (addr_high as u16) << 8 | addr_low as u16
+    }
+}
@@ -0,0 +1,12 @@
+/// The value ORed into A by the unstable XAA and LXA opcodes
+///
+/// It depends on the chip and even on its temperature,
+/// `$EE` is a common value and the one most test suites expect
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MagicConstant(pub u8);
+
+impl Default for MagicConstant {
+    fn default() -> Self {
+        Self(0xEE)
+    }
+}
@@ -1,6 +1,6 @@
-use num_enum::{FromPrimitive, IntoPrimitive};
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
-#[derive(Debug, Clone, Copy, IntoPrimitive, FromPrimitive, Default)]
+#[derive(Debug, Clone, Copy, IntoPrimitive, TryFromPrimitive)]
 #[non_exhaustive]
 #[repr(u8)]
 pub enum Opcode {
@@ -31,6 +31,17 @@ pub enum Opcode {
     AslAbsolute = 0x0E,
     AslAbsoluteX = 0x1E,
 
+    // B**
+    Bcc = 0x90,
+    Bcs = 0xB0,
+    Beq = 0xF0,
+    Bmi = 0x30,
+    Bne = 0xD0,
+    Bpl = 0x10,
+    Brk = 0x00,
+    Bvc = 0x50,
+    Bvs = 0x70,
+
     // BIT
     BitZeropage = 0x24,
     BitAbsolute = 0x2C,
@@ -91,6 +102,12 @@ pub enum Opcode {
     Inx = 0xE8,
     Iny = 0xC8,
 
+    // JMP
+    JmpAbsolute = 0x4C,
+    JmpIndirect = 0x6C,
+
+    Jsr = 0x20,
+
     // LDA
     LdaImmediate = 0xA9,
     LdaZeropage = 0xA5,
@@ -137,6 +154,8 @@ pub enum Opcode {
     // P**
     Pha = 0x48,
     Php = 0x08,
+    Pla = 0x68,
+    Plp = 0x28,
 
     // ROL
     RolAccumulator = 0x2A,
@@ -152,6 +171,10 @@ pub enum Opcode {
     RorAbsolute = 0x6E,
     RorAbsoluteX = 0x7E,
 
+    // RT*
+    Rti = 0x40,
+    Rts = 0x60,
+
     // SBC
     SbcImmediate = 0xE9,
     SbcZeropage = 0xE5,
@@ -194,6 +217,205 @@ pub enum Opcode {
     Txs = 0x9A,
     Tya = 0x98,
 
-    #[default]
-    Unimplemented = 0x0,
+    // Unofficial opcodes
+
+    // ALR
+    AlrImmediate = 0x4B,
+
+    // ANC
+    AncImmediate = 0x0B,
+    AncAltImmediate = 0x2B,
+
+    // ARR
+    ArrImmediate = 0x6B,
+
+    // AXS
+    AxsImmediate = 0xCB,
+
+    // DCP
+    DcpZeropage = 0xC7,
+    DcpZeropageX = 0xD7,
+    DcpAbsolute = 0xCF,
+    DcpAbsoluteX = 0xDF,
+    DcpAbsoluteY = 0xDB,
+    DcpIndirectX = 0xC3,
+    DcpIndirectY = 0xD3,
+
+    // ISC
+    IscZeropage = 0xE7,
+    IscZeropageX = 0xF7,
+    IscAbsolute = 0xEF,
+    IscAbsoluteX = 0xFF,
+    IscAbsoluteY = 0xFB,
+    IscIndirectX = 0xE3,
+    IscIndirectY = 0xF3,
+
+    // KIL
+    Kil02 = 0x02,
+    Kil12 = 0x12,
+    Kil22 = 0x22,
+    Kil32 = 0x32,
+    Kil42 = 0x42,
+    Kil52 = 0x52,
+    Kil62 = 0x62,
+    Kil72 = 0x72,
+    Kil92 = 0x92,
+    KilB2 = 0xB2,
+    KilD2 = 0xD2,
+    KilF2 = 0xF2,
+
+    // LAX
+    LaxZeropage = 0xA7,
+    LaxZeropageY = 0xB7,
+    LaxAbsolute = 0xAF,
+    LaxAbsoluteY = 0xBF,
+    LaxIndirectX = 0xA3,
+    LaxIndirectY = 0xB3,
+
+    // NOP
+    Nop1A = 0x1A,
+    Nop3A = 0x3A,
+    Nop5A = 0x5A,
+    Nop7A = 0x7A,
+    NopDa = 0xDA,
+    NopFa = 0xFA,
+    Nop80Immediate = 0x80,
+    Nop82Immediate = 0x82,
+    Nop89Immediate = 0x89,
+    NopC2Immediate = 0xC2,
+    NopE2Immediate = 0xE2,
+    Nop04Zeropage = 0x04,
+    Nop44Zeropage = 0x44,
+    Nop64Zeropage = 0x64,
+    Nop14ZeropageX = 0x14,
+    Nop34ZeropageX = 0x34,
+    Nop54ZeropageX = 0x54,
+    Nop74ZeropageX = 0x74,
+    NopD4ZeropageX = 0xD4,
+    NopF4ZeropageX = 0xF4,
+    Nop0CAbsolute = 0x0C,
+    Nop1CAbsoluteX = 0x1C,
+    Nop3CAbsoluteX = 0x3C,
+    Nop5CAbsoluteX = 0x5C,
+    Nop7CAbsoluteX = 0x7C,
+    NopDcAbsoluteX = 0xDC,
+    NopFcAbsoluteX = 0xFC,
+
+    // RLA
+    RlaZeropage = 0x27,
+    RlaZeropageX = 0x37,
+    RlaAbsolute = 0x2F,
+    RlaAbsoluteX = 0x3F,
+    RlaAbsoluteY = 0x3B,
+    RlaIndirectX = 0x23,
+    RlaIndirectY = 0x33,
+
+    // RRA
+    RraZeropage = 0x67,
+    RraZeropageX = 0x77,
+    RraAbsolute = 0x6F,
+    RraAbsoluteX = 0x7F,
+    RraAbsoluteY = 0x7B,
+    RraIndirectX = 0x63,
+    RraIndirectY = 0x73,
+
+    // SAX
+    SaxZeropage = 0x87,
+    SaxZeropageY = 0x97,
+    SaxAbsolute = 0x8F,
+    SaxIndirectX = 0x83,
+
+    // SLO
+    SloZeropage = 0x07,
+    SloZeropageX = 0x17,
+    SloAbsolute = 0x0F,
+    SloAbsoluteX = 0x1F,
+    SloAbsoluteY = 0x1B,
+    SloIndirectX = 0x03,
+    SloIndirectY = 0x13,
+
+    // SRE
+    SreZeropage = 0x47,
+    SreZeropageX = 0x57,
+    SreAbsolute = 0x4F,
+    SreAbsoluteX = 0x5F,
+    SreAbsoluteY = 0x5B,
+    SreIndirectX = 0x43,
+    SreIndirectY = 0x53,
+
+    UsbcImmediate = 0xEB,
+
+    // Unstable unofficial opcodes
+
+    // LAS
+    #[cfg(feature = "unstable-opcodes")]
+    LasAbsoluteY = 0xBB,
+
+    // LXA
+    #[cfg(feature = "unstable-opcodes")]
+    LxaImmediate = 0xAB,
+
+    // SHA
+    #[cfg(feature = "unstable-opcodes")]
+    ShaAbsoluteY = 0x9F,
+    #[cfg(feature = "unstable-opcodes")]
+    ShaIndirectY = 0x93,
+
+    // SHX
+    #[cfg(feature = "unstable-opcodes")]
+    ShxAbsoluteY = 0x9E,
+
+    // SHY
+    #[cfg(feature = "unstable-opcodes")]
+    ShyAbsoluteX = 0x9C,
+
+    // TAS
+    #[cfg(feature = "unstable-opcodes")]
+    TasAbsoluteY = 0x9B,
+
+    // XAA
+    #[cfg(feature = "unstable-opcodes")]
+    XaaImmediate = 0x8B,
+}
+
+/// Length of an instruction in bytes, including the opcode
+///
+/// Derived from the addressing mode encoded in the opcode,
+/// so it's also valid for opcodes that aren't implemented
+pub fn instruction_length(opcode: u8) -> u16 {
+    let mode = opcode >> 2 & 0b111;
+
+    match opcode & 0b11 {
+        // these columns use the same addressing modes
+        0b01 | 0b11 => match mode {
+            // (zp,X), zp, #imm, (zp),Y, zp,X
+            0 | 1 | 2 | 4 | 5 => 2,
+            // abs, abs,Y, abs,X
+            _ => 3,
+        },
+        0b10 => match mode {
+            // #imm, zp, zp,X/Y
+            0 | 1 | 5 => 2,
+            // abs, abs,X/Y
+            3 | 7 => 3,
+            // accumulator, implied, KIL
+            _ => 1,
+        },
+        _ => match opcode {
+            // BRK skips a padding byte
+            0x00 => 2,
+            // JSR
+            0x20 => 3,
+            // RTI, RTS
+            0x40 | 0x60 => 1,
+            _ => match mode {
+                // #imm, zp, relative, zp,X
+                0 | 1 | 4 | 5 => 2,
+                // abs, abs,X
+                3 | 7 => 3,
+                // implied
+                _ => 1,
+            },
+        },
+    }
 }
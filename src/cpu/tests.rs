@@ -1,12 +1,26 @@
 #![allow(clippy::arithmetic_side_effects)]
-use crate::memory::{Memory, ram::Ram};
+use crate::{
+    cpu::{Cpu, StatusFlags, Variant, opcode::Opcode},
+    memory::{Memory, ram::Ram},
+};
+use addressing_modes::OPCODE_ADDR;
 
 mod addressing_modes;
+mod bus_observer;
+mod cmos;
 mod flags;
+mod illegal_opcode;
+mod interrupts;
+mod open_bus;
 mod test_args;
+mod tick;
 
 mod instructions;
 
+/// RAM is mirrored across the whole address space,
+/// so that the interrupt vectors are accessible
+const TEST_MEMORY_MASK: u16 = 0x07FF;
+
 #[derive(Debug, Clone)]
 struct TestMemory {
     ram: Ram,
@@ -20,10 +34,37 @@ impl TestMemory {
 
 impl Memory for TestMemory {
     fn load(&mut self, address: u16) -> u8 {
-        self.ram.load(address)
+        self.ram.load(address & TEST_MEMORY_MASK)
     }
 
     fn store(&mut self, address: u16, value: u8) {
-        self.ram.store(address, value)
+        self.ram.store(address & TEST_MEMORY_MASK, value)
+    }
+
+    fn peek(&self, address: u16) -> Option<u8> {
+        self.ram.peek(address & TEST_MEMORY_MASK)
     }
 }
+
+/// Execute an immediate instruction with the DECIMAL flag set, `a` in the accumulator and `b` as the operand
+fn execute_decimal_immediate(opcode: Opcode, variant: Variant, a: u8, b: u8, carry: bool) -> Cpu {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.variant = variant;
+    cpu.a = a;
+    cpu.flags.insert(StatusFlags::DECIMAL);
+    cpu.flags.set(StatusFlags::CARRY, carry);
+    memory.store(OPCODE_ADDR, opcode as u8);
+    memory.store(OPCODE_ADDR + 1, b);
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    cpu
+}
+
+/// Convert a value below 100 to packed BCD
+fn to_bcd(value: u8) -> u8 {
+    ((value / 10) << 4) | (value % 10)
+}
@@ -1,10 +1,18 @@
 mod prepare;
 
+pub use prepare::OPCODE_ADDR;
+
+pub mod combined;
 pub mod implied;
+pub mod jump;
 pub mod read;
+pub mod relative;
 pub mod rmw;
 pub mod write;
+pub mod stack_pull;
 pub mod stack_push;
+#[cfg(feature = "unstable-opcodes")]
+pub mod unstable_store;
 
 /// Implement addressing mode test traits for the given instruction
 /// and generate test functions for them
@@ -170,6 +178,23 @@ macro_rules! test_addressing_modes {
         }
     };
 
+    (
+        @inner write_test {
+            $instruction:ident,
+            $instruction_type:ident,
+            Indirect,
+        }
+    ) => {
+        test_addressing_modes! {
+            @inner test_fns {
+                $instruction,
+                $instruction_type,
+                Indirect,
+                [ indirect, indirect_page_wrap ]
+            }
+        }
+    };
+
     (
         @inner write_test {
             $instruction:ident,
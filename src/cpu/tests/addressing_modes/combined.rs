@@ -0,0 +1,230 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{
+            TestMemory,
+            addressing_modes::{
+                prepare::{AddressingMode, OPCODE_ADDR},
+                read::TestReadArgs,
+            },
+        },
+    },
+    memory::Memory,
+};
+
+/// Test harness for unofficial instructions that combine an RMW instruction with a read instruction
+///
+/// They use RMW addressing modes, but unlike the plain RMW instructions
+/// they also change the registers depending on the value written back
+pub trait TestCombinedInstruction {
+    type Args: TestReadArgs;
+
+    fn prepare(
+        cpu: &mut Cpu,
+        arg: u8,
+        additional_args: <Self::Args as TestReadArgs>::AdditionalArgs,
+    );
+
+    /// Verify the state of the CPU and return the value that's expected to be written back
+    fn verify(
+        cpu: &Cpu,
+        arg: u8,
+        additional_args: <Self::Args as TestReadArgs>::AdditionalArgs,
+    ) -> u8;
+}
+
+pub trait TestCombinedZeropage: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_zeropage() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::Zeropage,
+            expected_clock_cycles: 5,
+        });
+    }
+}
+
+pub trait TestCombinedZeropageX: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_zeropage_x() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::ZeropageX,
+            expected_clock_cycles: 6,
+        });
+    }
+
+    fn test_zeropage_x_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::ZeropageXOverflow,
+            expected_clock_cycles: 6,
+        });
+    }
+}
+
+pub trait TestCombinedAbsolute: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_absolute() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::Absolute,
+            expected_clock_cycles: 6,
+        });
+    }
+}
+
+pub trait TestCombinedAbsoluteX: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_absolute_x() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteX,
+            expected_clock_cycles: 7,
+        });
+    }
+
+    fn test_absolute_x_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteXOverflow,
+            expected_clock_cycles: 7,
+        });
+    }
+}
+
+pub trait TestCombinedAbsoluteY: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_absolute_y() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteY,
+            expected_clock_cycles: 7,
+        });
+    }
+
+    fn test_absolute_y_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteYOverflow,
+            expected_clock_cycles: 7,
+        });
+    }
+}
+
+pub trait TestCombinedIndirectX: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_indirect_x() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectX,
+            expected_clock_cycles: 8,
+        });
+    }
+
+    fn test_indirect_x_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectXOverflow,
+            expected_clock_cycles: 8,
+        });
+    }
+
+    fn test_indirect_x_page_split() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectXPageSplit,
+            expected_clock_cycles: 8,
+        });
+    }
+}
+
+pub trait TestCombinedIndirectY: TestCombinedInstruction {
+    const OPCODE: Opcode;
+
+    fn test_indirect_y() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectY,
+            expected_clock_cycles: 8,
+        });
+    }
+
+    fn test_indirect_y_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectYOverflow,
+            expected_clock_cycles: 8,
+        });
+    }
+
+    fn test_indirect_y_page_split() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectYPageSplit,
+            expected_clock_cycles: 8,
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TestInstructionOptions {
+    opcode: Opcode,
+    addressing_mode: AddressingMode,
+    expected_clock_cycles: u64,
+}
+
+fn test_instruction<I: TestCombinedInstruction + ?Sized>(
+    TestInstructionOptions {
+        opcode,
+        addressing_mode,
+        expected_clock_cycles,
+    }: TestInstructionOptions,
+) {
+    for arg in I::Args::args() {
+        for additional_args in I::Args::additional_args() {
+            let mut cpu = Cpu::new();
+            let mut memory = TestMemory::new();
+
+            cpu.pc = OPCODE_ADDR;
+            I::prepare(&mut cpu, arg, additional_args);
+            memory.store(OPCODE_ADDR, opcode as u8);
+
+            let mut executor = Executor {
+                cpu: &mut cpu,
+                memory: &mut memory,
+                observer: (),
+            };
+
+            addressing_mode.prepare(&mut executor);
+            executor.memory.store(addressing_mode.value_addr(), arg);
+
+            executor.execute_next_instruction().unwrap();
+            assert_eq!(
+                cpu.clock_cycle_count, expected_clock_cycles,
+                "instruction {opcode:?} must take {expected_clock_cycles} clock cycles"
+            );
+            let instruction_length = addressing_mode.instruction_length();
+            assert_eq!(
+                cpu.pc,
+                OPCODE_ADDR + instruction_length,
+                "after instruction {opcode:?} PC must be incremented {instruction_length} times"
+            );
+
+            let expected_value = I::verify(&cpu, arg, additional_args);
+            assert_eq!(
+                memory.load(addressing_mode.value_addr()),
+                expected_value,
+                "value written back must match the expected value"
+            );
+        }
+    }
+}
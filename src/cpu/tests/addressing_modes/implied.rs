@@ -29,9 +29,10 @@ pub trait TestImplied: TestImpliedInstruction {
             let mut executor = Executor {
                 cpu: &mut cpu,
                 memory: &mut memory,
+                observer: (),
             };
 
-            executor.execute_next_instruction();
+            executor.execute_next_instruction().unwrap();
 
             assert_eq!(
                 executor.cpu.clock_cycle_count,
@@ -0,0 +1,113 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::prepare::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const TARGETS: [u16; 5] = [0x0000, 0x0425, 0x1234, 0x80FF, 0xFFFF];
+
+const INDIRECT_PTR: u16 = 0x0330;
+const INDIRECT_PTR_PAGE_WRAP: u16 = 0x03FF;
+
+pub trait TestJumpAbsolute {
+    const OPCODE: Opcode;
+
+    fn test_absolute() {
+        for target in TARGETS {
+            let [target_low, target_high] = target.to_le_bytes();
+            test_instruction(TestInstructionOptions {
+                opcode: Self::OPCODE,
+                args: [target_low, target_high],
+                memory_writes: &[],
+                expected_pc: target,
+                expected_clock_cycles: 3,
+            });
+        }
+    }
+}
+
+pub trait TestJumpIndirect {
+    const OPCODE: Opcode;
+
+    fn test_indirect() {
+        for target in TARGETS {
+            let [target_low, target_high] = target.to_le_bytes();
+            test_instruction(TestInstructionOptions {
+                opcode: Self::OPCODE,
+                args: INDIRECT_PTR.to_le_bytes(),
+                memory_writes: &[(INDIRECT_PTR, target_low), (INDIRECT_PTR + 1, target_high)],
+                expected_pc: target,
+                expected_clock_cycles: 5,
+            });
+        }
+    }
+
+    fn test_indirect_page_wrap() {
+        for target in TARGETS {
+            let [target_low, target_high] = target.to_le_bytes();
+            test_instruction(TestInstructionOptions {
+                opcode: Self::OPCODE,
+                args: INDIRECT_PTR_PAGE_WRAP.to_le_bytes(),
+                memory_writes: &[
+                    (INDIRECT_PTR_PAGE_WRAP, target_low),
+                    // the high byte must be fetched from the start of the same page
+                    (INDIRECT_PTR_PAGE_WRAP & 0xFF00, target_high),
+                    (INDIRECT_PTR_PAGE_WRAP + 1, !target_high),
+                ],
+                expected_pc: target,
+                expected_clock_cycles: 5,
+            });
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TestInstructionOptions<'a> {
+    opcode: Opcode,
+    args: [u8; 2],
+    memory_writes: &'a [(u16, u8)],
+    expected_pc: u16,
+    expected_clock_cycles: u64,
+}
+
+fn test_instruction(
+    TestInstructionOptions {
+        opcode,
+        args,
+        memory_writes,
+        expected_pc,
+        expected_clock_cycles,
+    }: TestInstructionOptions,
+) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    memory.store(OPCODE_ADDR, opcode as u8);
+    memory.store(OPCODE_ADDR + 1, args[0]);
+    memory.store(OPCODE_ADDR + 2, args[1]);
+    for &(addr, value) in memory_writes {
+        memory.store(addr, value);
+    }
+
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+
+    executor.execute_next_instruction().unwrap();
+
+    assert_eq!(
+        cpu.clock_cycle_count, expected_clock_cycles,
+        "instruction {opcode:?} must take {expected_clock_cycles} clock cycles"
+    );
+    assert_eq!(
+        cpu.pc, expected_pc,
+        "after instruction {opcode:?} PC must be {expected_pc:#06X}"
+    );
+}
@@ -56,7 +56,7 @@ impl AddressingMode {
         }
     }
 
-    pub fn prepare(self, Executor { cpu, memory }: &mut Executor<TestMemory>) {
+    pub fn prepare(self, Executor { cpu, memory, .. }: &mut Executor<TestMemory>) {
         match self {
             AddressingMode::Accumulator => {}
             AddressingMode::Immediate => {}
@@ -228,12 +228,13 @@ fn test_instruction<I: TestReadInstruction + ?Sized>(
             let mut executor = Executor {
                 cpu: &mut cpu,
                 memory: &mut memory,
+                observer: (),
             };
 
             addressing_mode.prepare(&mut executor);
             executor.memory.store(addressing_mode.value_addr(), arg);
 
-            executor.execute_next_instruction();
+            executor.execute_next_instruction().unwrap();
             assert_eq!(
                 cpu.clock_cycle_count, expected_clock_cycles,
                 "instruction {opcode:?} must take {expected_clock_cycles} clock cycles"
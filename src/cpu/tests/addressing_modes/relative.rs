@@ -0,0 +1,112 @@
+use crate::{
+    cpu::{Cpu, StatusFlags, executor::Executor, opcode::Opcode, tests::TestMemory},
+    memory::Memory,
+};
+
+pub trait TestRelativeInstruction {
+    /// Set up the flags so that the branch is taken if `branch` is true
+    fn prepare(cpu: &mut Cpu, branch: bool);
+}
+
+#[derive(Debug, Clone, Copy)]
+struct BranchCase {
+    opcode_addr: u16,
+    offset: u8,
+    /// Clock cycles the instruction takes if the branch is taken
+    expected_clock_cycles: u64,
+}
+
+const BRANCH_CASES: [BranchCase; 5] = [
+    // forward, same page
+    BranchCase {
+        opcode_addr: 0x0240,
+        offset: 0x20,
+        expected_clock_cycles: 3,
+    },
+    // backward, same page
+    BranchCase {
+        opcode_addr: 0x0240,
+        offset: -0x10i8 as u8,
+        expected_clock_cycles: 3,
+    },
+    // branching to itself
+    BranchCase {
+        opcode_addr: 0x0240,
+        offset: -2i8 as u8,
+        expected_clock_cycles: 3,
+    },
+    // forward, crossing into the next page
+    BranchCase {
+        opcode_addr: 0x02F0,
+        offset: 0x20,
+        expected_clock_cycles: 4,
+    },
+    // backward, crossing into the previous page
+    BranchCase {
+        opcode_addr: 0x0210,
+        offset: -0x20i8 as u8,
+        expected_clock_cycles: 4,
+    },
+];
+
+pub trait TestRelative: TestRelativeInstruction {
+    const OPCODE: Opcode;
+
+    fn test_relative() {
+        const INSTRUCTION_LENGTH: u16 = 2;
+
+        for flags in u8::MIN..u8::MAX {
+            for branch in [false, true] {
+                for BranchCase {
+                    opcode_addr,
+                    offset,
+                    expected_clock_cycles,
+                } in BRANCH_CASES
+                {
+                    let mut cpu = Cpu::new();
+                    let mut memory = TestMemory::new();
+
+                    cpu.pc = opcode_addr;
+                    cpu.flags = StatusFlags::from_bits_truncate(flags);
+                    Self::prepare(&mut cpu, branch);
+                    let flags_before = cpu.flags;
+
+                    memory.store(opcode_addr, Self::OPCODE as u8);
+                    memory.store(opcode_addr + 1, offset);
+
+                    let mut executor = Executor {
+                        cpu: &mut cpu,
+                        memory: &mut memory,
+                        observer: (),
+                    };
+
+                    executor.execute_next_instruction().unwrap();
+
+                    let next_instruction_addr = opcode_addr + INSTRUCTION_LENGTH;
+                    let (expected_pc, expected_clock_cycles) = if branch {
+                        (
+                            next_instruction_addr.wrapping_add_signed(offset as i8 as i16),
+                            expected_clock_cycles,
+                        )
+                    } else {
+                        (next_instruction_addr, 2)
+                    };
+
+                    assert_eq!(
+                        cpu.clock_cycle_count,
+                        expected_clock_cycles,
+                        "instruction {:?} must take {expected_clock_cycles} clock cycles",
+                        Self::OPCODE,
+                    );
+                    assert_eq!(
+                        cpu.pc,
+                        expected_pc,
+                        "after instruction {:?} PC must be {expected_pc:#06X}",
+                        Self::OPCODE,
+                    );
+                    assert_eq!(cpu.flags, flags_before, "branches must not change flags");
+                }
+            }
+        }
+    }
+}
@@ -116,6 +116,7 @@ fn test_instruction<I: TestRmwInstruction + ?Sized>(
             let mut executor = Executor {
                 cpu: &mut cpu,
                 memory: &mut memory,
+                observer: (),
             };
 
             if addressing_mode == AddressingMode::Accumulator {
@@ -125,7 +126,7 @@ fn test_instruction<I: TestRmwInstruction + ?Sized>(
             }
 
             addressing_mode.prepare(&mut executor);
-            executor.execute_next_instruction();
+            executor.execute_next_instruction().unwrap();
 
             let expected_value = I::verify(executor.cpu, arg, carry);
 
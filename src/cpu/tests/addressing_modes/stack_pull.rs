@@ -0,0 +1,67 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::prepare::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+pub trait TestStackPullInstruction {
+    fn prepare(cpu: &mut Cpu, arg: u8);
+
+    fn verify(cpu: &Cpu, value_pulled: u8);
+}
+
+pub trait TestStackPull: TestStackPullInstruction {
+    const OPCODE: Opcode;
+
+    fn test_stack_pull() {
+        const EXPECTED_CLOCK_CYCLES: u64 = 4;
+        const INSTRUCTION_LENGTH: u16 = 1;
+        const SP: u8 = 0xF7;
+
+        for arg in 0..u8::MAX {
+            let mut cpu = Cpu::new();
+            let mut memory = TestMemory::new();
+
+            cpu.pc = OPCODE_ADDR;
+            Self::prepare(&mut cpu, arg);
+            cpu.sp = SP;
+            memory.store(OPCODE_ADDR, Self::OPCODE as u8);
+            memory.store(0x0100 | SP.wrapping_add(1) as u16, arg);
+
+            let mut executor = Executor {
+                cpu: &mut cpu,
+                memory: &mut memory,
+                observer: (),
+            };
+
+            executor.execute_next_instruction().unwrap();
+
+            assert_eq!(
+                executor.cpu.clock_cycle_count,
+                EXPECTED_CLOCK_CYCLES,
+                "instruction {:?} must take {EXPECTED_CLOCK_CYCLES} clock cycles",
+                Self::OPCODE,
+            );
+
+            assert_eq!(
+                executor.cpu.pc,
+                OPCODE_ADDR + INSTRUCTION_LENGTH,
+                "after instruction {:?} PC must be incremented {INSTRUCTION_LENGTH} times",
+                Self::OPCODE
+            );
+
+            assert_eq!(
+                executor.cpu.sp,
+                SP.wrapping_add(1),
+                "instruction {:?} must increment SP",
+                Self::OPCODE
+            );
+
+            Self::verify(executor.cpu, arg);
+        }
+    }
+}
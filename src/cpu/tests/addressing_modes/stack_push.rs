@@ -33,9 +33,10 @@ pub trait TestStackPush: TestStackPushInstruction {
             let mut executor = Executor {
                 cpu: &mut cpu,
                 memory: &mut memory,
+                observer: (),
             };
 
-            executor.execute_next_instruction();
+            executor.execute_next_instruction().unwrap();
 
             assert_eq!(
                 executor.cpu.clock_cycle_count,
@@ -53,7 +54,7 @@ pub trait TestStackPush: TestStackPushInstruction {
 
             let value_pushed = executor
                 .memory
-                .load(0x100 << 8 | executor.cpu.sp.wrapping_add(1) as u16);
+                .load(0x0100 | executor.cpu.sp.wrapping_add(1) as u16);
 
             Self::verify(executor.cpu, value_pushed);
         }
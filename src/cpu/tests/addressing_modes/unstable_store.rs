@@ -0,0 +1,158 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{
+            TestMemory,
+            addressing_modes::prepare::{AddressingMode, OPCODE_ADDR},
+        },
+    },
+    memory::Memory,
+};
+
+pub trait TestUnstableStoreInstruction {
+    fn prepare(cpu: &mut Cpu, arg: u8);
+
+    /// The value before it's ANDed with the high byte of the address + 1
+    fn expected_value(cpu: &Cpu) -> u8;
+}
+
+pub trait TestUnstableStoreAbsoluteX: TestUnstableStoreInstruction {
+    const OPCODE: Opcode;
+
+    fn test_absolute_x() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteX,
+            expected_clock_cycles: 5,
+        });
+    }
+
+    fn test_absolute_x_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteXOverflow,
+            expected_clock_cycles: 5,
+        });
+    }
+}
+
+pub trait TestUnstableStoreAbsoluteY: TestUnstableStoreInstruction {
+    const OPCODE: Opcode;
+
+    fn test_absolute_y() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteY,
+            expected_clock_cycles: 5,
+        });
+    }
+
+    fn test_absolute_y_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::AbsoluteYOverflow,
+            expected_clock_cycles: 5,
+        });
+    }
+}
+
+pub trait TestUnstableStoreIndirectY: TestUnstableStoreInstruction {
+    const OPCODE: Opcode;
+
+    fn test_indirect_y() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectY,
+            expected_clock_cycles: 6,
+        });
+    }
+
+    fn test_indirect_y_overflow() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectYOverflow,
+            expected_clock_cycles: 6,
+        });
+    }
+
+    fn test_indirect_y_page_split() {
+        test_instruction::<Self>(TestInstructionOptions {
+            opcode: Self::OPCODE,
+            addressing_mode: AddressingMode::IndirectYPageSplit,
+            expected_clock_cycles: 6,
+        });
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct TestInstructionOptions {
+    opcode: Opcode,
+    addressing_mode: AddressingMode,
+    expected_clock_cycles: u64,
+}
+
+fn test_instruction<I: TestUnstableStoreInstruction + ?Sized>(
+    TestInstructionOptions {
+        opcode,
+        addressing_mode,
+        expected_clock_cycles,
+    }: TestInstructionOptions,
+) {
+    // the overflow variants are the ones where adding the index crosses a page
+    let page_crossed = matches!(
+        addressing_mode,
+        AddressingMode::AbsoluteXOverflow
+            | AddressingMode::AbsoluteYOverflow
+            | AddressingMode::IndirectYOverflow
+    );
+
+    let [addr_low, addr_high] = addressing_mode.value_addr().to_le_bytes();
+    let base_addr_high = if page_crossed {
+        addr_high - 1
+    } else {
+        addr_high
+    };
+
+    for arg in u8::MIN..u8::MAX {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        I::prepare(&mut cpu, arg);
+        memory.store(OPCODE_ADDR, opcode as u8);
+
+        let mut executor = Executor {
+            cpu: &mut cpu,
+            memory: &mut memory,
+            observer: (),
+        };
+
+        addressing_mode.prepare(&mut executor);
+        executor.execute_next_instruction().unwrap();
+
+        let expected_value = I::expected_value(&cpu) & (base_addr_high + 1);
+        let expected_addr = if page_crossed {
+            u16::from_le_bytes([addr_low, expected_value])
+        } else {
+            addressing_mode.value_addr()
+        };
+
+        assert_eq!(
+            memory.load(expected_addr),
+            expected_value,
+            "value must be ANDed with the high byte of the address + 1 and written to {expected_addr:#06X}"
+        );
+        assert_eq!(
+            cpu.clock_cycle_count, expected_clock_cycles,
+            "instruction {opcode:?} must take {expected_clock_cycles} clock cycles"
+        );
+        let instruction_length = addressing_mode.instruction_length();
+        assert_eq!(
+            cpu.pc,
+            OPCODE_ADDR + instruction_length,
+            "after instruction {opcode:?} PC must be incremented {instruction_length} times"
+        );
+    }
+}
@@ -201,10 +201,11 @@ fn test_instruction<I: TestWriteInstruction + ?Sized>(
         let mut executor = Executor {
             cpu: &mut cpu,
             memory: &mut memory,
+            observer: (),
         };
 
         addressing_mode.prepare(&mut executor);
-        executor.execute_next_instruction();
+        executor.execute_next_instruction().unwrap();
         assert_eq!(
             memory.load(addressing_mode.value_addr()),
             I::expected_value(&cpu),
@@ -0,0 +1,220 @@
+use crate::{
+    cpu::{
+        BusCycle, BusDirection,
+        BusDirection::{Read, Write},
+        Cpu, IllegalOpcodePolicy, StatusFlags, Variant,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::{
+        AccessKind::{self, Dummy, Real},
+        Memory,
+    },
+};
+
+fn prepare(variant: Variant, program: &[u8]) -> (Cpu, TestMemory) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.variant = variant;
+    cpu.pc = OPCODE_ADDR;
+    cpu.flags = StatusFlags::IGNORED;
+
+    for (addr, &byte) in (OPCODE_ADDR..).zip(program) {
+        memory.store(addr, byte);
+    }
+
+    (cpu, memory)
+}
+
+/// Shorthand for a bus cycle, leaves out the cycle number which is checked separately
+fn access(address: u16, value: u8, direction: BusDirection, kind: AccessKind) -> BusCycle {
+    BusCycle {
+        cycle: 0,
+        address,
+        value,
+        direction,
+        kind,
+    }
+}
+
+/// Check the cycle numbers are consecutive and compare the rest of the trace
+fn assert_trace(trace: &[BusCycle], expected: &[BusCycle]) {
+    for (i, cycle) in trace.iter().enumerate() {
+        assert_eq!(cycle.cycle, i as u64, "cycles must be numbered in order");
+    }
+
+    let trace: Vec<_> = trace
+        .iter()
+        .map(|cycle| BusCycle { cycle: 0, ..*cycle })
+        .collect();
+    assert_eq!(trace, expected);
+}
+
+#[test]
+fn zeropage_indexed() {
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, &[Opcode::LdaZeropageX as u8, 0x10]);
+    cpu.x = 0x05;
+    memory.store(0x15, 0x42);
+
+    let mut trace = Vec::new();
+    cpu.execute_next_instruction_observed(&mut memory, &mut trace)
+        .unwrap();
+
+    assert_trace(
+        &trace,
+        &[
+            access(OPCODE_ADDR, Opcode::LdaZeropageX as u8, Read, Real),
+            access(OPCODE_ADDR + 1, 0x10, Read, Real),
+            access(0x10, 0x00, Read, Dummy),
+            access(0x15, 0x42, Read, Real),
+        ],
+    );
+}
+
+#[test]
+fn rmw_dummy_write() {
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, &[Opcode::AslZeropage as u8, 0x10]);
+    memory.store(0x10, 0x21);
+
+    let mut trace = Vec::new();
+    cpu.execute_next_instruction_observed(&mut memory, &mut trace)
+        .unwrap();
+
+    assert_trace(
+        &trace,
+        &[
+            access(OPCODE_ADDR, Opcode::AslZeropage as u8, Read, Real),
+            access(OPCODE_ADDR + 1, 0x10, Read, Real),
+            access(0x10, 0x21, Read, Real),
+            access(0x10, 0x21, Write, Dummy),
+            access(0x10, 0x42, Write, Real),
+        ],
+    );
+}
+
+#[test]
+fn rmw_dummy_read_on_cmos() {
+    let (mut cpu, mut memory) = prepare(Variant::Wdc65C02, &[Opcode::AslZeropage as u8, 0x10]);
+    memory.store(0x10, 0x21);
+
+    let mut trace = Vec::new();
+    cpu.execute_next_instruction_observed(&mut memory, &mut trace)
+        .unwrap();
+
+    assert_eq!(
+        trace[3],
+        BusCycle {
+            cycle: 3,
+            address: 0x10,
+            value: 0x21,
+            direction: Read,
+            kind: Dummy,
+        }
+    );
+}
+
+#[test]
+fn page_crossing_read() {
+    let (mut cpu, mut memory) = prepare(
+        Variant::Ricoh2A03,
+        &[Opcode::LdaAbsoluteX as u8, 0xF0, 0x03],
+    );
+    cpu.x = 0x20;
+    memory.store(0x0410, 0x42);
+
+    let mut trace = Vec::new();
+    cpu.execute_next_instruction_observed(&mut memory, &mut trace)
+        .unwrap();
+
+    assert_trace(
+        &trace,
+        &[
+            access(OPCODE_ADDR, Opcode::LdaAbsoluteX as u8, Read, Real),
+            access(OPCODE_ADDR + 1, 0xF0, Read, Real),
+            access(OPCODE_ADDR + 2, 0x03, Read, Real),
+            access(0x0310, 0x00, Read, Dummy),
+            access(0x0410, 0x42, Read, Real),
+        ],
+    );
+}
+
+#[test]
+fn tick_observes_each_cycle_once() {
+    let program = [Opcode::IncAbsoluteX as u8, 0xF0, 0x03];
+
+    let (mut executed, mut executed_memory) = prepare(Variant::Ricoh2A03, &program);
+    executed.x = 0x20;
+    let mut expected = Vec::new();
+    executed
+        .execute_next_instruction_observed(&mut executed_memory, &mut expected)
+        .unwrap();
+
+    let (mut ticked, mut ticked_memory) = prepare(Variant::Ricoh2A03, &program);
+    ticked.x = 0x20;
+    let mut trace = Vec::new();
+    for i in 1..=expected.len() {
+        ticked
+            .tick_observed(&mut ticked_memory, &mut trace)
+            .unwrap();
+        assert_eq!(
+            trace.len(),
+            i,
+            "every tick must observe a single bus access"
+        );
+    }
+
+    assert_eq!(trace, expected);
+}
+
+/// Memory that only records the kinds of the accesses made to it
+struct KindMemory {
+    memory: TestMemory,
+    kinds: Vec<AccessKind>,
+}
+
+impl Memory for KindMemory {
+    fn load(&mut self, _address: u16) -> u8 {
+        unreachable!("the CPU must load through load_access");
+    }
+
+    fn store(&mut self, _address: u16, _value: u8) {
+        unreachable!("the CPU must store through store_access");
+    }
+
+    fn load_access(&mut self, address: u16, kind: AccessKind, _open_bus: u8) -> u8 {
+        self.kinds.push(kind);
+        self.memory.load(address)
+    }
+
+    fn store_access(&mut self, address: u16, value: u8, kind: AccessKind) {
+        self.kinds.push(kind);
+        self.memory.store(address, value);
+    }
+}
+
+#[test]
+fn memory_receives_access_kinds() {
+    for variant in [Variant::Ricoh2A03, Variant::Wdc65C02] {
+        for opcode in u8::MIN..=u8::MAX {
+            let (mut cpu, memory) = prepare(variant, &[opcode, 0xF0, 0x03]);
+            cpu.x = 0x20;
+            cpu.y = 0x20;
+            cpu.illegal_opcode_policy = IllegalOpcodePolicy::Nop;
+            let mut memory = KindMemory {
+                memory,
+                kinds: Vec::new(),
+            };
+
+            let mut trace = Vec::new();
+            cpu.execute_next_instruction_observed(&mut memory, &mut trace)
+                .unwrap();
+
+            let observed: Vec<_> = trace.iter().map(|cycle| cycle.kind).collect();
+            assert_eq!(
+                memory.kinds, observed,
+                "opcode {opcode:#04X} on {variant:?} must pass the same kinds to memory as to the observer"
+            );
+        }
+    }
+}
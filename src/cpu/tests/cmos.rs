@@ -0,0 +1,358 @@
+use crate::{
+    cpu::{
+        Cpu, StatusFlags, Variant,
+        cmos_opcode::CmosOpcode,
+        interrupts::IRQ_VECTOR,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+/// Clock cycles each opcode takes with all registers, flags and memory cleared,
+/// so no pages are crossed and only the branches that test for a clear flag or bit are taken
+#[rustfmt::skip]
+const CLOCK_CYCLES: [u64; 256] = [
+//  0  1  2  3  4  5  6  7  8  9  A  B  C  D  E  F
+    7, 6, 2, 1, 5, 3, 5, 5, 3, 2, 2, 1, 6, 4, 6, 6, // 0
+    3, 5, 5, 1, 5, 4, 6, 5, 2, 4, 2, 1, 6, 4, 6, 6, // 1
+    6, 6, 2, 1, 3, 3, 5, 5, 4, 2, 2, 1, 4, 4, 6, 6, // 2
+    2, 5, 5, 1, 4, 4, 6, 5, 2, 4, 2, 1, 4, 4, 6, 6, // 3
+    6, 6, 2, 1, 3, 3, 5, 5, 3, 2, 2, 1, 3, 4, 6, 6, // 4
+    3, 5, 5, 1, 4, 4, 6, 5, 2, 4, 3, 1, 8, 4, 6, 6, // 5
+    6, 6, 2, 1, 3, 3, 5, 5, 4, 2, 2, 1, 6, 4, 6, 6, // 6
+    2, 5, 5, 1, 4, 4, 6, 5, 2, 4, 4, 1, 6, 4, 6, 6, // 7
+    3, 6, 2, 1, 3, 3, 3, 5, 2, 2, 2, 1, 4, 4, 4, 5, // 8
+    3, 6, 5, 1, 4, 4, 4, 5, 2, 5, 2, 1, 4, 5, 5, 5, // 9
+    2, 6, 2, 1, 3, 3, 3, 5, 2, 2, 2, 1, 4, 4, 4, 5, // A
+    2, 5, 5, 1, 4, 4, 4, 5, 2, 4, 2, 1, 4, 4, 4, 5, // B
+    2, 6, 2, 1, 3, 3, 5, 5, 2, 2, 2, 3, 4, 4, 6, 5, // C
+    3, 5, 5, 1, 4, 4, 6, 5, 2, 4, 3, 3, 4, 4, 7, 5, // D
+    2, 6, 2, 1, 3, 3, 5, 5, 2, 2, 2, 1, 4, 4, 6, 5, // E
+    2, 5, 5, 1, 4, 4, 6, 5, 2, 4, 4, 1, 4, 4, 7, 5, // F
+];
+
+fn prepare(program: &[u8]) -> (Cpu, TestMemory) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.variant = Variant::Wdc65C02;
+    cpu.pc = OPCODE_ADDR;
+    cpu.sp = 0xFD;
+    cpu.flags = StatusFlags::IGNORED;
+
+    for (addr, &byte) in (OPCODE_ADDR..).zip(program) {
+        memory.store(addr, byte);
+    }
+
+    (cpu, memory)
+}
+
+fn execute(cpu: &mut Cpu, memory: &mut TestMemory) {
+    cpu.execute_next_instruction(memory).unwrap();
+}
+
+#[test]
+fn clock_cycles() {
+    for opcode in u8::MIN..=u8::MAX {
+        let (mut cpu, mut memory) = prepare(&[opcode]);
+
+        execute(&mut cpu, &mut memory);
+
+        let expected_clock_cycles = CLOCK_CYCLES[opcode as usize];
+        assert_eq!(
+            cpu.clock_cycle_count,
+            expected_clock_cycles,
+            "opcode {opcode:#04X} ({:?}) must take {expected_clock_cycles} clock cycles",
+            CmosOpcode::from(opcode),
+        );
+    }
+}
+
+#[test]
+fn page_crossing() {
+    // LDA abs,X, ASL abs,X and INC abs,X all take an extra cycle on a page cross
+    for (opcode, expected_clock_cycles) in [
+        (CmosOpcode::LdaAbsoluteX, 5),
+        (CmosOpcode::AslAbsoluteX, 7),
+        (CmosOpcode::IncAbsoluteX, 7),
+    ] {
+        let (mut cpu, mut memory) = prepare(&[opcode as u8, 0xF0, 0x03]);
+        cpu.x = 0x20;
+        memory.store(0x0410, 0x41);
+
+        execute(&mut cpu, &mut memory);
+
+        assert_eq!(cpu.clock_cycle_count, expected_clock_cycles, "{opcode:?}");
+    }
+}
+
+#[test]
+fn bra() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::Bra as u8, 0x10]);
+    cpu.flags = StatusFlags::all();
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(cpu.pc, OPCODE_ADDR + 2 + 0x10);
+    assert_eq!(cpu.clock_cycle_count, 3);
+}
+
+#[test]
+fn bbr_bbs() {
+    const VALUE: u8 = 0b1010_0101;
+
+    for bit in 0..8 {
+        let set = VALUE >> bit & 1 != 0;
+
+        for (opcode, branch) in [
+            (CmosOpcode::Bbr0 as u8 + (bit << 4), !set),
+            (CmosOpcode::Bbs0 as u8 + (bit << 4), set),
+        ] {
+            let (mut cpu, mut memory) = prepare(&[opcode, 0x42, 0x10]);
+            memory.store(0x42, VALUE);
+
+            execute(&mut cpu, &mut memory);
+
+            let (expected_pc, expected_clock_cycles) = if branch {
+                (OPCODE_ADDR + 3 + 0x10, 6)
+            } else {
+                (OPCODE_ADDR + 3, 5)
+            };
+            assert_eq!(cpu.pc, expected_pc, "opcode {opcode:#04X}");
+            assert_eq!(cpu.clock_cycle_count, expected_clock_cycles);
+        }
+    }
+}
+
+#[test]
+fn rmb_smb() {
+    for bit in 0..8 {
+        let (mut cpu, mut memory) = prepare(&[CmosOpcode::Rmb0 as u8 + (bit << 4), 0x42]);
+        memory.store(0x42, 0xFF);
+        execute(&mut cpu, &mut memory);
+        assert_eq!(memory.load(0x42), !(1 << bit));
+
+        let (mut cpu, mut memory) = prepare(&[CmosOpcode::Smb0 as u8 + (bit << 4), 0x42]);
+        execute(&mut cpu, &mut memory);
+        assert_eq!(memory.load(0x42), 1 << bit);
+    }
+}
+
+#[test]
+fn push_pull_x_y() {
+    let (mut cpu, mut memory) = prepare(&[
+        CmosOpcode::Phx as u8,
+        CmosOpcode::Phy as u8,
+        CmosOpcode::Plx as u8,
+        CmosOpcode::Ply as u8,
+    ]);
+    cpu.x = 0x12;
+    cpu.y = 0x84;
+
+    for _ in 0..3 {
+        execute(&mut cpu, &mut memory);
+    }
+    assert!(cpu.flags.contains(StatusFlags::NEGATIVE));
+
+    execute(&mut cpu, &mut memory);
+    assert!(!cpu.flags.contains(StatusFlags::NEGATIVE));
+
+    assert_eq!((cpu.x, cpu.y), (0x84, 0x12), "X and Y must be swapped");
+    assert_eq!(cpu.sp, 0xFD);
+}
+
+#[test]
+fn stz() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::StzAbsolute as u8, 0x34, 0x04]);
+    cpu.a = 0xFF;
+    memory.store(0x0434, 0x56);
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(memory.load(0x0434), 0);
+}
+
+#[test]
+fn trb_tsb() {
+    let (mut cpu, mut memory) = prepare(&[
+        CmosOpcode::TsbZeropage as u8,
+        0x42,
+        CmosOpcode::TrbZeropage as u8,
+        0x43,
+    ]);
+    cpu.a = 0b0011_1100;
+    memory.store(0x42, 0b0000_0011);
+    memory.store(0x43, 0b1111_0000);
+
+    execute(&mut cpu, &mut memory);
+    assert_eq!(memory.load(0x42), 0b0011_1111);
+    assert!(
+        cpu.flags.contains(StatusFlags::ZERO),
+        "ZERO must be set when A AND value is 0"
+    );
+
+    execute(&mut cpu, &mut memory);
+    assert_eq!(memory.load(0x43), 0b1100_0000);
+    assert!(!cpu.flags.contains(StatusFlags::ZERO));
+}
+
+#[test]
+fn zeropage_indirect() {
+    let (mut cpu, mut memory) = prepare(&[
+        CmosOpcode::LdaZeropageIndirect as u8,
+        0xFF,
+        CmosOpcode::StaZeropageIndirect as u8,
+        0x42,
+    ]);
+    // the pointer wraps around in zero page
+    memory.store(0xFF, 0x34);
+    memory.store(0x00, 0x04);
+    memory.store(0x0434, 0x99);
+    memory.store(0x42, 0x78);
+    memory.store(0x43, 0x04);
+
+    execute(&mut cpu, &mut memory);
+    assert_eq!(cpu.a, 0x99);
+
+    execute(&mut cpu, &mut memory);
+    assert_eq!(memory.load(0x0478), 0x99);
+}
+
+#[test]
+fn jmp_absolute_x_indirect() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::JmpAbsoluteXIndirect as u8, 0xF0, 0x04]);
+    cpu.x = 0x20;
+    memory.store(0x0510, 0x34);
+    memory.store(0x0511, 0x12);
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(cpu.pc, 0x1234);
+}
+
+#[test]
+fn jmp_indirect_page_wrap_fixed() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::JmpIndirect as u8, 0xFF, 0x04]);
+    memory.store(0x04FF, 0x34);
+    memory.store(0x0500, 0x12);
+    memory.store(0x0400, 0x56);
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(cpu.pc, 0x1234, "the high byte must come from the next page");
+}
+
+#[test]
+fn bit_immediate() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::BitImmediate as u8, 0xC0]);
+    cpu.a = 0x3F;
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(
+        cpu.flags,
+        StatusFlags::IGNORED | StatusFlags::ZERO,
+        "BIT #imm must only set ZERO"
+    );
+}
+
+#[test]
+fn inc_dec_accumulator() {
+    let (mut cpu, mut memory) = prepare(&[
+        CmosOpcode::IncAccumulator as u8,
+        CmosOpcode::DecAccumulator as u8,
+        CmosOpcode::DecAccumulator as u8,
+    ]);
+    cpu.a = 0xFF;
+
+    execute(&mut cpu, &mut memory);
+    assert_eq!(cpu.a, 0x00);
+    assert!(cpu.flags.contains(StatusFlags::ZERO));
+
+    execute(&mut cpu, &mut memory);
+    execute(&mut cpu, &mut memory);
+    assert_eq!(cpu.a, 0xFE);
+    assert!(cpu.flags.contains(StatusFlags::NEGATIVE));
+}
+
+#[test]
+fn decimal_mode() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::AdcImmediate as u8, 0x01]);
+    cpu.a = 0x99;
+    cpu.flags.insert(StatusFlags::DECIMAL);
+
+    execute(&mut cpu, &mut memory);
+
+    assert_eq!(cpu.a, 0x00);
+    assert_eq!(
+        cpu.clock_cycle_count, 3,
+        "decimal mode must take an extra cycle"
+    );
+    assert!(
+        cpu.flags.contains(StatusFlags::ZERO),
+        "ZERO must be based on the decimal result"
+    );
+    assert!(!cpu.flags.contains(StatusFlags::NEGATIVE));
+    assert!(cpu.flags.contains(StatusFlags::CARRY));
+}
+
+#[test]
+fn interrupts_clear_decimal() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::Brk as u8]);
+    cpu.flags.insert(StatusFlags::DECIMAL);
+
+    execute(&mut cpu, &mut memory);
+
+    assert!(!cpu.flags.contains(StatusFlags::DECIMAL));
+    assert_ne!(
+        memory.load(0x01FB) & StatusFlags::DECIMAL.bits(),
+        0,
+        "the pushed flags must keep DECIMAL"
+    );
+}
+
+#[test]
+fn wai() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::Wai as u8, CmosOpcode::Nop as u8]);
+    memory.store(IRQ_VECTOR, 0x00);
+    memory.store(IRQ_VECTOR + 1, 0x03);
+
+    execute(&mut cpu, &mut memory);
+    assert!(cpu.is_waiting());
+
+    execute(&mut cpu, &mut memory);
+    assert!(
+        cpu.is_waiting(),
+        "WAI must wait until an interrupt line is asserted"
+    );
+    assert_eq!(cpu.pc, OPCODE_ADDR + 1);
+
+    cpu.set_irq(true);
+    execute(&mut cpu, &mut memory);
+    assert!(!cpu.is_waiting());
+    assert_eq!(cpu.pc, 0x0300, "the IRQ must be serviced right away");
+}
+
+#[test]
+fn wai_masked_irq() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::Wai as u8, CmosOpcode::Nop as u8]);
+    cpu.flags.insert(StatusFlags::INTERRUPT_DISABLE);
+
+    execute(&mut cpu, &mut memory);
+    cpu.set_irq(true);
+    execute(&mut cpu, &mut memory);
+
+    assert!(!cpu.is_waiting(), "a masked IRQ must still end the wait");
+    assert_eq!(cpu.pc, OPCODE_ADDR + 2, "execution must continue after WAI");
+}
+
+#[test]
+fn stp() {
+    let (mut cpu, mut memory) = prepare(&[CmosOpcode::Stp as u8]);
+
+    execute(&mut cpu, &mut memory);
+    assert!(cpu.is_jammed());
+
+    cpu.reset(&mut memory);
+    assert!(!cpu.is_jammed());
+}
@@ -0,0 +1,109 @@
+#[cfg(feature = "unstable-opcodes")]
+use crate::cpu::executor::Executor;
+use crate::{
+    cpu::{
+        Cpu, IllegalOpcode, IllegalOpcodePolicy,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+/// Opcodes that aren't implemented, along with their lengths
+///
+/// Only the unstable opcodes are left unimplemented without the `unstable-opcodes` feature
+const ILLEGAL_OPCODES: [(u8, u16); 3] = [(0x8B, 2), (0xAB, 2), (0x9C, 3)];
+
+/// Execute the illegal opcode PC points to
+#[cfg(not(feature = "unstable-opcodes"))]
+fn execute_illegal_opcode(cpu: &mut Cpu, memory: &mut TestMemory) -> Result<(), IllegalOpcode> {
+    cpu.execute_next_instruction(memory)
+}
+
+/// Execute the illegal opcode PC points to
+///
+/// Every NMOS opcode is implemented with the `unstable-opcodes` feature,
+/// so the opcode is fetched here and handed to the policy like the decoder does without it
+#[cfg(feature = "unstable-opcodes")]
+fn execute_illegal_opcode(cpu: &mut Cpu, memory: &mut TestMemory) -> Result<(), IllegalOpcode> {
+    let mut executor = Executor {
+        cpu,
+        memory,
+        observer: (),
+    };
+
+    let pc = executor.cpu.pc;
+    let opcode = executor.fetch_from_pc_cycle();
+    executor.illegal_opcode(IllegalOpcode { opcode, pc })
+}
+
+fn prepare(opcode: u8, policy: IllegalOpcodePolicy) -> (Cpu, TestMemory) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.illegal_opcode_policy = policy;
+    memory.store(OPCODE_ADDR, opcode);
+
+    (cpu, memory)
+}
+
+#[test]
+fn error() {
+    for (opcode, _) in ILLEGAL_OPCODES {
+        let (mut cpu, mut memory) = prepare(opcode, IllegalOpcodePolicy::Error);
+
+        let result = execute_illegal_opcode(&mut cpu, &mut memory);
+
+        assert_eq!(
+            result,
+            Err(IllegalOpcode {
+                opcode,
+                pc: OPCODE_ADDR
+            })
+        );
+        assert_eq!(cpu.pc, OPCODE_ADDR, "PC must point at the illegal opcode");
+        assert!(!cpu.is_jammed());
+    }
+}
+
+#[test]
+fn halt() {
+    for (opcode, _) in ILLEGAL_OPCODES {
+        let (mut cpu, mut memory) = prepare(opcode, IllegalOpcodePolicy::Halt);
+
+        execute_illegal_opcode(&mut cpu, &mut memory).unwrap();
+        assert!(cpu.is_jammed(), "illegal opcode must jam the CPU");
+
+        let pc = cpu.pc;
+        let clock_cycle_count = cpu.clock_cycle_count;
+        cpu.set_nmi(true);
+        cpu.execute_next_instruction(&mut memory).unwrap();
+
+        assert!(cpu.is_jammed(), "NMI must not bring back a jammed CPU");
+        assert_eq!(cpu.pc, pc, "jammed CPU must not execute instructions");
+        assert_eq!(
+            cpu.clock_cycle_count,
+            clock_cycle_count + 1,
+            "jammed CPU must keep clocking the bus"
+        );
+
+        cpu.reset(&mut memory);
+        assert!(!cpu.is_jammed(), "reset must bring back a jammed CPU");
+    }
+}
+
+#[test]
+fn nop() {
+    for (opcode, length) in ILLEGAL_OPCODES {
+        let (mut cpu, mut memory) = prepare(opcode, IllegalOpcodePolicy::Nop);
+
+        execute_illegal_opcode(&mut cpu, &mut memory).unwrap();
+
+        assert_eq!(
+            cpu.pc,
+            OPCODE_ADDR + length,
+            "illegal opcode {opcode:#04X} must be skipped along with its operands"
+        );
+        assert!(!cpu.is_jammed());
+    }
+}
@@ -1,7 +1,20 @@
 mod adc;
+mod alr;
+mod anc;
 mod and;
+mod arr;
 mod asl;
+mod axs;
+mod bcc;
+mod bcs;
+mod beq;
 mod bit;
+mod bmi;
+mod bne;
+mod bpl;
+mod brk;
+mod bvc;
+mod bvs;
 mod clc;
 mod cld;
 mod cli;
@@ -9,6 +22,7 @@ mod clv;
 mod cmp;
 mod cpx;
 mod cpy;
+mod dcp;
 mod dec;
 mod dex;
 mod dey;
@@ -16,26 +30,55 @@ mod eor;
 mod inc;
 mod inx;
 mod iny;
+mod isc;
+mod jmp;
+mod jsr;
+mod kil;
+#[cfg(feature = "unstable-opcodes")]
+mod las;
+mod lax;
 mod lda;
 mod ldx;
 mod ldy;
 mod lsr;
+#[cfg(feature = "unstable-opcodes")]
+mod lxa;
 mod nop;
 mod ora;
 mod pha;
 mod php;
+mod pla;
+mod plp;
+mod rla;
 mod rol;
 mod ror;
+mod rra;
+mod rti;
+mod rts;
+mod sax;
 mod sbc;
 mod sec;
 mod sed;
 mod sei;
+#[cfg(feature = "unstable-opcodes")]
+mod sha;
+#[cfg(feature = "unstable-opcodes")]
+mod shx;
+#[cfg(feature = "unstable-opcodes")]
+mod shy;
+mod slo;
+mod sre;
 mod sta;
 mod stx;
 mod sty;
+#[cfg(feature = "unstable-opcodes")]
+mod tas;
 mod tax;
 mod tay;
 mod tsx;
 mod txa;
 mod txs;
 mod tya;
+mod usbc;
+#[cfg(feature = "unstable-opcodes")]
+mod xaa;
@@ -59,3 +59,114 @@ test_addressing_modes! {
         IndirectY,
     ],
 }
+
+mod decimal {
+    use crate::cpu::{
+        Cpu, StatusFlags, Variant,
+        opcode::Opcode,
+        tests::{execute_decimal_immediate, to_bcd},
+    };
+
+    fn adc(variant: Variant, a: u8, b: u8, carry: bool) -> Cpu {
+        execute_decimal_immediate(Opcode::AdcImmediate, variant, a, b, carry)
+    }
+
+    #[test]
+    fn valid_bcd() {
+        for a in 0..100 {
+            for b in 0..100 {
+                for carry in [false, true] {
+                    let cpu = adc(Variant::Nmos6502, to_bcd(a), to_bcd(b), carry);
+                    let sum = a + b + carry as u8;
+
+                    assert_eq!(cpu.a, to_bcd(sum % 100), "{a} + {b} + {carry}");
+                    assert_eq!(
+                        cpu.flags.contains(StatusFlags::CARRY),
+                        sum >= 100,
+                        "CARRY flag set incorrectly {a} + {b} + {carry}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, Copy)]
+    struct FlagsCase {
+        a: u8,
+        b: u8,
+        carry: bool,
+        result: u8,
+        /// Expected flags, apart from INTERRUPT_DISABLE, DECIMAL and IGNORED
+        flags: StatusFlags,
+    }
+
+    const FLAGS_CASES: [FlagsCase; 5] = [
+        // ZERO is based on the binary result $9A, NEGATIVE on $A0 before it's adjusted
+        FlagsCase {
+            a: 0x99,
+            b: 0x01,
+            carry: false,
+            result: 0x00,
+            flags: StatusFlags::NEGATIVE.union(StatusFlags::CARRY),
+        },
+        FlagsCase {
+            a: 0x00,
+            b: 0x00,
+            carry: false,
+            result: 0x00,
+            flags: StatusFlags::ZERO,
+        },
+        FlagsCase {
+            a: 0x79,
+            b: 0x00,
+            carry: true,
+            result: 0x80,
+            flags: StatusFlags::NEGATIVE.union(StatusFlags::OVERFLOW),
+        },
+        FlagsCase {
+            a: 0x24,
+            b: 0x56,
+            carry: false,
+            result: 0x80,
+            flags: StatusFlags::NEGATIVE.union(StatusFlags::OVERFLOW),
+        },
+        FlagsCase {
+            a: 0x93,
+            b: 0x82,
+            carry: false,
+            result: 0x75,
+            flags: StatusFlags::OVERFLOW.union(StatusFlags::CARRY),
+        },
+    ];
+
+    #[test]
+    fn nmos_flags() {
+        const UNCHANGED: StatusFlags = StatusFlags::INTERRUPT_DISABLE
+            .union(StatusFlags::DECIMAL)
+            .union(StatusFlags::IGNORED);
+
+        for FlagsCase {
+            a,
+            b,
+            carry,
+            result,
+            flags,
+        } in FLAGS_CASES
+        {
+            let cpu = adc(Variant::Nmos6502, a, b, carry);
+
+            assert_eq!(cpu.a, result, "{a:#04X} + {b:#04X} + {carry}");
+            assert_eq!(
+                cpu.flags.difference(UNCHANGED),
+                flags,
+                "flags set incorrectly {a:#04X} + {b:#04X} + {carry}"
+            );
+        }
+    }
+
+    #[test]
+    fn ignored_on_2a03() {
+        let cpu = adc(Variant::Ricoh2A03, 0x09, 0x01, false);
+        assert_eq!(cpu.a, 0x0A, "2A03 must ignore the DECIMAL flag");
+    }
+}
@@ -0,0 +1,35 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Alr,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+impl TestReadInstruction for Alr {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+    }
+
+    fn verify(cpu: &Cpu, b: u8, a: u8) {
+        assert_eq!(cpu.a, (a & b) >> 1);
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            a & b & 1 == 1,
+            "CARRY flag set incorrectly"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Alr,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
@@ -0,0 +1,62 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Anc,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+impl TestReadInstruction for Anc {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+    }
+
+    fn verify(cpu: &Cpu, b: u8, a: u8) {
+        assert_eq!(cpu.a, a & b);
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            (cpu.a as i8) < 0,
+            "CARRY flag must be a copy of NEGATIVE"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Anc,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
+
+mod alt {
+    use super::*;
+
+    /// The same instruction under its second opcode
+    struct AncAlt;
+
+    impl TestReadInstruction for AncAlt {
+        type Args = BytePairs;
+
+        fn prepare(cpu: &mut Cpu, arg: u8, a: u8) {
+            <Anc as TestReadInstruction>::prepare(cpu, arg, a);
+        }
+
+        fn verify(cpu: &Cpu, b: u8, a: u8) {
+            <Anc as TestReadInstruction>::verify(cpu, b, a);
+        }
+    }
+
+    test_addressing_modes! {
+        instruction: AncAlt,
+        instruction_type: Read,
+        addressing_modes: [
+            Immediate,
+        ],
+    }
+}
@@ -0,0 +1,45 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Arr,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairsWithCarry,
+    },
+};
+
+impl TestReadInstruction for Arr {
+    type Args = BytePairsWithCarry;
+
+    fn prepare(cpu: &mut Cpu, _: u8, (a, carry): (u8, bool)) {
+        cpu.a = a;
+        cpu.flags.set(StatusFlags::CARRY, carry);
+    }
+
+    fn verify(cpu: &Cpu, b: u8, (a, carry): (u8, bool)) {
+        let expected = (a & b).rotate_right(1) & 0x7F | (carry as u8) << 7;
+        let bit6 = expected & 0x40 != 0;
+        let bit5 = expected & 0x20 != 0;
+
+        assert_eq!(cpu.a, expected);
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            bit6,
+            "CARRY flag must be bit 6 of the result"
+        );
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::OVERFLOW),
+            bit6 ^ bit5,
+            "OVERFLOW flag must be bit 6 XOR bit 5 of the result"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Arr,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
@@ -0,0 +1,43 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Axs,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+const A_VALUE: u8 = 0b1110_1011;
+
+impl TestReadInstruction for Axs {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, x: u8) {
+        cpu.a = A_VALUE;
+        cpu.x = x;
+        // the CARRY flag must not affect the result
+        cpu.flags.insert(StatusFlags::CARRY);
+    }
+
+    fn verify(cpu: &Cpu, b: u8, x: u8) {
+        let and = A_VALUE & x;
+
+        assert_eq!(cpu.x, and.wrapping_sub(b));
+        assert_eq!(cpu.a, A_VALUE, "AXS must not change A");
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            and >= b,
+            "CARRY flag set incorrectly"
+        );
+        check_nz_flags(cpu.x, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Axs,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Bcc,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Bcc {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::CARRY, !branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Bcc,
+    instruction_type: Relative,
+}
@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Bcs,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Bcs {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::CARRY, branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Bcs,
+    instruction_type: Relative,
+}
@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Beq,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Beq {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::ZERO, branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Beq,
+    instruction_type: Relative,
+}
@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Bmi,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Bmi {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::NEGATIVE, branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Bmi,
+    instruction_type: Relative,
+}
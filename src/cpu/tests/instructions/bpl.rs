@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Bpl,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Bpl {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::NEGATIVE, !branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Bpl,
+    instruction_type: Relative,
+}
@@ -0,0 +1,121 @@
+use crate::{
+    cpu::{
+        Cpu, StatusFlags,
+        executor::Executor,
+        interrupts::{IRQ_VECTOR, NMI_VECTOR},
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const IRQ_HANDLER: u16 = 0x0500;
+const NMI_HANDLER: u16 = 0x0400;
+const SP: u8 = 0xF7;
+
+fn prepare(flags: u8) -> (Cpu, TestMemory) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.sp = SP;
+    cpu.flags = StatusFlags::from_bits_truncate(flags);
+    memory.store(OPCODE_ADDR, Opcode::Brk as u8);
+    memory.store(IRQ_VECTOR, IRQ_HANDLER.to_le_bytes()[0]);
+    memory.store(IRQ_VECTOR + 1, IRQ_HANDLER.to_le_bytes()[1]);
+    memory.store(NMI_VECTOR, NMI_HANDLER.to_le_bytes()[0]);
+    memory.store(NMI_VECTOR + 1, NMI_HANDLER.to_le_bytes()[1]);
+
+    (cpu, memory)
+}
+
+fn check_pushed(memory: &mut TestMemory, flags: u8) {
+    let return_addr = OPCODE_ADDR + 2;
+    assert_eq!(
+        memory.load(0x0100 | SP as u16),
+        return_addr.to_le_bytes()[1]
+    );
+    assert_eq!(
+        memory.load(0x0100 | SP.wrapping_sub(1) as u16),
+        return_addr.to_le_bytes()[0]
+    );
+    assert_eq!(
+        memory.load(0x0100 | SP.wrapping_sub(2) as u16),
+        (StatusFlags::from_bits_truncate(flags) | StatusFlags::BREAK | StatusFlags::IGNORED).bits(),
+        "BRK must push flags with BREAK set"
+    );
+}
+
+#[test]
+fn brk() {
+    for flags in u8::MIN..u8::MAX {
+        let (mut cpu, mut memory) = prepare(flags);
+
+        let mut executor = Executor {
+            cpu: &mut cpu,
+            memory: &mut memory,
+            observer: (),
+        };
+        executor.execute_next_instruction().unwrap();
+
+        assert_eq!(cpu.clock_cycle_count, 7, "BRK must take 7 clock cycles");
+        assert_eq!(cpu.pc, IRQ_HANDLER, "BRK must jump through the IRQ vector");
+        assert_eq!(cpu.sp, SP.wrapping_sub(3), "BRK must push 3 bytes");
+        assert!(cpu.flags.contains(StatusFlags::INTERRUPT_DISABLE));
+        check_pushed(&mut memory, flags);
+    }
+}
+
+#[test]
+fn brk_hijacked_by_nmi() {
+    let (mut cpu, mut memory) = prepare(0);
+
+    cpu.set_nmi(true);
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+    executor.execute_next_instruction().unwrap();
+
+    assert_eq!(cpu.clock_cycle_count, 7, "BRK must take 7 clock cycles");
+    assert_eq!(cpu.pc, NMI_HANDLER, "pending NMI must hijack BRK");
+    check_pushed(&mut memory, 0);
+
+    // the NMI has been serviced by the hijacked BRK
+    memory.store(NMI_HANDLER, Opcode::Nop as u8);
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+    executor.execute_next_instruction().unwrap();
+    executor.execute_next_instruction().unwrap();
+    assert_ne!(cpu.pc, NMI_HANDLER);
+}
+
+#[test]
+fn brk_rti_round_trip() {
+    let (mut cpu, mut memory) = prepare(StatusFlags::CARRY.bits());
+    memory.store(IRQ_HANDLER, Opcode::Rti as u8);
+
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+    executor.execute_next_instruction().unwrap();
+    executor.execute_next_instruction().unwrap();
+
+    assert_eq!(
+        cpu.pc,
+        OPCODE_ADDR + 2,
+        "RTI must return past the BRK padding byte"
+    );
+    assert_eq!(cpu.sp, SP);
+    assert_eq!(
+        cpu.flags,
+        StatusFlags::CARRY,
+        "RTI must restore flags from before BRK"
+    );
+}
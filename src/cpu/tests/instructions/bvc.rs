@@ -0,0 +1,16 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Bvc,
+    tests::addressing_modes::{relative::*, test_addressing_modes},
+};
+
+impl TestRelativeInstruction for Bvc {
+    fn prepare(cpu: &mut Cpu, branch: bool) {
+        cpu.flags.set(StatusFlags::OVERFLOW, !branch);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Bvc,
+    instruction_type: Relative,
+}
@@ -0,0 +1,45 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Dcp,
+    tests::{
+        addressing_modes::{combined::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+impl TestCombinedInstruction for Dcp {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+    }
+
+    fn verify(cpu: &Cpu, arg: u8, a: u8) -> u8 {
+        let decremented = arg.wrapping_sub(1);
+
+        assert_eq!(cpu.a, a, "DCP must not change A");
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            a >= decremented,
+            "CARRY flag set incorrectly"
+        );
+        check_nz_flags(a.wrapping_sub(decremented), cpu.flags);
+
+        decremented
+    }
+}
+
+test_addressing_modes! {
+    instruction: Dcp,
+    instruction_type: Combined,
+    addressing_modes: [
+        Zeropage,
+        ZeropageX,
+        Absolute,
+        AbsoluteX,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
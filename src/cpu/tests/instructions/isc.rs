@@ -0,0 +1,55 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Isc,
+    tests::{
+        addressing_modes::{combined::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairsWithCarry,
+    },
+};
+
+impl TestCombinedInstruction for Isc {
+    type Args = BytePairsWithCarry;
+
+    fn prepare(cpu: &mut Cpu, _: u8, (a, carry): (u8, bool)) {
+        cpu.a = a;
+        cpu.flags.set(StatusFlags::CARRY, carry);
+    }
+
+    fn verify(cpu: &Cpu, arg: u8, (a, carry): (u8, bool)) -> u8 {
+        let incremented = arg.wrapping_add(1);
+        let borrow = !carry as i32;
+
+        let unsigned_result = a as i32 - incremented as i32 - borrow;
+        let signed_result = a as i8 as i32 - incremented as i8 as i32 - borrow;
+
+        assert_eq!(cpu.a, unsigned_result as u8, "Subtraction result incorrect");
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            unsigned_result >= 0,
+            "CARRY flag set incorrectly"
+        );
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::OVERFLOW),
+            !(i8::MIN as i32..=i8::MAX as i32).contains(&signed_result),
+            "OVERFLOW flag set incorrectly"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+
+        incremented
+    }
+}
+
+test_addressing_modes! {
+    instruction: Isc,
+    instruction_type: Combined,
+    addressing_modes: [
+        Zeropage,
+        ZeropageX,
+        Absolute,
+        AbsoluteX,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
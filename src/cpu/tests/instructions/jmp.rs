@@ -0,0 +1,10 @@
+use crate::cpu::{instructions::Jmp, tests::addressing_modes::test_addressing_modes};
+
+test_addressing_modes! {
+    instruction: Jmp,
+    instruction_type: Jump,
+    addressing_modes: [
+        Absolute,
+        Indirect,
+    ],
+}
@@ -0,0 +1,71 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const TARGET: u16 = 0x0456;
+const SP: u8 = 0xF7;
+
+#[test]
+fn jsr() {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.sp = SP;
+    memory.store(OPCODE_ADDR, Opcode::Jsr as u8);
+    memory.store(OPCODE_ADDR + 1, TARGET.to_le_bytes()[0]);
+    memory.store(OPCODE_ADDR + 2, TARGET.to_le_bytes()[1]);
+
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+    executor.execute_next_instruction().unwrap();
+
+    assert_eq!(cpu.clock_cycle_count, 6, "JSR must take 6 clock cycles");
+    assert_eq!(cpu.pc, TARGET, "JSR must jump to the target address");
+    assert_eq!(cpu.sp, SP.wrapping_sub(2), "JSR must push 2 bytes");
+
+    // the address of the last byte of JSR is pushed
+    let return_addr = OPCODE_ADDR + 2;
+    assert_eq!(
+        memory.load(0x0100 | SP as u16),
+        return_addr.to_le_bytes()[1]
+    );
+    assert_eq!(
+        memory.load(0x0100 | SP.wrapping_sub(1) as u16),
+        return_addr.to_le_bytes()[0]
+    );
+}
+
+#[test]
+fn jsr_rts_round_trip() {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.sp = SP;
+    memory.store(OPCODE_ADDR, Opcode::Jsr as u8);
+    memory.store(OPCODE_ADDR + 1, TARGET.to_le_bytes()[0]);
+    memory.store(OPCODE_ADDR + 2, TARGET.to_le_bytes()[1]);
+    memory.store(TARGET, Opcode::Rts as u8);
+
+    let mut executor = Executor {
+        cpu: &mut cpu,
+        memory: &mut memory,
+        observer: (),
+    };
+    executor.execute_next_instruction().unwrap();
+    executor.execute_next_instruction().unwrap();
+
+    assert_eq!(cpu.clock_cycle_count, 12);
+    assert_eq!(cpu.pc, OPCODE_ADDR + 3, "RTS must return past the JSR");
+    assert_eq!(cpu.sp, SP);
+}
@@ -0,0 +1,74 @@
+use crate::{
+    cpu::{
+        Cpu,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const KIL_OPCODES: [Opcode; 12] = [
+    Opcode::Kil02,
+    Opcode::Kil12,
+    Opcode::Kil22,
+    Opcode::Kil32,
+    Opcode::Kil42,
+    Opcode::Kil52,
+    Opcode::Kil62,
+    Opcode::Kil72,
+    Opcode::Kil92,
+    Opcode::KilB2,
+    Opcode::KilD2,
+    Opcode::KilF2,
+];
+
+#[test]
+fn jams() {
+    for opcode in KIL_OPCODES {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        memory.store(OPCODE_ADDR, opcode as u8);
+        memory.store(OPCODE_ADDR + 1, Opcode::Nop as u8);
+
+        cpu.execute_next_instruction(&mut memory).unwrap();
+        assert!(cpu.is_jammed(), "{opcode:?} must jam the CPU");
+        assert_eq!(cpu.clock_cycle_count, 2);
+
+        let pc = cpu.pc;
+        for _ in 0..4 {
+            cpu.execute_next_instruction(&mut memory).unwrap();
+        }
+        assert!(cpu.is_jammed());
+        assert_eq!(cpu.pc, pc, "jammed CPU must not execute instructions");
+        assert_eq!(
+            cpu.clock_cycle_count,
+            2 + 4,
+            "jammed CPU must keep clocking the bus"
+        );
+    }
+}
+
+#[test]
+fn reset_clears_jam() {
+    for opcode in KIL_OPCODES {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        memory.store(OPCODE_ADDR, opcode as u8);
+
+        cpu.execute_next_instruction(&mut memory).unwrap();
+        cpu.set_irq(true);
+        cpu.set_nmi(true);
+        cpu.execute_next_instruction(&mut memory).unwrap();
+        assert!(
+            cpu.is_jammed(),
+            "interrupts must not bring back a jammed CPU"
+        );
+
+        cpu.reset(&mut memory);
+        assert!(!cpu.is_jammed(), "reset must bring back a jammed CPU");
+    }
+}
@@ -0,0 +1,34 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Las,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+impl TestReadInstruction for Las {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, sp: u8) {
+        cpu.sp = sp;
+    }
+
+    fn verify(cpu: &Cpu, value: u8, sp: u8) {
+        let result = value & sp;
+
+        assert_eq!(cpu.a, result);
+        assert_eq!(cpu.x, result);
+        assert_eq!(cpu.sp, result);
+        check_nz_flags(result, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Las,
+    instruction_type: Read,
+    addressing_modes: [
+        AbsoluteY,
+    ],
+}
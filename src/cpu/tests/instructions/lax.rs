@@ -0,0 +1,34 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Lax,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::SingleBytes,
+    },
+};
+
+impl TestReadInstruction for Lax {
+    type Args = SingleBytes;
+
+    fn prepare(_: &mut Cpu, _: u8, _: ()) {}
+
+    fn verify(cpu: &Cpu, arg: u8, _: ()) {
+        assert_eq!(cpu.a, arg);
+        assert_eq!(cpu.x, arg);
+        check_nz_flags(arg, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Lax,
+    instruction_type: Read,
+    addressing_modes: [
+        Zeropage,
+        ZeropageY,
+        Absolute,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
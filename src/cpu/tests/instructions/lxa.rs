@@ -0,0 +1,58 @@
+use crate::{
+    cpu::{
+        Cpu, MagicConstant,
+        instructions::Lxa,
+        opcode::Opcode,
+        tests::{
+            TestMemory,
+            addressing_modes::{OPCODE_ADDR, read::*, test_addressing_modes},
+            flags::check_nz_flags,
+            test_args::BytePairs,
+        },
+    },
+    memory::Memory,
+};
+
+impl TestReadInstruction for Lxa {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+    }
+
+    fn verify(cpu: &Cpu, value: u8, a: u8) {
+        let result = (a | MagicConstant::default().0) & value;
+
+        assert_eq!(cpu.a, result);
+        assert_eq!(cpu.x, result);
+        check_nz_flags(result, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Lxa,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
+
+#[test]
+fn configured_magic_constant() {
+    for magic_constant in [0x00, 0xFF] {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        cpu.a = 0x0F;
+        cpu.magic_constant = MagicConstant(magic_constant);
+        memory.store(OPCODE_ADDR, Opcode::LxaImmediate as u8);
+        memory.store(OPCODE_ADDR + 1, 0x3C);
+
+        cpu.execute_next_instruction(&mut memory).unwrap();
+
+        let expected = (0x0F | magic_constant) & 0x3C;
+        assert_eq!(cpu.a, expected);
+        assert_eq!(cpu.x, expected);
+    }
+}
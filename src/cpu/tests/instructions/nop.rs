@@ -1,7 +1,10 @@
 use crate::cpu::{
     Cpu, StatusFlags,
     instructions::Nop,
-    tests::addressing_modes::{implied::*, test_addressing_modes},
+    tests::{
+        addressing_modes::{implied::*, read::*, test_addressing_modes},
+        test_args::SingleBytes,
+    },
 };
 
 const A_VALUE: u8 = 42;
@@ -31,3 +34,88 @@ test_addressing_modes! {
     instruction: Nop,
     instruction_type: Implied,
 }
+
+/// Generate tests for the unofficial NOPs, one module per opcode
+///
+/// They behave just like the official NOP, except for the memory they read
+macro_rules! test_unofficial_nops {
+    ($($instruction:ident: $instruction_type:ident $([$addressing_mode:ident])?),+ $(,)?) => {
+        $(
+        pastey::paste! {
+            mod [<$instruction:lower>] {
+                use super::*;
+
+                struct $instruction;
+
+                test_unofficial_nops!(@impl $instruction, $instruction_type);
+
+                test_addressing_modes! {
+                    instruction: $instruction,
+                    instruction_type: $instruction_type,
+                    $(addressing_modes: [$addressing_mode],)?
+                }
+            }
+        }
+        )+
+    };
+
+    (@impl $instruction:ident, Implied) => {
+        impl TestImpliedInstruction for $instruction {
+            fn prepare(cpu: &mut Cpu, arg: u8) {
+                <Nop as TestImpliedInstruction>::prepare(cpu, arg);
+            }
+
+            fn verify(cpu: &Cpu, arg: u8) {
+                <Nop as TestImpliedInstruction>::verify(cpu, arg);
+            }
+        }
+    };
+
+    (@impl $instruction:ident, Read) => {
+        impl TestReadInstruction for $instruction {
+            type Args = SingleBytes;
+
+            fn prepare(cpu: &mut Cpu, arg: u8, _: ()) {
+                <Nop as TestImpliedInstruction>::prepare(cpu, arg);
+            }
+
+            fn verify(cpu: &Cpu, arg: u8, _: ()) {
+                // X is overwritten by the indexed addressing modes
+                assert_eq!(cpu.flags, StatusFlags::from_bits_truncate(arg));
+                assert_eq!(cpu.a, A_VALUE);
+                assert_eq!(cpu.y, Y_VALUE);
+                assert_eq!(cpu.sp, SP_VALUE);
+            }
+        }
+    };
+}
+
+test_unofficial_nops! {
+    Nop1A: Implied,
+    Nop3A: Implied,
+    Nop5A: Implied,
+    Nop7A: Implied,
+    NopDa: Implied,
+    NopFa: Implied,
+    Nop80: Read [Immediate],
+    Nop82: Read [Immediate],
+    Nop89: Read [Immediate],
+    NopC2: Read [Immediate],
+    NopE2: Read [Immediate],
+    Nop04: Read [Zeropage],
+    Nop44: Read [Zeropage],
+    Nop64: Read [Zeropage],
+    Nop14: Read [ZeropageX],
+    Nop34: Read [ZeropageX],
+    Nop54: Read [ZeropageX],
+    Nop74: Read [ZeropageX],
+    NopD4: Read [ZeropageX],
+    NopF4: Read [ZeropageX],
+    Nop0C: Read [Absolute],
+    Nop1C: Read [AbsoluteX],
+    Nop3C: Read [AbsoluteX],
+    Nop5C: Read [AbsoluteX],
+    Nop7C: Read [AbsoluteX],
+    NopDc: Read [AbsoluteX],
+    NopFc: Read [AbsoluteX],
+}
@@ -0,0 +1,24 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Pla,
+    tests::{
+        addressing_modes::{stack_pull::TestStackPullInstruction, test_addressing_modes},
+        flags::check_nz_flags,
+    },
+};
+
+impl TestStackPullInstruction for Pla {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.a = !arg;
+    }
+
+    fn verify(cpu: &Cpu, value_pulled: u8) {
+        assert_eq!(cpu.a, value_pulled);
+        check_nz_flags(cpu.a, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Pla,
+    instruction_type: StackPull,
+}
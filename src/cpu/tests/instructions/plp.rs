@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Plp,
+    tests::addressing_modes::{stack_pull::TestStackPullInstruction, test_addressing_modes},
+};
+
+impl TestStackPullInstruction for Plp {
+    fn prepare(cpu: &mut Cpu, _arg: u8) {
+        cpu.flags = StatusFlags::IGNORED;
+    }
+
+    fn verify(cpu: &Cpu, value_pulled: u8) {
+        assert_eq!(
+            cpu.flags,
+            StatusFlags::from_bits_retain(value_pulled).difference(StatusFlags::BREAK)
+                | StatusFlags::IGNORED,
+            "PLP must restore all flags except BREAK and IGNORED"
+        );
+    }
+}
+
+test_addressing_modes! {
+    instruction: Plp,
+    instruction_type: StackPull,
+}
@@ -0,0 +1,46 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Rla,
+    tests::{
+        addressing_modes::{combined::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairsWithCarry,
+    },
+};
+
+impl TestCombinedInstruction for Rla {
+    type Args = BytePairsWithCarry;
+
+    fn prepare(cpu: &mut Cpu, _: u8, (a, carry): (u8, bool)) {
+        cpu.a = a;
+        cpu.flags.set(StatusFlags::CARRY, carry);
+    }
+
+    fn verify(cpu: &Cpu, arg: u8, (a, carry): (u8, bool)) -> u8 {
+        let rotated = arg << 1 | carry as u8;
+
+        assert_eq!(cpu.a, a & rotated);
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            arg >> 7 == 1,
+            "CARRY flag set incorrectly"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+
+        rotated
+    }
+}
+
+test_addressing_modes! {
+    instruction: Rla,
+    instruction_type: Combined,
+    addressing_modes: [
+        Zeropage,
+        ZeropageX,
+        Absolute,
+        AbsoluteX,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
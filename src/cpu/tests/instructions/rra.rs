@@ -0,0 +1,56 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Rra,
+    tests::{
+        addressing_modes::{combined::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairsWithCarry,
+    },
+};
+
+impl TestCombinedInstruction for Rra {
+    type Args = BytePairsWithCarry;
+
+    fn prepare(cpu: &mut Cpu, _: u8, (a, carry): (u8, bool)) {
+        cpu.a = a;
+        cpu.flags.set(StatusFlags::CARRY, carry);
+    }
+
+    fn verify(cpu: &Cpu, arg: u8, (a, carry): (u8, bool)) -> u8 {
+        let rotated = arg >> 1 | (carry as u8) << 7;
+        // the bit rotated out is the carry for the addition
+        let add_carry = arg & 1;
+
+        let unsigned_result = a as u32 + rotated as u32 + add_carry as u32;
+        let signed_result = a as i8 as i32 + rotated as i8 as i32 + add_carry as i32;
+
+        assert_eq!(cpu.a, unsigned_result as u8, "Addition result incorrect");
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            unsigned_result > u8::MAX as u32,
+            "CARRY flag set incorrectly"
+        );
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::OVERFLOW),
+            !(i8::MIN as i32..=i8::MAX as i32).contains(&signed_result),
+            "OVERFLOW flag set incorrectly"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+
+        rotated
+    }
+}
+
+test_addressing_modes! {
+    instruction: Rra,
+    instruction_type: Combined,
+    addressing_modes: [
+        Zeropage,
+        ZeropageX,
+        Absolute,
+        AbsoluteX,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
@@ -0,0 +1,60 @@
+use crate::{
+    cpu::{
+        Cpu, StatusFlags,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const RETURN_ADDR: u16 = 0x0456;
+const SP: u8 = 0xF4;
+
+#[test]
+fn rti() {
+    for flags in u8::MIN..u8::MAX {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        cpu.sp = SP;
+        let previous_flags = cpu.flags;
+        memory.store(OPCODE_ADDR, Opcode::Rti as u8);
+        memory.store(0x0100 | SP.wrapping_add(1) as u16, flags);
+        memory.store(
+            0x0100 | SP.wrapping_add(2) as u16,
+            RETURN_ADDR.to_le_bytes()[0],
+        );
+        memory.store(
+            0x0100 | SP.wrapping_add(3) as u16,
+            RETURN_ADDR.to_le_bytes()[1],
+        );
+
+        let mut executor = Executor {
+            cpu: &mut cpu,
+            memory: &mut memory,
+            observer: (),
+        };
+        executor.execute_next_instruction().unwrap();
+
+        assert_eq!(cpu.clock_cycle_count, 6, "RTI must take 6 clock cycles");
+        assert_eq!(
+            cpu.pc, RETURN_ADDR,
+            "RTI must return to the exact pulled address"
+        );
+        assert_eq!(cpu.sp, SP.wrapping_add(3), "RTI must pull 3 bytes");
+
+        let ignored = StatusFlags::BREAK | StatusFlags::IGNORED;
+        assert_eq!(
+            cpu.flags.difference(ignored),
+            StatusFlags::from_bits_retain(flags).difference(ignored),
+            "RTI must restore the pulled flags"
+        );
+        assert_eq!(
+            cpu.flags.intersection(ignored),
+            previous_flags.intersection(ignored),
+            "RTI must ignore BREAK and IGNORED"
+        );
+    }
+}
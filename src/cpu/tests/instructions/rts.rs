@@ -0,0 +1,47 @@
+use crate::{
+    cpu::{
+        Cpu,
+        executor::Executor,
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const RETURN_ADDR: u16 = 0x0456;
+
+#[test]
+fn rts() {
+    // also covers pulling across the top of the stack page
+    for sp in [0xF5, 0xFE, 0xFF] {
+        let mut cpu = Cpu::new();
+        let mut memory = TestMemory::new();
+
+        cpu.pc = OPCODE_ADDR;
+        cpu.sp = sp;
+        memory.store(OPCODE_ADDR, Opcode::Rts as u8);
+        memory.store(
+            0x0100 | sp.wrapping_add(1) as u16,
+            RETURN_ADDR.to_le_bytes()[0],
+        );
+        memory.store(
+            0x0100 | sp.wrapping_add(2) as u16,
+            RETURN_ADDR.to_le_bytes()[1],
+        );
+
+        let mut executor = Executor {
+            cpu: &mut cpu,
+            memory: &mut memory,
+            observer: (),
+        };
+        executor.execute_next_instruction().unwrap();
+
+        assert_eq!(cpu.clock_cycle_count, 6, "RTS must take 6 clock cycles");
+        assert_eq!(
+            cpu.pc,
+            RETURN_ADDR + 1,
+            "RTS must return past the pulled address"
+        );
+        assert_eq!(cpu.sp, sp.wrapping_add(2), "RTS must pull 2 bytes");
+    }
+}
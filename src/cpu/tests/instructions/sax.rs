@@ -0,0 +1,27 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Sax,
+    tests::addressing_modes::{test_addressing_modes, write::TestWriteInstruction},
+};
+
+impl TestWriteInstruction for Sax {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.a = arg;
+        cpu.x = arg.rotate_left(3);
+    }
+
+    fn expected_value(cpu: &Cpu) -> u8 {
+        cpu.a & cpu.x
+    }
+}
+
+test_addressing_modes! {
+    instruction: Sax,
+    instruction_type: Write,
+    addressing_modes: [
+        Zeropage,
+        ZeropageY,
+        Absolute,
+        IndirectX,
+    ],
+}
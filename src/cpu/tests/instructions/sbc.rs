@@ -61,3 +61,58 @@ test_addressing_modes! {
         IndirectY,
     ],
 }
+
+mod decimal {
+    use crate::cpu::{
+        Cpu, StatusFlags, Variant,
+        opcode::Opcode,
+        tests::{execute_decimal_immediate, to_bcd},
+    };
+
+    fn sbc(variant: Variant, a: u8, b: u8, carry: bool) -> Cpu {
+        execute_decimal_immediate(Opcode::SbcImmediate, variant, a, b, carry)
+    }
+
+    #[test]
+    fn valid_bcd() {
+        for a in 0..100u8 {
+            for b in 0..100u8 {
+                for carry in [false, true] {
+                    let cpu = sbc(Variant::Nmos6502, to_bcd(a), to_bcd(b), carry);
+                    let difference = (a as i16) - (b as i16) - (!carry as i16);
+
+                    assert_eq!(
+                        cpu.a,
+                        to_bcd(difference.rem_euclid(100) as u8),
+                        "{a} - {b} - {}",
+                        !carry
+                    );
+                    assert_eq!(
+                        cpu.flags.contains(StatusFlags::CARRY),
+                        difference >= 0,
+                        "CARRY flag set incorrectly {a} - {b} - {}",
+                        !carry
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn flags_match_binary_result() {
+        // $00 - $01 is $99 in BCD, but the flags come from the binary result $FF
+        let cpu = sbc(Variant::Nmos6502, 0x00, 0x01, true);
+
+        assert_eq!(cpu.a, 0x99);
+        assert!(cpu.flags.contains(StatusFlags::NEGATIVE));
+        assert!(!cpu.flags.contains(StatusFlags::ZERO));
+        assert!(!cpu.flags.contains(StatusFlags::CARRY));
+        assert!(!cpu.flags.contains(StatusFlags::OVERFLOW));
+    }
+
+    #[test]
+    fn ignored_on_2a03() {
+        let cpu = sbc(Variant::Ricoh2A03, 0x10, 0x01, true);
+        assert_eq!(cpu.a, 0x0F, "2A03 must ignore the DECIMAL flag");
+    }
+}
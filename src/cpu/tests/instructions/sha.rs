@@ -0,0 +1,27 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Sha,
+    tests::addressing_modes::{
+        test_addressing_modes, unstable_store::TestUnstableStoreInstruction,
+    },
+};
+
+impl TestUnstableStoreInstruction for Sha {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.a = arg;
+        cpu.x = arg.rotate_left(3);
+    }
+
+    fn expected_value(cpu: &Cpu) -> u8 {
+        cpu.a & cpu.x
+    }
+}
+
+test_addressing_modes! {
+    instruction: Sha,
+    instruction_type: UnstableStore,
+    addressing_modes: [
+        AbsoluteY,
+        IndirectY,
+    ],
+}
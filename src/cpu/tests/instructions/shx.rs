@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Shx,
+    tests::addressing_modes::{
+        test_addressing_modes, unstable_store::TestUnstableStoreInstruction,
+    },
+};
+
+impl TestUnstableStoreInstruction for Shx {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.x = arg;
+    }
+
+    fn expected_value(cpu: &Cpu) -> u8 {
+        cpu.x
+    }
+}
+
+test_addressing_modes! {
+    instruction: Shx,
+    instruction_type: UnstableStore,
+    addressing_modes: [
+        AbsoluteY,
+    ],
+}
@@ -0,0 +1,25 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Shy,
+    tests::addressing_modes::{
+        test_addressing_modes, unstable_store::TestUnstableStoreInstruction,
+    },
+};
+
+impl TestUnstableStoreInstruction for Shy {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.y = arg;
+    }
+
+    fn expected_value(cpu: &Cpu) -> u8 {
+        cpu.y
+    }
+}
+
+test_addressing_modes! {
+    instruction: Shy,
+    instruction_type: UnstableStore,
+    addressing_modes: [
+        AbsoluteX,
+    ],
+}
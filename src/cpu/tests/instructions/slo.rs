@@ -0,0 +1,45 @@
+use crate::cpu::{
+    Cpu, StatusFlags,
+    instructions::Slo,
+    tests::{
+        addressing_modes::{combined::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+impl TestCombinedInstruction for Slo {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+    }
+
+    fn verify(cpu: &Cpu, arg: u8, a: u8) -> u8 {
+        let shifted = arg << 1;
+
+        assert_eq!(cpu.a, a | shifted);
+        assert_eq!(
+            cpu.flags.contains(StatusFlags::CARRY),
+            arg >> 7 == 1,
+            "CARRY flag set incorrectly"
+        );
+        check_nz_flags(cpu.a, cpu.flags);
+
+        shifted
+    }
+}
+
+test_addressing_modes! {
+    instruction: Slo,
+    instruction_type: Combined,
+    addressing_modes: [
+        Zeropage,
+        ZeropageX,
+        Absolute,
+        AbsoluteX,
+        AbsoluteY,
+        IndirectX,
+        IndirectY,
+    ],
+}
@@ -0,0 +1,27 @@
+use crate::cpu::{
+    Cpu,
+    instructions::Tas,
+    tests::addressing_modes::{
+        test_addressing_modes, unstable_store::TestUnstableStoreInstruction,
+    },
+};
+
+impl TestUnstableStoreInstruction for Tas {
+    fn prepare(cpu: &mut Cpu, arg: u8) {
+        cpu.a = arg;
+        cpu.x = arg.rotate_left(3);
+    }
+
+    fn expected_value(cpu: &Cpu) -> u8 {
+        assert_eq!(cpu.sp, cpu.a & cpu.x, "TAS must set SP to A AND X");
+        cpu.sp
+    }
+}
+
+test_addressing_modes! {
+    instruction: Tas,
+    instruction_type: UnstableStore,
+    addressing_modes: [
+        AbsoluteY,
+    ],
+}
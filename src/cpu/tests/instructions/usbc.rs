@@ -0,0 +1,28 @@
+use crate::cpu::{
+    Cpu,
+    instructions::{Sbc, Usbc},
+    tests::{
+        addressing_modes::{read::TestReadInstruction, test_addressing_modes},
+        test_args::BytePairsWithCarry,
+    },
+};
+
+impl TestReadInstruction for Usbc {
+    type Args = BytePairsWithCarry;
+
+    fn prepare(cpu: &mut Cpu, arg: u8, additional_args: (u8, bool)) {
+        <Sbc as TestReadInstruction>::prepare(cpu, arg, additional_args);
+    }
+
+    fn verify(cpu: &Cpu, b: u8, additional_args: (u8, bool)) {
+        <Sbc as TestReadInstruction>::verify(cpu, b, additional_args);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Usbc,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
@@ -0,0 +1,36 @@
+use crate::cpu::{
+    Cpu, MagicConstant,
+    instructions::Xaa,
+    tests::{
+        addressing_modes::{read::*, test_addressing_modes},
+        flags::check_nz_flags,
+        test_args::BytePairs,
+    },
+};
+
+const X_VALUE: u8 = 0b1101_0111;
+
+impl TestReadInstruction for Xaa {
+    type Args = BytePairs;
+
+    fn prepare(cpu: &mut Cpu, _: u8, a: u8) {
+        cpu.a = a;
+        cpu.x = X_VALUE;
+    }
+
+    fn verify(cpu: &Cpu, value: u8, a: u8) {
+        let result = (a | MagicConstant::default().0) & X_VALUE & value;
+
+        assert_eq!(cpu.a, result);
+        assert_eq!(cpu.x, X_VALUE, "XAA must not change X");
+        check_nz_flags(result, cpu.flags);
+    }
+}
+
+test_addressing_modes! {
+    instruction: Xaa,
+    instruction_type: Read,
+    addressing_modes: [
+        Immediate,
+    ],
+}
@@ -0,0 +1,277 @@
+use crate::{
+    cpu::{
+        Cpu, StatusFlags,
+        interrupts::{IRQ_VECTOR, NMI_VECTOR, RESET_VECTOR},
+        opcode::Opcode,
+        tests::{TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const RESET_HANDLER: u16 = 0x0300;
+const NMI_HANDLER: u16 = 0x0400;
+const IRQ_HANDLER: u16 = 0x0500;
+const SP: u8 = 0xF7;
+
+fn prepare(program: &[Opcode]) -> (Cpu, TestMemory) {
+    let mut cpu = Cpu::new();
+    let mut memory = TestMemory::new();
+
+    for (vector, handler) in [
+        (RESET_VECTOR, RESET_HANDLER),
+        (NMI_VECTOR, NMI_HANDLER),
+        (IRQ_VECTOR, IRQ_HANDLER),
+    ] {
+        memory.store(vector, handler.to_le_bytes()[0]);
+        memory.store(vector + 1, handler.to_le_bytes()[1]);
+    }
+
+    for (addr, &opcode) in (OPCODE_ADDR..).zip(program) {
+        memory.store(addr, opcode as u8);
+    }
+
+    cpu.pc = OPCODE_ADDR;
+    cpu.sp = SP;
+
+    (cpu, memory)
+}
+
+/// Check that the interrupt sequence pushed the expected return address and flags
+fn check_interrupt_frame(cpu: &Cpu, memory: &mut TestMemory, return_addr: u16, flags: StatusFlags) {
+    assert_eq!(cpu.sp, SP.wrapping_sub(3), "interrupt must push 3 bytes");
+    assert_eq!(
+        memory.load(0x0100 | SP as u16),
+        return_addr.to_le_bytes()[1]
+    );
+    assert_eq!(
+        memory.load(0x0100 | SP.wrapping_sub(1) as u16),
+        return_addr.to_le_bytes()[0]
+    );
+    assert_eq!(
+        memory.load(0x0100 | SP.wrapping_sub(2) as u16),
+        (flags | StatusFlags::IGNORED)
+            .difference(StatusFlags::BREAK)
+            .bits(),
+        "hardware interrupts must push flags with BREAK clear"
+    );
+    assert!(cpu.flags.contains(StatusFlags::INTERRUPT_DISABLE));
+}
+
+#[test]
+fn reset() {
+    let (mut cpu, mut memory) = prepare(&[]);
+    cpu.sp = 0x00;
+    cpu.a = 0x12;
+    cpu.x = 0x34;
+    cpu.y = 0x56;
+    cpu.flags = StatusFlags::IGNORED;
+
+    cpu.reset(&mut memory);
+
+    assert_eq!(cpu.clock_cycle_count, 7, "reset must take 7 clock cycles");
+    assert_eq!(
+        cpu.pc, RESET_HANDLER,
+        "reset must load PC from the reset vector"
+    );
+    assert_eq!(cpu.sp, 0xFD, "reset must decrement SP by 3");
+    assert!(cpu.flags.contains(StatusFlags::INTERRUPT_DISABLE));
+    assert_eq!((cpu.a, cpu.x, cpu.y), (0x12, 0x34, 0x56));
+
+    for addr in 0x0100..=0x01FF {
+        assert_eq!(memory.load(addr), 0, "reset must not write to the stack");
+    }
+}
+
+#[test]
+fn power_up() {
+    let (_, mut memory) = prepare(&[]);
+    let mut cpu = Cpu::new();
+    assert_eq!(cpu.sp, 0xFF);
+
+    cpu.reset(&mut memory);
+
+    assert_eq!(cpu.sp, 0xFC, "reset must decrement the power-up SP");
+    assert_eq!(cpu.pc, RESET_HANDLER);
+}
+
+#[test]
+fn nmi() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Nop]);
+    cpu.flags = StatusFlags::IGNORED | StatusFlags::INTERRUPT_DISABLE;
+
+    cpu.set_nmi(true);
+    // the NMI is detected during the NOP and serviced after it
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, OPCODE_ADDR + 1);
+
+    let flags = cpu.flags;
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.clock_cycle_count, 2 + 7, "NMI must take 7 clock cycles");
+    assert_eq!(cpu.pc, NMI_HANDLER);
+    check_interrupt_frame(&cpu, &mut memory, OPCODE_ADDR + 1, flags);
+}
+
+#[test]
+fn nmi_is_edge_triggered() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Nop]);
+    memory.store(NMI_HANDLER, Opcode::Nop as u8);
+    memory.store(NMI_HANDLER + 1, Opcode::Nop as u8);
+
+    cpu.set_nmi(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, NMI_HANDLER);
+
+    // the line is still asserted, but there's no new edge
+    cpu.set_nmi(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(
+        cpu.pc,
+        NMI_HANDLER + 2,
+        "NMI must not retrigger without an edge"
+    );
+}
+
+#[test]
+fn irq() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Nop]);
+    cpu.flags = StatusFlags::IGNORED | StatusFlags::CARRY;
+
+    cpu.set_irq(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    let flags = cpu.flags;
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    assert_eq!(cpu.clock_cycle_count, 2 + 7, "IRQ must take 7 clock cycles");
+    assert_eq!(cpu.pc, IRQ_HANDLER);
+    check_interrupt_frame(&cpu, &mut memory, OPCODE_ADDR + 1, flags);
+}
+
+#[test]
+fn irq_masked() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Nop, Opcode::Nop]);
+    cpu.flags = StatusFlags::IGNORED | StatusFlags::INTERRUPT_DISABLE;
+
+    cpu.set_irq(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    assert_eq!(
+        cpu.pc,
+        OPCODE_ADDR + 2,
+        "IRQ must be ignored when interrupts are disabled"
+    );
+    assert_eq!(cpu.sp, SP);
+}
+
+#[test]
+fn irq_delayed_by_cli() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Cli, Opcode::Nop]);
+    cpu.flags = StatusFlags::IGNORED | StatusFlags::INTERRUPT_DISABLE;
+
+    cpu.set_irq(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(
+        cpu.pc,
+        OPCODE_ADDR + 2,
+        "the instruction after CLI must be executed"
+    );
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, IRQ_HANDLER);
+}
+
+#[test]
+fn irq_after_sei() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Sei]);
+    cpu.flags = StatusFlags::IGNORED;
+
+    cpu.set_irq(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    assert_eq!(
+        cpu.pc, IRQ_HANDLER,
+        "IRQ must still be serviced right after SEI"
+    );
+    assert_ne!(
+        memory.load(0x0100 | SP.wrapping_sub(2) as u16) & StatusFlags::INTERRUPT_DISABLE.bits(),
+        0,
+        "flags pushed after SEI must have INTERRUPT_DISABLE set"
+    );
+}
+
+#[test]
+fn nmi_hijacks_irq() {
+    let (mut cpu, mut memory) = prepare(&[Opcode::Nop]);
+    memory.store(NMI_HANDLER, Opcode::Nop as u8);
+    cpu.flags = StatusFlags::IGNORED;
+
+    cpu.set_irq(true);
+    cpu.set_nmi(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, NMI_HANDLER, "NMI must take priority over IRQ");
+
+    // the NMI has been serviced and IRQs are disabled
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, NMI_HANDLER + 1);
+}
+
+/// Memory with a device that drives the IRQ line
+struct IrqMemory {
+    memory: TestMemory,
+    irq: bool,
+}
+
+impl Memory for IrqMemory {
+    fn load(&mut self, address: u16) -> u8 {
+        self.memory.load(address)
+    }
+
+    fn store(&mut self, address: u16, value: u8) {
+        self.memory.store(address, value);
+    }
+
+    fn irq(&self) -> bool {
+        self.irq
+    }
+}
+
+#[test]
+fn irq_from_memory() {
+    let (mut cpu, memory) = prepare(&[Opcode::Nop, Opcode::Nop]);
+    let mut memory = IrqMemory { memory, irq: false };
+    cpu.flags = StatusFlags::IGNORED;
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    memory.irq = true;
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, OPCODE_ADDR + 2);
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(
+        cpu.pc, IRQ_HANDLER,
+        "memory asserting IRQ must trigger an interrupt"
+    );
+    assert!(!cpu.is_mid_instruction());
+}
+
+#[test]
+fn irq_from_memory_while_ticking() {
+    let (mut cpu, memory) = prepare(&[Opcode::Nop, Opcode::Nop]);
+    let mut memory = IrqMemory { memory, irq: false };
+    cpu.flags = StatusFlags::IGNORED;
+
+    cpu.tick(&mut memory).unwrap();
+    memory.irq = true;
+    cpu.tick(&mut memory).unwrap();
+    // the level sampled before the last cycle of NOP is what counts
+    memory.irq = false;
+    assert_eq!(cpu.pc, OPCODE_ADDR + 1);
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, IRQ_HANDLER);
+}
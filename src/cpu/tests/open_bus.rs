@@ -0,0 +1,99 @@
+use crate::{
+    cartridge::{Mapper, ines::Rom, mapper::Nrom},
+    cpu::{Cpu, opcode::Opcode, tests::TestMemory},
+    memory::{
+        AccessKind, Memory,
+        bus::{Bus, PpuRegisters},
+    },
+};
+
+const PROGRAM_ADDR: u16 = 0x8000;
+
+type TestBus = Bus<TestMemory, TestMemory, Nrom>;
+
+impl PpuRegisters for TestMemory {
+    fn read_register(
+        &mut self,
+        register: u16,
+        kind: AccessKind,
+        open_bus: u8,
+        _cartridge: &mut impl Mapper,
+    ) -> u8 {
+        self.load_access(register, kind, open_bus)
+    }
+
+    fn write_register(
+        &mut self,
+        register: u16,
+        value: u8,
+        kind: AccessKind,
+        _cartridge: &mut impl Mapper,
+    ) {
+        self.store_access(register, value, kind);
+    }
+}
+
+/// Put the program at the start of a 16 KiB NROM cartridge
+fn prepare(program: &[u8]) -> (Cpu, TestBus) {
+    let mut file = b"NES\x1A\x01\x01".to_vec();
+    file.resize(16, 0);
+
+    let mut prg_rom = program.to_vec();
+    prg_rom.resize(16 * 1024, 0);
+    file.extend(prg_rom);
+    file.resize(file.len() + 8 * 1024, 0);
+
+    let cartridge = Nrom::new(Rom::parse(&file).unwrap());
+    let mut cpu = Cpu::new();
+    cpu.pc = PROGRAM_ADDR;
+
+    (
+        cpu,
+        TestBus::new(TestMemory::new(), TestMemory::new(), cartridge),
+    )
+}
+
+#[test]
+fn unmapped_read_returns_last_bus_value() {
+    let (mut cpu, mut bus) = prepare(&[Opcode::LdaAbsolute as u8, 0x18, 0x40]);
+
+    cpu.execute_next_instruction(&mut bus).unwrap();
+
+    assert_eq!(
+        cpu.a, 0x40,
+        "the high byte of the address must still be on the data bus"
+    );
+    assert_eq!(cpu.data_bus(), 0x40);
+}
+
+#[test]
+fn indexed_unmapped_read() {
+    let (mut cpu, mut bus) = prepare(&[Opcode::LdaAbsoluteX as u8, 0x10, 0x40]);
+    cpu.x = 0x0A;
+
+    cpu.execute_next_instruction(&mut bus).unwrap();
+
+    assert_eq!(cpu.a, 0x40);
+}
+
+#[test]
+fn writes_drive_the_bus() {
+    let (mut cpu, mut bus) = prepare(&[Opcode::StaZeropage as u8, 0x10]);
+    cpu.a = 0x99;
+
+    cpu.execute_next_instruction(&mut bus).unwrap();
+
+    assert_eq!(cpu.data_bus(), 0x99);
+}
+
+#[test]
+fn ticked_unmapped_read() {
+    let (mut cpu, mut bus) = prepare(&[Opcode::LdaAbsolute as u8, 0x1F, 0x40]);
+
+    for _ in 0..4 {
+        cpu.tick(&mut bus).unwrap();
+    }
+
+    assert!(!cpu.is_mid_instruction());
+    assert_eq!(cpu.a, 0x40);
+}
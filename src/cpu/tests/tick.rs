@@ -0,0 +1,265 @@
+use crate::{
+    cpu::{
+        Cpu, IllegalOpcodePolicy, StatusFlags, Variant,
+        interrupts::{IRQ_VECTOR, NMI_VECTOR},
+        tests::{TEST_MEMORY_MASK, TestMemory, addressing_modes::OPCODE_ADDR},
+    },
+    memory::Memory,
+};
+
+const IRQ_HANDLER: u16 = 0x0300;
+const NMI_HANDLER: u16 = 0x0400;
+
+/// A single bus access, `true` meaning a write
+type BusAccess = (u16, u8, bool);
+
+/// Memory that records every access made to it
+struct RecordingMemory {
+    memory: TestMemory,
+    accesses: Vec<BusAccess>,
+}
+
+impl Memory for RecordingMemory {
+    fn load(&mut self, address: u16) -> u8 {
+        let value = self.memory.load(address);
+        self.accesses.push((address, value, false));
+        value
+    }
+
+    fn store(&mut self, address: u16, value: u8) {
+        self.memory.store(address, value);
+        self.accesses.push((address, value, true));
+    }
+}
+
+/// Fill memory with pseudo random bytes, so that every addressing mode reads varied operands
+fn random_memory(seed: u32) -> TestMemory {
+    let mut memory = TestMemory::new();
+    let mut state = seed.wrapping_mul(0x9E37_79B9) | 1;
+
+    for addr in 0..=TEST_MEMORY_MASK {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        memory.store(addr, (state >> 24) as u8);
+    }
+
+    memory
+}
+
+fn prepare(variant: Variant, opcode: u8, seed: u32) -> (Cpu, RecordingMemory) {
+    let mut cpu = Cpu::new();
+    cpu.variant = variant;
+    cpu.illegal_opcode_policy = IllegalOpcodePolicy::Nop;
+    cpu.pc = OPCODE_ADDR;
+    cpu.a = 0x5A;
+    cpu.x = 0xC3;
+    cpu.y = 0x81;
+    cpu.sp = 0xFD;
+    cpu.flags = StatusFlags::from_bits_retain(seed as u8) | StatusFlags::IGNORED;
+
+    let mut memory = random_memory(seed);
+    memory.store(OPCODE_ADDR, opcode);
+
+    let memory = RecordingMemory {
+        memory,
+        accesses: Vec::new(),
+    };
+
+    (cpu, memory)
+}
+
+fn assert_same_state(ticked: &Cpu, executed: &Cpu, context: &str) {
+    assert_eq!(ticked.a, executed.a, "A must match, {context}");
+    assert_eq!(ticked.x, executed.x, "X must match, {context}");
+    assert_eq!(ticked.y, executed.y, "Y must match, {context}");
+    assert_eq!(ticked.sp, executed.sp, "SP must match, {context}");
+    assert_eq!(ticked.pc, executed.pc, "PC must match, {context}");
+    assert_eq!(ticked.flags, executed.flags, "flags must match, {context}");
+    assert_eq!(
+        ticked.clock_cycle_count, executed.clock_cycle_count,
+        "clock cycles must match, {context}"
+    );
+    assert_eq!(ticked.is_jammed(), executed.is_jammed(), "{context}");
+    assert_eq!(ticked.is_waiting(), executed.is_waiting(), "{context}");
+}
+
+/// Tick until the instruction is finished, checking that every tick is a single bus access
+fn tick_instruction(cpu: &mut Cpu, memory: &mut RecordingMemory) {
+    loop {
+        let accesses = memory.accesses.len();
+        let clock_cycle_count = cpu.clock_cycle_count;
+
+        cpu.tick(memory).unwrap();
+
+        assert_eq!(
+            memory.accesses.len(),
+            accesses + 1,
+            "a tick must perform exactly one bus access"
+        );
+        assert_eq!(cpu.clock_cycle_count, clock_cycle_count + 1);
+
+        if !cpu.is_mid_instruction() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn matches_execute_next_instruction() {
+    for variant in [Variant::Ricoh2A03, Variant::Nmos6502, Variant::Wdc65C02] {
+        for opcode in u8::MIN..=u8::MAX {
+            for seed in 0..4 {
+                let (mut executed, mut executed_memory) = prepare(variant, opcode, seed);
+                let (mut ticked, mut ticked_memory) = prepare(variant, opcode, seed);
+
+                executed
+                    .execute_next_instruction(&mut executed_memory)
+                    .unwrap();
+                tick_instruction(&mut ticked, &mut ticked_memory);
+
+                let context = format!("opcode {opcode:#04X} on {variant:?} with seed {seed}");
+                assert_same_state(&ticked, &executed, &context);
+                assert_eq!(
+                    ticked_memory.accesses, executed_memory.accesses,
+                    "bus accesses must match, {context}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn registers_update_on_last_cycle() {
+    // LDA #$42
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, 0xA9, 0);
+    memory.memory.store(OPCODE_ADDR + 1, 0x42);
+
+    cpu.tick(&mut memory).unwrap();
+    assert!(cpu.is_mid_instruction());
+    assert_eq!(cpu.a, 0x5A, "A must not change before the last cycle");
+    assert_eq!(cpu.pc, OPCODE_ADDR);
+
+    cpu.tick(&mut memory).unwrap();
+    assert!(!cpu.is_mid_instruction());
+    assert_eq!(cpu.a, 0x42);
+    assert_eq!(cpu.pc, OPCODE_ADDR + 2);
+}
+
+#[test]
+fn execute_next_instruction_finishes_ticked_instruction() {
+    // INC $0300,X
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, 0xFE, 0);
+    memory.memory.store(OPCODE_ADDR + 1, 0x00);
+    memory.memory.store(OPCODE_ADDR + 2, 0x03);
+
+    for _ in 0..3 {
+        cpu.tick(&mut memory).unwrap();
+    }
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    assert!(!cpu.is_mid_instruction());
+    assert_eq!(cpu.clock_cycle_count, 7);
+    assert_eq!(cpu.pc, OPCODE_ADDR + 3);
+}
+
+fn prepare_interrupts(opcode: u8) -> (Cpu, RecordingMemory) {
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, opcode, 0);
+    cpu.flags = StatusFlags::IGNORED;
+
+    let [irq_low, irq_high] = IRQ_HANDLER.to_le_bytes();
+    memory.memory.store(IRQ_VECTOR, irq_low);
+    memory.memory.store(IRQ_VECTOR + 1, irq_high);
+    let [nmi_low, nmi_high] = NMI_HANDLER.to_le_bytes();
+    memory.memory.store(NMI_VECTOR, nmi_low);
+    memory.memory.store(NMI_VECTOR + 1, nmi_high);
+
+    (cpu, memory)
+}
+
+#[test]
+fn irq_before_last_cycle() {
+    // NOP
+    let (mut cpu, mut memory) = prepare_interrupts(0xEA);
+
+    cpu.tick(&mut memory).unwrap();
+    cpu.set_irq(true);
+    cpu.tick(&mut memory).unwrap();
+    assert_eq!(cpu.pc, OPCODE_ADDR + 1);
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(
+        cpu.pc, IRQ_HANDLER,
+        "an IRQ asserted before the last cycle must be serviced after the instruction"
+    );
+}
+
+#[test]
+fn irq_after_last_cycle() {
+    // NOP
+    let (mut cpu, mut memory) = prepare_interrupts(0xEA);
+    memory.memory.store(OPCODE_ADDR + 1, 0xEA);
+
+    cpu.tick(&mut memory).unwrap();
+    cpu.tick(&mut memory).unwrap();
+    cpu.set_irq(true);
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(
+        cpu.pc,
+        OPCODE_ADDR + 2,
+        "an IRQ asserted after the last cycle must wait for the next instruction"
+    );
+
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, IRQ_HANDLER);
+}
+
+#[test]
+fn nmi_hijacks_brk_mid_instruction() {
+    // BRK
+    let (mut cpu, mut memory) = prepare_interrupts(0x00);
+
+    for _ in 0..4 {
+        cpu.tick(&mut memory).unwrap();
+    }
+    cpu.set_nmi(true);
+    cpu.execute_next_instruction(&mut memory).unwrap();
+
+    assert_eq!(
+        cpu.pc, NMI_HANDLER,
+        "an NMI asserted before the vector is fetched must hijack BRK"
+    );
+}
+
+#[test]
+fn nmi_too_late_to_hijack_brk() {
+    // BRK
+    let (mut cpu, mut memory) = prepare_interrupts(0x00);
+
+    for _ in 0..6 {
+        cpu.tick(&mut memory).unwrap();
+    }
+    cpu.set_nmi(true);
+    cpu.tick(&mut memory).unwrap();
+
+    assert_eq!(cpu.pc, IRQ_HANDLER);
+
+    // the first instruction of the handler is executed before the NMI
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    cpu.execute_next_instruction(&mut memory).unwrap();
+    assert_eq!(cpu.pc, NMI_HANDLER);
+}
+
+#[test]
+fn reset_abandons_ticked_instruction() {
+    // INC $0300,X
+    let (mut cpu, mut memory) = prepare(Variant::Ricoh2A03, 0xFE, 0);
+
+    for _ in 0..3 {
+        cpu.tick(&mut memory).unwrap();
+    }
+    cpu.reset(&mut memory);
+
+    assert!(!cpu.is_mid_instruction());
+}
@@ -0,0 +1,38 @@
+/// The chip the CPU emulates
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Variant {
+    /// Ricoh 2A03 used in the NES
+    ///
+    /// An NMOS 6502 with decimal mode disconnected,
+    /// the DECIMAL flag can still be set but ADC and SBC ignore it
+    #[default]
+    Ricoh2A03,
+
+    /// Generic NMOS 6502
+    ///
+    /// ADC and SBC do BCD arithmetic when the DECIMAL flag is set,
+    /// with NEGATIVE, OVERFLOW and ZERO set the same quirky way as on the original chip
+    Nmos6502,
+
+    /// WDC 65C02, the CMOS version of the 6502
+    ///
+    /// Adds new instructions and addressing modes, including the bit manipulation ones,
+    /// fixes the JMP indirect page wrap bug and changes some cycle counts.
+    /// Opcodes it doesn't define are NOPs of various lengths
+    Wdc65C02,
+}
+
+impl Variant {
+    /// Whether ADC and SBC respect the DECIMAL flag
+    pub fn has_decimal_mode(self) -> bool {
+        match self {
+            Variant::Ricoh2A03 => false,
+            Variant::Nmos6502 | Variant::Wdc65C02 => true,
+        }
+    }
+
+    /// Whether the CPU is a 65C02
+    pub fn is_cmos(self) -> bool {
+        self == Variant::Wdc65C02
+    }
+}
@@ -1,2 +1,4 @@
+pub mod cartridge;
 pub mod cpu;
 pub mod memory;
+pub mod mixer;
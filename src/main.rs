@@ -1,6 +1,36 @@
-use amnesty_emulator::memory::ram::Ram;
+use std::{env, fs, process::ExitCode};
 
-fn main() {
-    let m = Ram::default();
-    dbg!(m);
+use amnesty_emulator::cartridge::{ines::Rom, mapper};
+
+fn main() -> ExitCode {
+    let Some(path) = env::args().nth(1) else {
+        eprintln!("usage: amnesty-emulator <rom file>");
+        return ExitCode::FAILURE;
+    };
+
+    let bytes = match fs::read(&path) {
+        Ok(bytes) => bytes,
+        Err(error) => {
+            eprintln!("can't read {path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let rom = match Rom::parse(&bytes) {
+        Ok(rom) => rom,
+        Err(error) => {
+            eprintln!("can't load {path}: {error}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    println!("{:#?}", rom.header);
+
+    match mapper::from_rom(rom) {
+        Ok(_) => ExitCode::SUCCESS,
+        Err(error) => {
+            eprintln!("can't load {path}: {error}");
+            ExitCode::FAILURE
+        }
+    }
 }
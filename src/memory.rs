@@ -1,5 +1,9 @@
+pub mod bus;
 pub mod ram;
 
+#[cfg(all(test, not(tarpaulin_include)))]
+mod tests;
+
 /// Trait for anything that acts like memory, i.e. can be written to or read from by the CPU.
 ///
 /// Both loads and stores can mutate the state of memory,
@@ -8,4 +12,50 @@ pub trait Memory {
     fn load(&mut self, address: u16) -> u8;
 
     fn store(&mut self, address: u16, value: u8);
+
+    /// Read a value without any side effects, for debuggers, tracers and disassemblers
+    ///
+    /// Returns `None` if the value can't be known without disturbing the state of memory,
+    /// for example for I/O ports whose reads acknowledge something.
+    /// The default can't see anything, implementors should override it where they can
+    fn peek(&self, _address: u16) -> Option<u8> {
+        None
+    }
+
+    /// Load with the kind of access the CPU is making, the CPU always loads through this
+    ///
+    /// `open_bus` is the last value that was on the data bus,
+    /// it's what the CPU reads when nothing responds to the address,
+    /// or in the bits a device doesn't drive.
+    /// Dummy reads have the same side effects as real ones on hardware,
+    /// so the default just forwards to `load`
+    fn load_access(&mut self, address: u16, _kind: AccessKind, _open_bus: u8) -> u8 {
+        self.load(address)
+    }
+
+    /// Store with the kind of access the CPU is making, the CPU always stores through this
+    ///
+    /// The default just forwards to `store`
+    fn store_access(&mut self, address: u16, value: u8, _kind: AccessKind) {
+        self.store(address, value);
+    }
+
+    /// Level of the IRQ line driven by devices in memory, `true` meaning it's asserted
+    ///
+    /// The CPU samples it before every cycle, along with the line set by `Cpu::set_irq`
+    fn irq(&self) -> bool {
+        false
+    }
+}
+
+/// Whether a bus access is one an instruction needs or a dummy one
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccessKind {
+    Real,
+
+    /// An access that's only a side effect of how the CPU works internally
+    ///
+    /// Dummy reads have their value discarded,
+    /// dummy writes store a value that's about to be overwritten
+    Dummy,
 }
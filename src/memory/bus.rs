@@ -0,0 +1,161 @@
+use super::{AccessKind, Memory, ram::Ram};
+use crate::cartridge::{Mapper, mapper::CPU_START};
+
+const RAM_END: u16 = 0x1FFF;
+const RAM_MIRROR_MASK: u16 = 0x07FF;
+
+const PPU_REGISTERS_START: u16 = 0x2000;
+const PPU_REGISTERS_END: u16 = 0x3FFF;
+const PPU_REGISTERS_MIRROR_MASK: u16 = 0x0007;
+
+const APU_IO_START: u16 = 0x4000;
+const APU_IO_END: u16 = 0x4017;
+
+/// The NES CPU address space, routes every address to the device that's mapped there
+///
+/// | Range         | Device                                              |
+/// |---------------|-----------------------------------------------------|
+/// | `$0000-$1FFF` | 2 KiB of RAM, mirrored 4 times                      |
+/// | `$2000-$3FFF` | PPU registers `$2000-$2007`, mirrored every 8 bytes |
+/// | `$4000-$4017` | APU and I/O registers                               |
+/// | `$4018-$401F` | APU and I/O test mode registers, normally disabled  |
+/// | `$4020-$FFFF` | Cartridge                                           |
+///
+/// Devices receive addresses with the mirroring already removed,
+/// so the PPU only ever sees `$2000-$2007`.
+/// Nothing responds to the disabled test mode registers, reading them returns the open bus value
+/// and writes to them are ignored. Loading through `load` uses 0 as the open bus value.
+///
+/// Every access is one CPU cycle, the cartridge's `cpu_cycle` is called before each one
+/// even if it goes to another device. Peeking doesn't count as a cycle.
+/// Writes to the PPU registers are also passed to the cartridge.
+/// The IRQ lines of the APU and the cartridge are combined
+#[derive(Debug, Clone, Default)]
+pub struct Bus<P, A, C> {
+    pub ram: Ram,
+    pub ppu: P,
+    pub apu: A,
+    pub cartridge: C,
+}
+
+/// The PPU as seen from the CPU bus, its registers `$2000-$2007`
+///
+/// The PPU's own address bus is connected to the cartridge, so every register access gets it.
+/// `$2007` accesses go through `Mapper::ppu_read` and `Mapper::ppu_write`,
+/// and the VRAM address being set by `$2006` or incremented by `$2007`
+/// is reported with `Mapper::ppu_address_changed`
+pub trait PpuRegisters {
+    /// Read a register, `open_bus` is used for the bits the PPU doesn't drive
+    fn read_register(
+        &mut self,
+        register: u16,
+        kind: AccessKind,
+        open_bus: u8,
+        cartridge: &mut impl Mapper,
+    ) -> u8;
+
+    fn write_register(
+        &mut self,
+        register: u16,
+        value: u8,
+        kind: AccessKind,
+        cartridge: &mut impl Mapper,
+    );
+
+    /// Read a register without any side effects, like `Memory::peek`
+    fn peek_register(&self, _register: u16) -> Option<u8> {
+        None
+    }
+}
+
+/// Where an address ends up, along with the address the device sees
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Mapping {
+    Ram(u16),
+    Ppu(u16),
+    Apu(u16),
+    Cartridge(u16),
+    Unmapped,
+}
+
+impl Mapping {
+    fn decode(address: u16) -> Self {
+        match address {
+            ..=RAM_END => Self::Ram(address & RAM_MIRROR_MASK),
+            PPU_REGISTERS_START..=PPU_REGISTERS_END => {
+                Self::Ppu(PPU_REGISTERS_START | (address & PPU_REGISTERS_MIRROR_MASK))
+            }
+            APU_IO_START..=APU_IO_END => Self::Apu(address),
+            CPU_START.. => Self::Cartridge(address),
+            _ => Self::Unmapped,
+        }
+    }
+}
+
+impl<P, A, C> Bus<P, A, C> {
+    /// Create a bus with cleared RAM
+    pub fn new(ppu: P, apu: A, cartridge: C) -> Self {
+        Self {
+            ram: Ram::new(),
+            ppu,
+            apu,
+            cartridge,
+        }
+    }
+}
+
+impl<P: PpuRegisters, A: Memory, C: Mapper> Memory for Bus<P, A, C> {
+    fn load(&mut self, address: u16) -> u8 {
+        self.load_access(address, AccessKind::Real, 0)
+    }
+
+    fn store(&mut self, address: u16, value: u8) {
+        self.store_access(address, value, AccessKind::Real);
+    }
+
+    fn peek(&self, address: u16) -> Option<u8> {
+        match Mapping::decode(address) {
+            Mapping::Ram(address) => self.ram.peek(address),
+            Mapping::Ppu(address) => self.ppu.peek_register(address),
+            Mapping::Apu(address) => self.apu.peek(address),
+            Mapping::Cartridge(address) => self.cartridge.cpu_peek(address),
+            // open bus depends on the previous access
+            Mapping::Unmapped => None,
+        }
+    }
+
+    fn irq(&self) -> bool {
+        self.apu.irq() || self.cartridge.irq()
+    }
+
+    fn load_access(&mut self, address: u16, kind: AccessKind, open_bus: u8) -> u8 {
+        self.cartridge.cpu_cycle();
+
+        match Mapping::decode(address) {
+            Mapping::Ram(address) => self.ram.load(address),
+            Mapping::Ppu(address) => {
+                self.ppu
+                    .read_register(address, kind, open_bus, &mut self.cartridge)
+            }
+            Mapping::Apu(address) => self.apu.load_access(address, kind, open_bus),
+            Mapping::Cartridge(address) => self.cartridge.cpu_read(address, open_bus),
+            Mapping::Unmapped => open_bus,
+        }
+    }
+
+    fn store_access(&mut self, address: u16, value: u8, kind: AccessKind) {
+        self.cartridge.cpu_cycle();
+
+        match Mapping::decode(address) {
+            Mapping::Ram(address) => self.ram.store(address, value),
+            Mapping::Ppu(address) => {
+                self.ppu
+                    .write_register(address, value, kind, &mut self.cartridge);
+                self.cartridge.ppu_register_write(address, value);
+            }
+            Mapping::Apu(address) => self.apu.store_access(address, value, kind),
+            Mapping::Cartridge(address) => self.cartridge.cpu_write(address, value),
+            Mapping::Unmapped => {}
+        }
+    }
+}
@@ -22,6 +22,12 @@ impl Ram {
         self[addr]
     }
 
+    /// Read a byte without panicking, `None` if the address is outside of RAM
+    #[must_use]
+    pub fn peek(&self, addr: u16) -> Option<u8> {
+        self.buf.get(addr as usize).copied()
+    }
+
     /// Write a byte to an address
     pub fn store(&mut self, addr: u16, value: u8) {
         self[addr] = value;
@@ -0,0 +1,100 @@
+use super::{AccessKind, Memory, bus::PpuRegisters};
+use crate::cartridge::{Ciram, Mapper};
+
+mod bus;
+
+/// A device that records every access made to it,
+/// reads return the low byte of the address
+///
+/// As a cartridge it records CPU accesses as real ones, counts the CPU cycles
+/// and records the PPU register writes and PPU address changes it sees
+#[derive(Debug, Clone, Default)]
+struct TestDevice {
+    accesses: Vec<(u16, Option<u8>, AccessKind)>,
+    cycles: u64,
+    ppu_register_writes: Vec<(u16, u8)>,
+    ppu_addresses: Vec<u16>,
+}
+
+impl Memory for TestDevice {
+    fn load(&mut self, address: u16) -> u8 {
+        self.load_access(address, AccessKind::Real, 0)
+    }
+
+    fn store(&mut self, address: u16, value: u8) {
+        self.store_access(address, value, AccessKind::Real);
+    }
+
+    fn peek(&self, address: u16) -> Option<u8> {
+        Some(address as u8)
+    }
+
+    fn load_access(&mut self, address: u16, kind: AccessKind, _open_bus: u8) -> u8 {
+        self.accesses.push((address, None, kind));
+        address as u8
+    }
+
+    fn store_access(&mut self, address: u16, value: u8, kind: AccessKind) {
+        self.accesses.push((address, Some(value), kind));
+    }
+}
+
+impl PpuRegisters for TestDevice {
+    fn read_register(
+        &mut self,
+        register: u16,
+        kind: AccessKind,
+        open_bus: u8,
+        _cartridge: &mut impl Mapper,
+    ) -> u8 {
+        self.load_access(register, kind, open_bus)
+    }
+
+    fn write_register(
+        &mut self,
+        register: u16,
+        value: u8,
+        kind: AccessKind,
+        _cartridge: &mut impl Mapper,
+    ) {
+        self.store_access(register, value, kind);
+    }
+
+    fn peek_register(&self, register: u16) -> Option<u8> {
+        self.peek(register)
+    }
+}
+
+impl Mapper for TestDevice {
+    fn cpu_read(&mut self, address: u16, open_bus: u8) -> u8 {
+        self.load_access(address, AccessKind::Real, open_bus)
+    }
+
+    fn cpu_peek(&self, address: u16) -> Option<u8> {
+        self.peek(address)
+    }
+
+    fn cpu_write(&mut self, address: u16, value: u8) {
+        self.store(address, value);
+    }
+
+    fn ppu_read(&mut self, _address: u16, _ciram: &Ciram) -> u8 {
+        unreachable!()
+    }
+
+    fn ppu_write(&mut self, _address: u16, _value: u8, _ciram: &mut Ciram) {
+        unreachable!()
+    }
+
+    fn ppu_register_write(&mut self, address: u16, value: u8) {
+        self.ppu_register_writes.push((address, value));
+    }
+
+    fn ppu_address_changed(&mut self, address: u16) {
+        self.ppu_addresses.push(address);
+    }
+
+    fn cpu_cycle(&mut self) {
+        self.cycles += 1;
+    }
+}
@@ -0,0 +1,203 @@
+use crate::{
+    cartridge::Mapper,
+    memory::{
+        AccessKind::{self, Dummy, Real},
+        Memory,
+        bus::{Bus, PpuRegisters},
+        tests::TestDevice,
+    },
+};
+
+type TestBus = Bus<TestDevice, TestDevice, TestDevice>;
+
+fn single_access(device: &TestDevice) -> (u16, Option<u8>, AccessKind) {
+    assert_eq!(
+        device.accesses.len(),
+        1,
+        "exactly one access must reach the device"
+    );
+    device.accesses[0]
+}
+
+#[test]
+fn ram_mirroring() {
+    let mut bus = TestBus::default();
+
+    for mirror in 0..4u16 {
+        let addr = mirror * 0x0800 + 0x0123;
+        bus.store(addr, mirror as u8 + 1);
+
+        for read_mirror in 0..4u16 {
+            let read_addr = read_mirror * 0x0800 + 0x0123;
+            assert_eq!(
+                bus.load(read_addr),
+                mirror as u8 + 1,
+                "a write to ${addr:04X} must be visible at ${read_addr:04X}"
+            );
+            assert_eq!(bus.peek(read_addr), Some(mirror as u8 + 1));
+        }
+    }
+
+    assert_eq!(bus.ram.load(0x0123), 4);
+}
+
+#[test]
+fn ppu_register_mirroring() {
+    for addr in 0x2000..=0x3FFF {
+        let mut bus = TestBus::default();
+
+        let value = bus.load_access(addr, Dummy, 0);
+        let expected_addr = 0x2000 | (addr & 0x0007);
+
+        assert_eq!(value, expected_addr as u8);
+        assert_eq!(single_access(&bus.ppu), (expected_addr, None, Dummy));
+        assert!(bus.apu.accesses.is_empty());
+        assert!(bus.cartridge.accesses.is_empty());
+    }
+}
+
+#[test]
+fn cartridge_sees_ppu_register_writes() {
+    let mut bus = TestBus::default();
+
+    bus.store(0x2000, 0x80);
+    bus.store_access(0x3FF9, 0x18, Dummy);
+    bus.load(0x2002);
+
+    assert_eq!(
+        bus.cartridge.ppu_register_writes,
+        [(0x2000, 0x80), (0x2001, 0x18)]
+    );
+    assert!(bus.cartridge.accesses.is_empty());
+}
+
+#[test]
+fn ppu_reaches_the_cartridge() {
+    /// Puts the value written to a register on its address bus, reads go to the pattern tables
+    struct AddressPpu;
+
+    impl PpuRegisters for AddressPpu {
+        fn read_register(
+            &mut self,
+            _register: u16,
+            _kind: AccessKind,
+            _open_bus: u8,
+            cartridge: &mut impl Mapper,
+        ) -> u8 {
+            cartridge.ppu_address_changed(0x1000);
+            0
+        }
+
+        fn write_register(
+            &mut self,
+            _register: u16,
+            value: u8,
+            _kind: AccessKind,
+            cartridge: &mut impl Mapper,
+        ) {
+            cartridge.ppu_address_changed(value as u16);
+        }
+    }
+
+    let mut bus = Bus::new(AddressPpu, TestDevice::default(), TestDevice::default());
+
+    bus.store(0x2006, 0x20);
+    bus.load_access(0x3FFF, Dummy, 0);
+
+    assert_eq!(bus.cartridge.ppu_addresses, [0x0020, 0x1000]);
+    assert_eq!(bus.peek(0x2002), None);
+    assert!(bus.cartridge.accesses.is_empty());
+}
+
+#[test]
+fn apu_io_registers() {
+    for addr in 0x4000..=0x4017 {
+        let mut bus = TestBus::default();
+
+        bus.store(addr, 0x42);
+
+        assert_eq!(single_access(&bus.apu), (addr, Some(0x42), Real));
+        assert!(bus.ppu.accesses.is_empty());
+        assert!(bus.cartridge.accesses.is_empty());
+    }
+}
+
+#[test]
+fn test_mode_registers_are_open_bus() {
+    for addr in 0x4018..=0x401F {
+        let mut bus = TestBus::default();
+
+        bus.store(addr, 0x42);
+        let value = bus.load_access(addr, Real, 0xA5);
+
+        assert_eq!(
+            value, 0xA5,
+            "reading ${addr:04X} must return the open bus value"
+        );
+        assert_eq!(bus.peek(addr), None);
+        assert!(bus.ppu.accesses.is_empty());
+        assert!(bus.apu.accesses.is_empty());
+        assert!(bus.cartridge.accesses.is_empty());
+    }
+}
+
+#[test]
+fn open_bus_passed_to_devices() {
+    struct OpenBusDevice;
+
+    impl PpuRegisters for OpenBusDevice {
+        fn read_register(
+            &mut self,
+            _register: u16,
+            _kind: AccessKind,
+            open_bus: u8,
+            _cartridge: &mut impl Mapper,
+        ) -> u8 {
+            // like the PPU status register, only the top 3 bits are driven
+            0b1010_0000 | (open_bus & 0b0001_1111)
+        }
+
+        fn write_register(
+            &mut self,
+            _register: u16,
+            _value: u8,
+            _kind: AccessKind,
+            _cartridge: &mut impl Mapper,
+        ) {
+        }
+    }
+
+    let mut bus = Bus::new(OpenBusDevice, TestDevice::default(), TestDevice::default());
+
+    assert_eq!(bus.load_access(0x2002, Real, 0x5F), 0b1011_1111);
+}
+
+#[test]
+fn cartridge() {
+    for addr in [0x4020, 0x5000, 0x6000, 0x8000, 0xFFFF] {
+        let mut bus = TestBus::default();
+
+        let value = bus.load(addr);
+        bus.store_access(addr, 0x42, Dummy);
+
+        assert_eq!(value, addr as u8);
+        assert_eq!(
+            bus.cartridge.accesses,
+            [(addr, None, Real), (addr, Some(0x42), Real)]
+        );
+        assert_eq!(bus.peek(addr), Some(addr as u8));
+    }
+}
+
+#[test]
+fn cartridge_sees_every_cycle() {
+    let mut bus = TestBus::default();
+
+    bus.load(0x0000);
+    bus.store_access(0x2000, 0, Dummy);
+    bus.load_access(0x4018, Real, 0);
+    bus.store(0x8000, 0);
+    bus.peek(0x8000);
+
+    assert_eq!(bus.cartridge.cycles, 4, "peeks must not count as cycles");
+}
@@ -0,0 +1,16 @@
+use crate::cartridge::Mapper;
+
+/// Output of one APU pulse channel at full volume, from the APU's pulse mixing formula
+///
+/// Expansion audio is scaled relative to it, see `Mapper::audio`
+pub const APU_PULSE_MAX: f32 = 95.88 / (8128.0 / 15.0 + 100.0);
+
+/// Mix the APU's output, from 0 to 1, with the cartridge's expansion audio
+///
+/// The cartridge's output is already scaled to the APU's range, so the 2 are added together
+/// and cartridges without expansion audio leave the APU's output as it is.
+///
+/// See https://www.nesdev.org/wiki/APU_Mixer
+pub fn mix(apu: f32, cartridge: &impl Mapper) -> f32 {
+    apu + cartridge.audio()
+}